@@ -0,0 +1,484 @@
+#!/usr/bin/env python3
+"""Generate the property tables in `src/tables` from the Unicode Character
+Database.
+
+    scripts/gen_tables.py [--ucd DIR]
+
+The UCD files of `UCD_VERSION` are downloaded into `target/ucd/<version>`,
+unless `--ucd` points to a directory with a copy of them, laid out like
+https://www.unicode.org/Public/<version>/ucd/.
+"""
+
+import argparse
+import os
+import textwrap
+import urllib.request
+
+UCD_VERSION = "16.0.0"
+UCD_URL = f"https://www.unicode.org/Public/{UCD_VERSION}/ucd/"
+
+ROOT = os.path.dirname(os.path.dirname(os.path.abspath(__file__)))
+TABLES = os.path.join(ROOT, "src", "tables")
+
+MAX_CODE_POINT = 0x10FFFF
+MAX_WIDTH = 99
+
+
+def ucd_file(ucd_dir, name):
+    """Get the path of a UCD file, and download it if it's missing."""
+    path = os.path.join(ucd_dir, name)
+
+    if not os.path.exists(path):
+        os.makedirs(os.path.dirname(path), exist_ok=True)
+        print(f"downloading {UCD_URL}{name}")
+        urllib.request.urlretrieve(UCD_URL + name, path)
+
+    return path
+
+
+def parse_range(field):
+    start, _, end = field.partition("..")
+    return int(start, 16), int(end or start, 16)
+
+
+def parse(path):
+    """Yield `(start, end, fields, missing)` for every entry of a UCD file.
+
+    `missing` is set for the `@missing` lines, which give the default value of
+    the code points the file doesn't list.
+    """
+    with open(path, encoding="utf-8") as file:
+        for line in file:
+            missing = line.startswith("# @missing:")
+            if missing:
+                line = line[len("# @missing:"):]
+
+            line = line.split("#", 1)[0].strip()
+            if not line:
+                continue
+
+            fields = [field.strip() for field in line.split(";")]
+            start, end = parse_range(fields[0])
+            yield start, end, fields[1:], missing
+
+
+def property_map(path, default, prop=None):
+    """Get the value of an enumerated property for every code point.
+
+    Files listing multiple properties, like `DerivedCoreProperties.txt`, have
+    the name of the property as the first field, which is given as `prop`.
+    """
+    values = [default] * (MAX_CODE_POINT + 1)
+    entries = list(parse(path))
+
+    # The @missing lines come first, and from the most general to the most
+    # specific ranges.
+    for only_missing in (True, False):
+        for start, end, fields, missing in entries:
+            if missing != only_missing:
+                continue
+            if prop is not None:
+                if fields[0] != prop:
+                    continue
+                fields = fields[1:]
+
+            values[start:end + 1] = [fields[0]] * (end - start + 1)
+
+    return values
+
+
+def property_set(path, prop):
+    """Get the code points which have a binary property."""
+    values = [False] * (MAX_CODE_POINT + 1)
+
+    for start, end, fields, missing in parse(path):
+        if not missing and fields == [prop]:
+            values[start:end + 1] = [True] * (end - start + 1)
+
+    return values
+
+
+def variant(name):
+    """Turn a property value name into the name of an enum variant, like
+    `Regional_Indicator` into `RegionalIndicator` or `ZWJ` into `Zwj`."""
+    return "".join(
+        part.capitalize() if part.isupper() else part for part in name.split("_")
+    )
+
+
+def ranges(values, default):
+    """Collect runs of code points with the same value, leaving out those with
+    the default value."""
+    runs = []
+
+    for cp, value in enumerate(values):
+        if value == default or 0xD800 <= cp <= 0xDFFF:
+            continue
+
+        if runs and runs[-1][2] == value and (
+            runs[-1][1] + 1 == cp or (runs[-1][1] == 0xD7FF and cp == 0xE000)
+        ):
+            runs[-1][1] = cp
+        else:
+            runs.append([cp, cp, value])
+
+    return runs
+
+
+def char(cp):
+    return f"'\\u{{{cp:x}}}'"
+
+
+def table(name, ty, values, default, indent="", visibility=""):
+    """Render a range table, see `lookup` and `contains` in `src/tables.rs`."""
+    if ty is None:
+        items = [f"({char(start)}, {char(end)})," for start, end, _ in ranges(values, default)]
+        ty = "(char, char)"
+    else:
+        items = [
+            f"({char(start)}, {char(end)}, {value})," for start, end, value in ranges(values, default)
+        ]
+        ty = f"(char, char, {ty})"
+
+    lines = [f"{indent}#[rustfmt::skip]", f"{indent}{visibility}const {name}: &[{ty}] = &["]
+    line = indent + "   "
+    for item in items:
+        if len(line) + 1 + len(item) > MAX_WIDTH:
+            lines.append(line)
+            line = indent + "   "
+        line += " " + item
+    if line.strip():
+        lines.append(line)
+    lines.append(f"{indent}];")
+
+    return "\n".join(lines) + "\n"
+
+
+def header(title, reference, properties=None):
+    source = f"Unicode Character Database {UCD_VERSION}"
+    if properties:
+        source = f"{properties} properties of the {source}"
+    generated = textwrap.wrap(
+        f"Generated from the {source} by `scripts/gen_tables.py`, do not edit by hand.",
+        width=76,
+    )
+
+    return f"//! {title}, see {reference}.\n//!\n" + "".join(f"//! {line}\n" for line in generated)
+
+
+def enum(doc, name, variants):
+    body = "".join(f"    {variant},\n" for variant in variants)
+
+    return (
+        f"/// {doc}\n"
+        "#[derive(Debug, Clone, Copy, PartialEq, Eq)]\n"
+        f"pub(crate) enum {name} {{\n{body}}}\n"
+    )
+
+
+def write(name, content):
+    with open(os.path.join(TABLES, name), "w", encoding="utf-8") as file:
+        file.write(content)
+
+
+def gen_grapheme(ucd):
+    gcb = property_map(ucd("auxiliary/GraphemeBreakProperty.txt"), "Other")
+    incb = property_map(ucd("DerivedCoreProperties.txt"), "None", prop="InCB")
+    extpict = property_set(ucd("emoji/emoji-data.txt"), "Extended_Pictographic")
+
+    write(
+        "grapheme.rs",
+        header(
+            "Grapheme cluster related properties",
+            "[UAX #29](https://www.unicode.org/reports/tr29/)\n"
+            "//! and [UTS #51](https://www.unicode.org/reports/tr51/)",
+        )
+        + "\nuse super::{contains, lookup};\n\n"
+        + enum(
+            "The `Grapheme_Cluster_Break` property of a code point.",
+            "GraphemeClusterBreak",
+            ["Other", "Cr", "Lf", "Control", "Extend", "Zwj", "RegionalIndicator", "Prepend",
+             "SpacingMark", "L", "V", "T", "Lv", "Lvt"],
+        )
+        + "\n"
+        + enum(
+            "The `Indic_Conjunct_Break` property of a code point.",
+            "IndicConjunctBreak",
+            ["None", "Consonant", "Extend", "Linker"],
+        )
+        + """
+pub(crate) fn grapheme_cluster_break(c: char) -> GraphemeClusterBreak {
+    lookup(c, gcb::GRAPHEME_CLUSTER_BREAK).unwrap_or(GraphemeClusterBreak::Other)
+}
+
+pub(crate) fn indic_conjunct_break(c: char) -> IndicConjunctBreak {
+    lookup(c, incb::INDIC_CONJUNCT_BREAK).unwrap_or(IndicConjunctBreak::None)
+}
+
+pub(crate) fn is_extended_pictographic(c: char) -> bool {
+    contains(c, EXTENDED_PICTOGRAPHIC)
+}
+
+mod gcb {
+    use super::GraphemeClusterBreak::{self, *};
+
+"""
+        + table(
+            "GRAPHEME_CLUSTER_BREAK",
+            "GraphemeClusterBreak",
+            [variant(value) for value in gcb],
+            "Other",
+            indent="    ",
+            visibility="pub(super) ",
+        )
+        + """}
+
+mod incb {
+    use super::IndicConjunctBreak::{self, *};
+
+"""
+        + table(
+            "INDIC_CONJUNCT_BREAK",
+            "IndicConjunctBreak",
+            incb,
+            "None",
+            indent="    ",
+            visibility="pub(super) ",
+        )
+        + "}\n\n"
+        + table("EXTENDED_PICTOGRAPHIC", None, extpict, False),
+    )
+
+
+def gen_word(ucd):
+    wb = property_map(ucd("auxiliary/WordBreakProperty.txt"), "Other")
+
+    write(
+        "word.rs",
+        header(
+            "Word boundary related properties",
+            "[UAX #29](https://www.unicode.org/reports/tr29/)",
+        )
+        + "\nuse super::lookup;\nuse WordBreak::*;\n\n"
+        + enum(
+            "The `Word_Break` property of a code point.",
+            "WordBreak",
+            ["Other", "Cr", "Lf", "Newline", "Extend", "Zwj", "RegionalIndicator", "Format",
+             "Katakana", "HebrewLetter", "ALetter", "SingleQuote", "DoubleQuote", "MidNumLet",
+             "MidLetter", "MidNum", "Numeric", "ExtendNumLet", "WSegSpace"],
+        )
+        + """
+pub(crate) fn word_break(c: char) -> WordBreak {
+    lookup(c, WORD_BREAK).unwrap_or(WordBreak::Other)
+}
+
+"""
+        + table("WORD_BREAK", "WordBreak", [variant(value) for value in wb], "Other"),
+    )
+
+
+def gen_sentence(ucd):
+    sb = property_map(ucd("auxiliary/SentenceBreakProperty.txt"), "Other")
+
+    write(
+        "sentence.rs",
+        header(
+            "Sentence boundary related properties",
+            "[UAX #29](https://www.unicode.org/reports/tr29/)",
+        )
+        + "\nuse super::lookup;\nuse SentenceBreak::*;\n\n"
+        + enum(
+            "The `Sentence_Break` property of a code point.",
+            "SentenceBreak",
+            ["Other", "Cr", "Lf", "Extend", "Sep", "Format", "Sp", "Lower", "Upper", "OLetter",
+             "Numeric", "ATerm", "SContinue", "STerm", "Close"],
+        )
+        + """
+pub(crate) fn sentence_break(c: char) -> SentenceBreak {
+    lookup(c, SENTENCE_BREAK).unwrap_or(SentenceBreak::Other)
+}
+
+"""
+        + table("SENTENCE_BREAK", "SentenceBreak", [variant(value) for value in sb], "Other"),
+    )
+
+
+def gen_line_break(ucd):
+    lb = property_map(ucd("LineBreak.txt"), "XX")
+    gc = property_map(ucd("extracted/DerivedGeneralCategory.txt"), "Cn")
+    ea = property_map(ucd("EastAsianWidth.txt"), "N")
+
+    # LB1
+    def resolve(cp):
+        if lb[cp] in ("AI", "SG", "XX"):
+            return "Al"
+        if lb[cp] == "CJ":
+            return "Ns"
+        if lb[cp] == "SA":
+            return "Cm" if gc[cp] in ("Mn", "Mc") else "Al"
+        return variant(lb[cp])
+
+    classes = ["Bk", "Cr", "Lf", "Cm", "Nl", "Wj", "Zw", "Gl", "Sp", "Zwj", "B2", "Ba", "Bb",
+               "Hy", "Cb", "Cl", "Cp", "Ex", "In", "Ns", "Op", "Qu", "Is", "Nu", "Po", "Pr",
+               "Sy", "Al", "Eb", "Em", "H2", "H3", "Hl", "Id", "Jl", "Jv", "Jt", "Ri", "Ak",
+               "Ap", "As", "Vf", "Vi"]
+    values = [resolve(cp) for cp in range(MAX_CODE_POINT + 1)]
+    assert set(values) <= set(classes), set(values) - set(classes)
+
+    write(
+        "line_break.rs",
+        header(
+            "Line breaking related properties",
+            "[UAX #14](https://www.unicode.org/reports/tr14/)",
+            properties="`Line_Break`, `General_Category` and `East_Asian_Width`",
+        )
+        + """//!
+//! The classes are already resolved as described by LB1: `AI`, `SG` and `XX`
+//! are [`Al`](LineBreak::Al), `CJ` is [`Ns`](LineBreak::Ns), and `SA` is
+//! [`Cm`](LineBreak::Cm) for marks and [`Al`](LineBreak::Al) otherwise.
+
+use super::{contains, lookup};
+use LineBreak::*;
+
+"""
+        + enum(
+            "The `Line_Break` property of a code point, named by the short alias of\n"
+            "/// each class.",
+            "LineBreak",
+            classes,
+        )
+        + """
+pub(crate) fn line_break(c: char) -> LineBreak {
+    lookup(c, LINE_BREAK).unwrap_or(LineBreak::Al)
+}
+
+/// Check if the code point is a quotation mark, which opens a quote (`QU` and
+/// `Pi`).
+pub(crate) fn is_initial_quote(c: char) -> bool {
+    contains(c, INITIAL_QUOTE)
+}
+
+/// Check if the code point is a quotation mark, which closes a quote (`QU` and
+/// `Pf`).
+pub(crate) fn is_final_quote(c: char) -> bool {
+    contains(c, FINAL_QUOTE)
+}
+
+/// Check if the code point has an east asian width of fullwidth, wide or
+/// halfwidth.
+pub(crate) fn is_east_asian(c: char) -> bool {
+    contains(c, EAST_ASIAN)
+}
+
+"""
+        + table("LINE_BREAK", "LineBreak", values, "Al")
+        + "\n"
+        + table(
+            "INITIAL_QUOTE",
+            None,
+            [lb[cp] == "QU" and gc[cp] == "Pi" for cp in range(MAX_CODE_POINT + 1)],
+            False,
+        )
+        + "\n"
+        + table(
+            "FINAL_QUOTE",
+            None,
+            [lb[cp] == "QU" and gc[cp] == "Pf" for cp in range(MAX_CODE_POINT + 1)],
+            False,
+        )
+        + "\n"
+        + table("EAST_ASIAN", None, [value in ("F", "W", "H") for value in ea], False),
+    )
+
+
+def gen_width(ucd):
+    gc = property_map(ucd("extracted/DerivedGeneralCategory.txt"), "Cn")
+    ea = property_map(ucd("EastAsianWidth.txt"), "N")
+    hst = property_map(ucd("HangulSyllableType.txt"), "NA")
+    ignorable = property_set(ucd("DerivedCoreProperties.txt"), "Default_Ignorable_Code_Point")
+    prepended = property_set(ucd("PropList.txt"), "Prepended_Concatenation_Mark")
+    emoji = property_set(ucd("emoji/emoji-data.txt"), "Emoji")
+    presentation = property_set(ucd("emoji/emoji-data.txt"), "Emoji_Presentation")
+
+    def width(cp):
+        # Format characters are invisible, except for the prepended marks like
+        # the Arabic number sign. The Hangul choseong filler is ignorable, but
+        # takes the place of a leading consonant.
+        if (
+            gc[cp] in ("Cc", "Mn", "Me")
+            or (gc[cp] == "Cf" and not prepended[cp])
+            or (ignorable[cp] and hst[cp] != "L")
+            or hst[cp] in ("V", "T")
+        ):
+            return "Zero"
+        if ea[cp] in ("W", "F") or presentation[cp]:
+            return "Wide"
+        if ea[cp] == "A":
+            return "Ambiguous"
+        return "Narrow"
+
+    write(
+        "width.rs",
+        header(
+            "The display width of code points",
+            "[UAX #11](https://www.unicode.org/reports/tr11/)",
+            properties="`East_Asian_Width`, `General_Category` and related",
+        )
+        + """
+use super::{contains, lookup};
+use CharWidth::*;
+
+/// How many terminal cells a code point takes on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CharWidth {
+    /// Control and format characters, default ignorable code points, and code
+    /// points which are rendered on top of others, like combining marks or
+    /// Hangul medial vowels.
+    Zero,
+    Narrow,
+    /// East asian wide and fullwidth code points, as well as emoji with a
+    /// default emoji presentation.
+    Wide,
+    /// East asian ambiguous code points, which are narrow or wide depending on
+    /// the context.
+    Ambiguous,
+}
+
+pub(crate) fn char_width(c: char) -> CharWidth {
+    lookup(c, CHAR_WIDTH).unwrap_or(CharWidth::Narrow)
+}
+
+/// Check if the code point is an emoji, which can be given an emoji
+/// presentation by a variation selector (`Emoji`).
+pub(crate) fn is_emoji(c: char) -> bool {
+    contains(c, EMOJI)
+}
+
+"""
+        + table("CHAR_WIDTH", "CharWidth", [width(cp) for cp in range(MAX_CODE_POINT + 1)], "Narrow")
+        + "\n"
+        + table("EMOJI", None, emoji, False),
+    )
+
+
+def main():
+    parser = argparse.ArgumentParser(description=__doc__.split("\n\n")[0])
+    parser.add_argument(
+        "--ucd",
+        default=os.path.join(ROOT, "target", "ucd", UCD_VERSION),
+        help="directory with the UCD files, missing ones are downloaded",
+    )
+    args = parser.parse_args()
+
+    def ucd(name):
+        return ucd_file(args.ucd, name)
+
+    gen_grapheme(ucd)
+    gen_word(ucd)
+    gen_sentence(ucd)
+    gen_line_break(ucd)
+    gen_width(ucd)
+
+
+if __name__ == "__main__":
+    main()
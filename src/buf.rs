@@ -0,0 +1,242 @@
+use std::{
+    borrow::Borrow,
+    fmt::{Debug, Display},
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+
+use crate::{ToUTF8Chars, UTF8Char};
+
+/// The number of bytes an [`UTF8CharBuf`] stores without allocating.
+const INLINE_CAPACITY: usize = 22;
+
+#[derive(Clone)]
+enum Repr {
+    Inline {
+        len: u8,
+        bytes: [u8; INLINE_CAPACITY],
+    },
+    Heap(Box<str>),
+}
+
+/// An owned [`UTF8Char`].
+///
+/// Chars of up to 22 bytes, which covers almost all of them, are stored
+/// inline without allocating. Equality, ordering and hashing are the same as
+/// those of the `&str` representation, so a `HashSet<UTF8CharBuf>` can be
+/// queried with a `&str`.
+#[derive(Clone)]
+pub struct UTF8CharBuf {
+    repr: Repr,
+}
+
+impl UTF8CharBuf {
+    pub fn as_str(&self) -> &str {
+        match &self.repr {
+            // SAFETY: The bytes are copied from a [`UTF8Char`].
+            Repr::Inline { len, bytes } => unsafe {
+                std::str::from_utf8_unchecked(&bytes[..*len as usize])
+            },
+            Repr::Heap(utf8_char) => utf8_char,
+        }
+    }
+
+    /// Borrow the char as an [`UTF8Char`].
+    pub fn as_utf8_char(&self) -> UTF8Char<'_> {
+        self.as_str().into()
+    }
+}
+
+impl<'a> UTF8Char<'a> {
+    /// Copy the char into an [`UTF8CharBuf`], which does not borrow from the
+    /// source.
+    pub fn to_owned(&self) -> UTF8CharBuf {
+        let repr = match self.bytes.len() {
+            len if len <= INLINE_CAPACITY => {
+                let mut bytes = [0; INLINE_CAPACITY];
+                bytes[..len].copy_from_slice(self.bytes);
+
+                Repr::Inline {
+                    len: len as u8,
+                    bytes,
+                }
+            }
+            _ => Repr::Heap(self.as_str().into()),
+        };
+
+        UTF8CharBuf { repr }
+    }
+}
+
+impl<'a> From<UTF8Char<'a>> for UTF8CharBuf {
+    fn from(val: UTF8Char<'a>) -> Self {
+        val.to_owned()
+    }
+}
+
+impl Borrow<str> for UTF8CharBuf {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for UTF8CharBuf {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq for UTF8CharBuf {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for UTF8CharBuf {}
+
+impl<'a> PartialEq<UTF8Char<'a>> for UTF8CharBuf {
+    fn eq(&self, other: &UTF8Char<'a>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl PartialEq<str> for UTF8CharBuf {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for UTF8CharBuf {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialOrd for UTF8CharBuf {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for UTF8CharBuf {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl Hash for UTF8CharBuf {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl Debug for UTF8CharBuf {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "'{}'", self.as_str())
+    }
+}
+
+impl Display for UTF8CharBuf {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for UTF8CharBuf {
+    type Err = ParseUTF8CharError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.utf8_chars();
+
+        let Some(utf8_char) = chars.next() else {
+            return Err(ParseUTF8CharError {
+                kind: ParseUTF8CharErrorKind::Empty,
+            });
+        };
+
+        if chars.next().is_some() {
+            return Err(ParseUTF8CharError {
+                kind: ParseUTF8CharErrorKind::MultipleChars,
+            });
+        }
+
+        Ok(utf8_char.to_owned())
+    }
+}
+
+/// The string an [`UTF8CharBuf`] was parsed from is not exactly one
+/// [`UTF8Char`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseUTF8CharError {
+    kind: ParseUTF8CharErrorKind,
+}
+
+impl ParseUTF8CharError {
+    pub fn kind(&self) -> ParseUTF8CharErrorKind {
+        self.kind
+    }
+}
+
+impl Display for ParseUTF8CharError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.kind {
+            ParseUTF8CharErrorKind::Empty => write!(f, "cannot parse char from empty string"),
+            ParseUTF8CharErrorKind::MultipleChars => {
+                write!(f, "string contains more than one char")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseUTF8CharError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseUTF8CharErrorKind {
+    Empty,
+    MultipleChars,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::{ParseUTF8CharErrorKind, ToUTF8Chars, UTF8CharBuf};
+
+    #[test]
+    fn utf8_char_buf_outlives_source() {
+        let chars = {
+            let source = String::from("a👨‍👩‍👧‍👦🏴󠁧󠁢󠁳󠁣󠁴󠁿");
+            source
+                .utf8_chars()
+                .map(|c| c.to_owned())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(chars, ["a", "👨‍👩‍👧‍👦", "🏴󠁧󠁢󠁳󠁣󠁴󠁿"]);
+        assert_eq!(chars[2].as_utf8_char(), "🏴󠁧󠁢󠁳󠁣󠁴󠁿");
+    }
+
+    #[test]
+    fn utf8_char_buf_lookup_by_str() {
+        let symbols = "e\u{301}🇩🇪"
+            .utf8_chars()
+            .map(UTF8CharBuf::from)
+            .collect::<HashSet<_>>();
+
+        assert!(symbols.contains("🇩🇪"));
+        assert!(symbols.contains("e\u{301}"));
+        assert!(!symbols.contains("e"));
+    }
+
+    #[test]
+    fn utf8_char_buf_from_str() {
+        let utf8_char = "👍🏽".parse::<UTF8CharBuf>().unwrap();
+        assert_eq!(utf8_char.to_string(), "👍🏽");
+
+        let err = "".parse::<UTF8CharBuf>().unwrap_err();
+        assert_eq!(err.kind(), ParseUTF8CharErrorKind::Empty);
+
+        let err = "ab".parse::<UTF8CharBuf>().unwrap_err();
+        assert_eq!(err.kind(), ParseUTF8CharErrorKind::MultipleChars);
+    }
+}
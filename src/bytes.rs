@@ -0,0 +1,219 @@
+use std::fmt::Display;
+
+use crate::{next_utf8_char, CodePointError, CodePointErrorKind, UTF8Char, REPLACEMENT_CHARACTER};
+
+/// An invalid utf-8 sequence found while iterating over a byte slice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Utf8Error {
+    offset: usize,
+    error: CodePointError,
+}
+
+impl Utf8Error {
+    pub(crate) fn new(offset: usize, error: CodePointError) -> Self {
+        Utf8Error { offset, error }
+    }
+
+    /// The byte offset at which the invalid sequence starts.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The number of bytes the invalid sequence consists of.
+    pub fn error_len(&self) -> usize {
+        self.error.invalid_len()
+    }
+
+    /// Why the sequence is invalid.
+    pub fn kind(&self) -> CodePointErrorKind {
+        self.error.kind()
+    }
+}
+
+impl Display for Utf8Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid utf-8 sequence of {} bytes at byte offset {}: {}",
+            self.error_len(),
+            self.offset,
+            self.kind()
+        )
+    }
+}
+
+impl std::error::Error for Utf8Error {}
+
+/// An item of [`RawUTF8Chars`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RawUTF8Char<'a> {
+    Char(UTF8Char<'a>),
+    /// An invalid sequence, with the bytes it consists of.
+    Invalid(&'a [u8]),
+}
+
+/// An iterator over the [`UTF8Char`]s of a byte slice, which yields an error
+/// for every invalid sequence.
+///
+/// Invalid sequences always end the char in front of them.
+#[derive(Debug)]
+pub struct StrictUTF8Chars<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Iterator for StrictUTF8Chars<'a> {
+    type Item = Result<UTF8Char<'a>, Utf8Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match next_utf8_char(self.bytes)? {
+            Ok((utf8_char, remaining_bytes)) => {
+                self.offset += utf8_char.bytes.len();
+                self.bytes = remaining_bytes;
+
+                Some(Ok(utf8_char))
+            }
+            Err(error) => {
+                let err = Utf8Error::new(self.offset, error);
+
+                self.offset += err.error_len();
+                self.bytes = &self.bytes[err.error_len()..];
+
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+/// An iterator over the [`UTF8Char`]s of a byte slice, which replaces every
+/// invalid sequence with a [`REPLACEMENT_CHARACTER`].
+#[derive(Debug)]
+pub struct LossyUTF8Chars<'a> {
+    inner: StrictUTF8Chars<'a>,
+}
+
+impl<'a> Iterator for LossyUTF8Chars<'a> {
+    type Item = UTF8Char<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(
+            self.inner
+                .next()?
+                .unwrap_or_else(|_| REPLACEMENT_CHARACTER.into()),
+        )
+    }
+}
+
+/// An iterator over the [`UTF8Char`]s of a byte slice, which returns every
+/// invalid sequence as [`RawUTF8Char::Invalid`].
+#[derive(Debug)]
+pub struct RawUTF8Chars<'a> {
+    inner: StrictUTF8Chars<'a>,
+}
+
+impl<'a> Iterator for RawUTF8Chars<'a> {
+    type Item = RawUTF8Char<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.inner.bytes;
+
+        match self.inner.next()? {
+            Ok(utf8_char) => Some(RawUTF8Char::Char(utf8_char)),
+            Err(err) => Some(RawUTF8Char::Invalid(&bytes[..err.error_len()])),
+        }
+    }
+}
+
+pub trait BytesToUTF8Chars<'a> {
+    fn utf8_chars_strict(&'a self) -> StrictUTF8Chars<'a>;
+    fn utf8_chars_lossy(&'a self) -> LossyUTF8Chars<'a>;
+    fn utf8_chars_raw(&'a self) -> RawUTF8Chars<'a>;
+}
+
+impl<'a> BytesToUTF8Chars<'a> for [u8] {
+    fn utf8_chars_strict(&'a self) -> StrictUTF8Chars<'a> {
+        StrictUTF8Chars {
+            bytes: self,
+            offset: 0,
+        }
+    }
+
+    fn utf8_chars_lossy(&'a self) -> LossyUTF8Chars<'a> {
+        LossyUTF8Chars {
+            inner: self.utf8_chars_strict(),
+        }
+    }
+
+    fn utf8_chars_raw(&'a self) -> RawUTF8Chars<'a> {
+        RawUTF8Chars {
+            inner: self.utf8_chars_strict(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BytesToUTF8Chars, CodePointErrorKind, RawUTF8Char};
+
+    const INVALID: &[u8] = b"a\xcc\x81\x80\xe2\x82\xe2\x82\xac\xf0\x9f";
+
+    #[test]
+    fn utf8_chars_strict() {
+        let mut chars = INVALID.utf8_chars_strict();
+
+        assert_eq!(chars.next(), Some(Ok("a\u{301}".into())));
+
+        let err = chars.next().unwrap().unwrap_err();
+        assert_eq!(err.offset(), 3);
+        assert_eq!(err.error_len(), 1);
+        assert_eq!(err.kind(), CodePointErrorKind::UnexpectedContinuationByte);
+
+        let err = chars.next().unwrap().unwrap_err();
+        assert_eq!(err.offset(), 4);
+        assert_eq!(err.error_len(), 2);
+        assert_eq!(err.kind(), CodePointErrorKind::ExpectedContinuationByte);
+
+        assert_eq!(chars.next(), Some(Ok("€".into())));
+
+        let err = chars.next().unwrap().unwrap_err();
+        assert_eq!(err.offset(), 9);
+        assert_eq!(err.error_len(), 2);
+        assert_eq!(err.kind(), CodePointErrorKind::UnexpectedEnd);
+
+        assert_eq!(chars.next(), None);
+    }
+
+    #[test]
+    fn utf8_chars_lossy() {
+        let mut chars = INVALID.utf8_chars_lossy();
+
+        assert_eq!(chars.next(), Some("a\u{301}".into()));
+        assert_eq!(chars.next(), Some("\u{fffd}".into()));
+        assert_eq!(chars.next(), Some("\u{fffd}".into()));
+        assert_eq!(chars.next(), Some("€".into()));
+        assert_eq!(chars.next(), Some("\u{fffd}".into()));
+        assert_eq!(chars.next(), None);
+    }
+
+    #[test]
+    fn utf8_chars_raw() {
+        let mut chars = INVALID.utf8_chars_raw();
+
+        assert_eq!(chars.next(), Some(RawUTF8Char::Char("a\u{301}".into())));
+        assert_eq!(chars.next(), Some(RawUTF8Char::Invalid(b"\x80")));
+        assert_eq!(chars.next(), Some(RawUTF8Char::Invalid(b"\xe2\x82")));
+        assert_eq!(chars.next(), Some(RawUTF8Char::Char("€".into())));
+        assert_eq!(chars.next(), Some(RawUTF8Char::Invalid(b"\xf0\x9f")));
+        assert_eq!(chars.next(), None);
+    }
+
+    #[test]
+    fn utf8_chars_invalid_sequence_ends_char() {
+        let mut chars = b"a\xff\xcc\x81".utf8_chars_raw();
+
+        assert_eq!(chars.next(), Some(RawUTF8Char::Char("a".into())));
+        assert_eq!(chars.next(), Some(RawUTF8Char::Invalid(b"\xff")));
+        assert_eq!(chars.next(), Some(RawUTF8Char::Char("\u{301}".into())));
+        assert_eq!(chars.next(), None);
+    }
+}
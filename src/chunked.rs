@@ -0,0 +1,282 @@
+use std::borrow::Cow;
+
+use crate::grapheme::{is_sufficient_context, GraphemeState};
+
+/// What a [`ChunkedSegmenter`] found, or needs, to go on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChunkEvent {
+    /// There is a boundary at the given byte offset of the text.
+    Boundary(usize),
+    /// The text in front of the start offset is required to decide the next
+    /// boundary, see [`ChunkedSegmenter::provide_previous`].
+    NeedsPreviousChunk,
+    /// The chunk is used up, step on with the next one, or
+    /// [`finish`](ChunkedSegmenter::finish) if there is none.
+    NeedsNextChunk,
+}
+
+#[derive(Debug, Clone)]
+enum Context {
+    /// Nothing was seen yet, and there is nothing in front of the offset.
+    Start,
+    /// The text in front of the start offset, as far as it is known.
+    Unknown {
+        text: String,
+        complete: bool,
+    },
+    Known(GraphemeState),
+}
+
+/// A state machine that finds [`UTF8Char`](crate::UTF8Char) boundaries in a
+/// text which is split into chunks, like a rope.
+///
+/// The chunks are passed to [`step`](ChunkedSegmenter::step) one after the
+/// other, which returns a [`ChunkEvent`] at a time.
+#[derive(Debug, Clone)]
+pub struct ChunkedSegmenter {
+    offset: usize,
+    context: Context,
+}
+
+impl ChunkedSegmenter {
+    /// Create a segmenter at the start of a text.
+    pub fn new() -> Self {
+        ChunkedSegmenter {
+            offset: 0,
+            context: Context::Start,
+        }
+    }
+
+    /// Create a segmenter at the given byte offset of a text, which must be at
+    /// a code point boundary.
+    ///
+    /// Depending on the text in front of the offset, the segmenter might ask
+    /// for the previous chunks, before it can decide the first boundary.
+    pub fn at(offset: usize) -> Self {
+        ChunkedSegmenter {
+            offset,
+            context: Context::Unknown {
+                text: String::new(),
+                complete: offset == 0,
+            },
+        }
+    }
+
+    /// The byte offset of the text up to which the segmenter got.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Provide the chunk which ends where the text, which was provided
+    /// previously, starts. That is the text in front of the start offset in
+    /// the first call.
+    ///
+    /// Does nothing if the text in front of the offset is not needed anymore.
+    pub fn provide_previous(&mut self, chunk: &str) {
+        if let Context::Unknown { text, complete } = &mut self.context {
+            text.insert_str(0, chunk);
+            *complete = text.len() >= self.offset;
+        }
+    }
+
+    /// Look for the next boundary in the given chunk, which starts at the
+    /// byte offset `chunk_start` of the text.
+    ///
+    /// The chunk has to contain the current [`offset`](ChunkedSegmenter::offset),
+    /// or end at it.
+    pub fn step(&mut self, chunk: &str, chunk_start: usize) -> ChunkEvent {
+        let mut local_offset = self.offset.saturating_sub(chunk_start);
+
+        while let Some(c) = chunk
+            .get(local_offset..)
+            .and_then(|rest| rest.chars().next())
+        {
+            let boundary = match &mut self.context {
+                Context::Start => {
+                    self.context = Context::Known(GraphemeState::new(c));
+                    true
+                }
+                Context::Unknown { text, complete } => {
+                    if !*complete && !is_sufficient_context(text) {
+                        return ChunkEvent::NeedsPreviousChunk;
+                    }
+
+                    match GraphemeState::preceding(text) {
+                        Some(mut state) => {
+                            let boundary = state.is_boundary_before(c);
+                            self.context = Context::Known(state);
+                            boundary
+                        }
+                        None => {
+                            self.context = Context::Known(GraphemeState::new(c));
+                            true
+                        }
+                    }
+                }
+                Context::Known(state) => state.is_boundary_before(c),
+            };
+
+            let boundary_offset = self.offset;
+            local_offset += c.len_utf8();
+            self.offset += c.len_utf8();
+
+            if boundary {
+                return ChunkEvent::Boundary(boundary_offset);
+            }
+        }
+
+        ChunkEvent::NeedsNextChunk
+    }
+
+    /// Signal that there is no next chunk.
+    ///
+    /// Returns the boundary at the end of the text, or [`None`] if the text is
+    /// empty.
+    pub fn finish(&mut self) -> Option<usize> {
+        match self.context {
+            Context::Start => None,
+            _ => Some(self.offset),
+        }
+    }
+}
+
+impl Default for ChunkedSegmenter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Splits a text, which is fed one chunk at a time, into
+/// [`UTF8Char`](crate::UTF8Char)s.
+///
+/// Chars which are inside of one chunk are borrowed, while those spanning
+/// multiple chunks are copied.
+#[derive(Debug, Clone, Default)]
+pub struct ChunkedUTF8Chars {
+    segmenter: ChunkedSegmenter,
+    /// The start of a char which might continue in the next chunk.
+    pending: String,
+}
+
+impl ChunkedUTF8Chars {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed the next chunk of the text, and get the chars it completes.
+    pub fn push<'c>(&mut self, chunk: &'c str) -> Vec<Cow<'c, str>> {
+        let chunk_start = self.segmenter.offset();
+        let mut char_start = 0;
+        let mut chars = Vec::new();
+
+        while let ChunkEvent::Boundary(offset) = self.segmenter.step(chunk, chunk_start) {
+            let char_end = offset - chunk_start;
+
+            if !self.pending.is_empty() {
+                let mut utf8_char = std::mem::take(&mut self.pending);
+                utf8_char.push_str(&chunk[..char_end]);
+                chars.push(Cow::Owned(utf8_char));
+            } else if char_end > 0 {
+                chars.push(Cow::Borrowed(&chunk[char_start..char_end]));
+            }
+
+            char_start = char_end;
+        }
+
+        self.pending.push_str(&chunk[char_start..]);
+        chars
+    }
+
+    /// Signal the end of the text, and get the last char.
+    pub fn finish(&mut self) -> Option<String> {
+        self.segmenter.finish()?;
+
+        (!self.pending.is_empty()).then(|| std::mem::take(&mut self.pending))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use crate::{ChunkEvent, ChunkedSegmenter, ChunkedUTF8Chars, ToUTF8Chars};
+
+    const TEXT: &str = "a👨‍👩‍👦🇩🇪🇫🇷e\u{301}\r\n\u{915}\u{94d}\u{937}🏳️‍🌈";
+
+    #[test]
+    fn chunked_utf8_chars_every_split() {
+        let expected = TEXT.utf8_chars().map(|c| c.as_str()).collect::<Vec<_>>();
+        let offsets = TEXT.char_indices().map(|(offset, _)| offset);
+
+        for first in offsets.clone() {
+            for second in offsets.clone().filter(|&offset| offset >= first) {
+                let mut chars = ChunkedUTF8Chars::new();
+                let mut actual = Vec::new();
+
+                actual.extend(chars.push(&TEXT[..first]));
+                actual.extend(chars.push(&TEXT[first..second]));
+                actual.extend(chars.push(&TEXT[second..]));
+                actual.extend(chars.finish().map(Cow::Owned));
+
+                assert_eq!(actual, expected, "split at {first} and {second}");
+            }
+        }
+    }
+
+    #[test]
+    fn chunked_utf8_chars_borrows_inner_chars() {
+        let mut chars = ChunkedUTF8Chars::new();
+
+        let first = chars.push("ab👨\u{200d}");
+        assert_eq!(first, ["a", "b"]);
+        assert!(first.iter().all(|c| matches!(c, Cow::Borrowed(_))));
+
+        let second = chars.push("👩c");
+        assert_eq!(second, ["👨\u{200d}👩"]);
+        assert!(matches!(second[0], Cow::Owned(_)));
+
+        assert_eq!(chars.finish().as_deref(), Some("c"));
+    }
+
+    #[test]
+    fn chunked_segmenter() {
+        let mut segmenter = ChunkedSegmenter::new();
+
+        assert_eq!(segmenter.step("a🇩", 0), ChunkEvent::Boundary(0));
+        assert_eq!(segmenter.step("a🇩", 0), ChunkEvent::Boundary(1));
+        assert_eq!(segmenter.step("a🇩", 0), ChunkEvent::NeedsNextChunk);
+        assert_eq!(segmenter.step("🇪b", 5), ChunkEvent::Boundary(9));
+        assert_eq!(segmenter.step("🇪b", 5), ChunkEvent::NeedsNextChunk);
+        assert_eq!(segmenter.finish(), Some(10));
+    }
+
+    #[test]
+    fn chunked_segmenter_needs_previous_chunk() {
+        // The regional indicator in front of the offset belongs to the second
+        // flag, which is only known after seeing all of them.
+        let chunks = ["🇩🇪", "🇫🇷🇮", "🇹a"];
+        let mut segmenter = ChunkedSegmenter::at(20);
+
+        assert_eq!(
+            segmenter.step(chunks[2], 20),
+            ChunkEvent::NeedsPreviousChunk
+        );
+        segmenter.provide_previous(chunks[1]);
+        assert_eq!(
+            segmenter.step(chunks[2], 20),
+            ChunkEvent::NeedsPreviousChunk
+        );
+        segmenter.provide_previous(chunks[0]);
+        assert_eq!(segmenter.step(chunks[2], 20), ChunkEvent::Boundary(24));
+        assert_eq!(segmenter.step(chunks[2], 20), ChunkEvent::NeedsNextChunk);
+        assert_eq!(segmenter.finish(), Some(25));
+    }
+
+    #[test]
+    fn chunked_segmenter_sufficient_context() {
+        let mut segmenter = ChunkedSegmenter::at(2);
+        segmenter.provide_previous("a\u{200d}");
+
+        assert_eq!(segmenter.step("👩", 2), ChunkEvent::Boundary(2));
+    }
+}
@@ -0,0 +1,300 @@
+use std::fmt::Display;
+
+/// An error that occurs when the bytes at the start of a slice are not the
+/// start of a valid code point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CodePointError {
+    offset: usize,
+    kind: CodePointErrorKind,
+}
+
+impl CodePointError {
+    /// The offset of the offending byte, relative to the start of the code
+    /// point.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Why the byte is invalid.
+    pub fn kind(&self) -> CodePointErrorKind {
+        self.kind
+    }
+
+    /// The number of bytes in front of the offending one, which can't be
+    /// part of any valid code point. At least one byte is always invalid.
+    pub(crate) fn invalid_len(&self) -> usize {
+        self.offset.max(1)
+    }
+}
+
+impl Display for CodePointError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at byte offset {}", self.kind, self.offset)
+    }
+}
+
+impl std::error::Error for CodePointError {}
+
+/// The reason a [`CodePointError`] occurred.
+///
+/// The rules follow [RFC 3629](https://www.rfc-editor.org/rfc/rfc3629#section-4).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CodePointErrorKind {
+    /// The bytes ended before the code point was complete.
+    UnexpectedEnd,
+    /// A continuation byte (`10xx_xxxx`) was found where a code point should
+    /// start.
+    UnexpectedContinuationByte,
+    /// The byte can never be the first byte of a code point (`1111_1xxx`).
+    InvalidFirstByte,
+    /// A byte which is not a continuation byte (`10xx_xxxx`) was found inside
+    /// a code point.
+    ExpectedContinuationByte,
+    /// The code point is encoded with more bytes than necessary (`C0`, `C1`,
+    /// `E0 80..9F` and `F0 80..8F`).
+    Overlong,
+    /// The code point is a UTF-16 surrogate (`ED A0..BF`).
+    Surrogate,
+    /// The code point is greater than `U+10FFFF` (`F4 90..BF` and `F5..F7`).
+    OutOfRange,
+}
+
+impl Display for CodePointErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CodePointErrorKind::UnexpectedEnd => write!(f, "unexpected end of code point"),
+            CodePointErrorKind::UnexpectedContinuationByte => {
+                write!(f, "unexpected continuation byte")
+            }
+            CodePointErrorKind::InvalidFirstByte => write!(f, "invalid first byte"),
+            CodePointErrorKind::ExpectedContinuationByte => {
+                write!(f, "expected continuation byte")
+            }
+            CodePointErrorKind::Overlong => write!(f, "overlong encoding"),
+            CodePointErrorKind::Surrogate => write!(f, "encoded surrogate"),
+            CodePointErrorKind::OutOfRange => write!(f, "code point out of range"),
+        }
+    }
+}
+
+/// Decode the code point at the start of the given bytes.
+///
+/// Returns the code point and its length in bytes, or [`None`] if there are
+/// no bytes left.
+pub(crate) fn next_code_point(bytes: &[u8]) -> Option<Result<(char, usize), CodePointError>> {
+    if bytes.is_empty() {
+        return None;
+    }
+
+    Some(decode_code_point(bytes))
+}
+
+fn decode_code_point(bytes: &[u8]) -> Result<(char, usize), CodePointError> {
+    let first_byte = bytes[0];
+    let len = first_byte_len(first_byte).map_err(|kind| CodePointError { offset: 0, kind })?;
+
+    // Only some of the first bytes restrict the range of the second one.
+    let (second_byte_range, second_byte_kind) = match first_byte {
+        0xe0 => (0xa0..=0xbf, CodePointErrorKind::Overlong),
+        0xed => (0x80..=0x9f, CodePointErrorKind::Surrogate),
+        0xf0 => (0x90..=0xbf, CodePointErrorKind::Overlong),
+        0xf4 => (0x80..=0x8f, CodePointErrorKind::OutOfRange),
+        _ => (0x80..=0xbf, CodePointErrorKind::ExpectedContinuationByte),
+    };
+
+    let mut value = u32::from(first_byte) & [0x7f, 0x1f, 0x0f, 0x07][len - 1];
+
+    // The continuation bytes are checked before the length, so that a code
+    // point which is followed by a new one isn't reported as truncated.
+    for (offset, &byte) in bytes.iter().enumerate().take(len).skip(1) {
+        if byte & 0b1100_0000 != 0b1000_0000 {
+            return Err(CodePointError {
+                offset,
+                kind: CodePointErrorKind::ExpectedContinuationByte,
+            });
+        }
+
+        if offset == 1 && !second_byte_range.contains(&byte) {
+            return Err(CodePointError {
+                offset,
+                kind: second_byte_kind,
+            });
+        }
+
+        value = value << 6 | u32::from(byte & 0b0011_1111);
+    }
+
+    if bytes.len() < len {
+        return Err(CodePointError {
+            offset: bytes.len(),
+            kind: CodePointErrorKind::UnexpectedEnd,
+        });
+    }
+
+    match char::from_u32(value) {
+        Some(code_point) => Ok((code_point, len)),
+        None => Err(CodePointError {
+            offset: 0,
+            kind: CodePointErrorKind::OutOfRange,
+        }),
+    }
+}
+
+/// Get the length of a code point.
+///
+/// # Panics
+///
+/// If the following assumptions are incorrect:
+///
+/// - The function is called with the start of the code point at index 0.
+/// - The function is called with valid utf-8 bytes.
+///
+/// Use [`try_code_point_len`] for bytes which are not known to be valid.
+#[deprecated(note = "use `try_code_point_len`, which doesn't panic on invalid bytes")]
+pub fn code_point_len(bytes: &[u8]) -> usize {
+    match try_code_point_len(bytes) {
+        Ok(len) => len,
+        Err(err) => panic!("{}", err),
+    }
+}
+
+/// Get the length of the code point at the start of the given bytes.
+///
+/// Fails if the first byte can't start a code point, or if there are fewer
+/// bytes than the first byte announces. Only the length is checked, to
+/// validate the whole code point use
+/// [`utf8_chars_strict`](crate::BytesToUTF8Chars::utf8_chars_strict).
+pub fn try_code_point_len(bytes: &[u8]) -> Result<usize, CodePointError> {
+    let Some(&first_byte) = bytes.first() else {
+        return Err(CodePointError {
+            offset: 0,
+            kind: CodePointErrorKind::UnexpectedEnd,
+        });
+    };
+
+    let len = first_byte_len(first_byte).map_err(|kind| CodePointError { offset: 0, kind })?;
+
+    if bytes.len() < len {
+        return Err(CodePointError {
+            offset: bytes.len(),
+            kind: CodePointErrorKind::UnexpectedEnd,
+        });
+    }
+
+    Ok(len)
+}
+
+/// Get the length of a code point from its first byte.
+fn first_byte_len(first_byte: u8) -> Result<usize, CodePointErrorKind> {
+    match first_byte {
+        0x00..=0x7f => Ok(1),
+        0x80..=0xbf => Err(CodePointErrorKind::UnexpectedContinuationByte),
+        0xc0..=0xc1 => Err(CodePointErrorKind::Overlong),
+        0xc2..=0xdf => Ok(2),
+        0xe0..=0xef => Ok(3),
+        0xf0..=0xf4 => Ok(4),
+        0xf5..=0xf7 => Err(CodePointErrorKind::OutOfRange),
+        0xf8..=0xff => Err(CodePointErrorKind::InvalidFirstByte),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{code_point::next_code_point, try_code_point_len, CodePointErrorKind};
+
+    #[test]
+    fn try_code_point_len_valid() {
+        assert_eq!(try_code_point_len("A".as_bytes()), Ok(1));
+        assert_eq!(try_code_point_len("±".as_bytes()), Ok(2));
+        assert_eq!(try_code_point_len("⚽".as_bytes()), Ok(3));
+        assert_eq!(try_code_point_len("🫥".as_bytes()), Ok(4));
+    }
+
+    #[test]
+    fn try_code_point_len_empty() {
+        let err = try_code_point_len(&[]).unwrap_err();
+
+        assert_eq!(err.offset(), 0);
+        assert_eq!(err.kind(), CodePointErrorKind::UnexpectedEnd);
+    }
+
+    #[test]
+    fn try_code_point_len_continuation_byte() {
+        let err = try_code_point_len(&[0b1000_0000, b'A']).unwrap_err();
+
+        assert_eq!(err.offset(), 0);
+        assert_eq!(err.kind(), CodePointErrorKind::UnexpectedContinuationByte);
+    }
+
+    #[test]
+    fn try_code_point_len_invalid_first_byte() {
+        let err = try_code_point_len(&[0b1111_1000, 0b1000_0000]).unwrap_err();
+
+        assert_eq!(err.offset(), 0);
+        assert_eq!(err.kind(), CodePointErrorKind::InvalidFirstByte);
+    }
+
+    #[test]
+    fn try_code_point_len_truncated() {
+        let err = try_code_point_len(&"🫥".as_bytes()[..2]).unwrap_err();
+
+        assert_eq!(err.offset(), 2);
+        assert_eq!(err.kind(), CodePointErrorKind::UnexpectedEnd);
+        assert_eq!(
+            err.to_string(),
+            "unexpected end of code point at byte offset 2"
+        );
+    }
+
+    #[test]
+    fn next_code_point_valid() {
+        assert_eq!(next_code_point(b"\x7f"), Some(Ok(('\u{7f}', 1))));
+        assert_eq!(next_code_point(b"\xc2\x80"), Some(Ok(('\u{80}', 2))));
+        assert_eq!(next_code_point(b"\xe0\xa0\x80"), Some(Ok(('\u{800}', 3))));
+        assert_eq!(next_code_point(b"\xed\x9f\xbf"), Some(Ok(('\u{d7ff}', 3))));
+        assert_eq!(
+            next_code_point(b"\xf0\x90\x80\x80"),
+            Some(Ok(('\u{10000}', 4)))
+        );
+        assert_eq!(
+            next_code_point(b"\xf4\x8f\xbf\xbf"),
+            Some(Ok(('\u{10ffff}', 4)))
+        );
+        assert_eq!(next_code_point(b""), None);
+    }
+
+    #[test]
+    fn next_code_point_invalid() {
+        let cases: &[(&[u8], usize, CodePointErrorKind)] = &[
+            (b"\x80", 0, CodePointErrorKind::UnexpectedContinuationByte),
+            (
+                b"\xf8\x80\x80\x80\x80",
+                0,
+                CodePointErrorKind::InvalidFirstByte,
+            ),
+            (b"\xc0\xaf", 0, CodePointErrorKind::Overlong),
+            (b"\xc1\xbf", 0, CodePointErrorKind::Overlong),
+            (b"\xe0\x9f\xbf", 1, CodePointErrorKind::Overlong),
+            (b"\xf0\x8f\xbf\xbf", 1, CodePointErrorKind::Overlong),
+            (b"\xed\xa0\x80", 1, CodePointErrorKind::Surrogate),
+            (b"\xed\xbf\xbf", 1, CodePointErrorKind::Surrogate),
+            (b"\xf4\x90\x80\x80", 1, CodePointErrorKind::OutOfRange),
+            (b"\xf5\x80\x80\x80", 0, CodePointErrorKind::OutOfRange),
+            (b"\xf7\xbf\xbf\xbf", 0, CodePointErrorKind::OutOfRange),
+            (
+                b"\xe2\x82A",
+                2,
+                CodePointErrorKind::ExpectedContinuationByte,
+            ),
+            (b"\xe2A", 1, CodePointErrorKind::ExpectedContinuationByte),
+            (b"\xf0\x9f\x98", 3, CodePointErrorKind::UnexpectedEnd),
+        ];
+
+        for &(bytes, offset, kind) in cases {
+            let err = next_code_point(bytes).unwrap().unwrap_err();
+
+            assert_eq!(err.offset(), offset, "{:x?}", bytes);
+            assert_eq!(err.kind(), kind, "{:x?}", bytes);
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use std::str::Chars;
+
+use crate::{
+    grapheme::{
+        follows_pictographic, is_emoji_modifier, is_tag, COMBINING_ENCLOSING_KEYCAP,
+        EMOJI_PRESENTATION_SELECTOR, TEXT_PRESENTATION_SELECTOR,
+    },
+    tables::is_extended_pictographic,
+    UTF8Char, CANCEL_TAG, ZERO_WIDTH_JOINER,
+};
+
+/// The structure of an [`UTF8Char`], see [`UTF8Char::components`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UTF8CharComponents<'a> {
+    /// The part in front of the first joiner.
+    pub head: UTF8CharComponent<'a>,
+    /// The parts which are joined to the head by a [`ZERO_WIDTH_JOINER`], in
+    /// order.
+    pub joined: Vec<UTF8CharComponent<'a>>,
+}
+
+/// A part of an [`UTF8Char`], which is not split by a [`ZERO_WIDTH_JOINER`].
+///
+/// The optional parts are only recognized in the order of the fields.
+/// Everything after them ends up in `extensions`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UTF8CharComponent<'a> {
+    /// The first code point.
+    pub base: char,
+    /// A text (`U+FE0E`) or emoji (`U+FE0F`) presentation selector.
+    pub presentation: Option<char>,
+    /// One of the Fitzpatrick skin tone modifiers (`U+1F3FB` to `U+1F3FF`).
+    pub modifier: Option<char>,
+    /// Whether the base is enclosed in a keycap (`U+20E3`).
+    pub keycap: bool,
+    /// The tag characters, including the terminating [`CANCEL_TAG`].
+    pub tag_sequence: Option<&'a str>,
+    /// The remaining code points, like combining marks, or a joiner which
+    /// does not join anything.
+    pub extensions: &'a str,
+}
+
+impl<'a> UTF8Char<'a> {
+    /// Iterate over the code points the char consists of.
+    pub fn code_points(&self) -> Chars<'a> {
+        self.as_str().chars()
+    }
+
+    /// Split the char into its parts.
+    ///
+    /// For example `"👩🏽‍💻"` results in the head `"👩🏽"`, with the base
+    /// `'👩'` and a modifier, and the joined part `"💻"`.
+    ///
+    /// Returns [`None`] for an empty char, which has no base.
+    pub fn components(&self) -> Option<UTF8CharComponents<'a>> {
+        if self.as_str().is_empty() {
+            return None;
+        }
+
+        let mut parts = split_joined(self.as_str()).map(UTF8CharComponent::parse);
+
+        Some(UTF8CharComponents {
+            head: parts.next()?,
+            joined: parts.collect(),
+        })
+    }
+}
+
+/// Split the text at every joiner, which is preceded by a pictographic code
+/// point and optional extending code points, and followed by a pictographic
+/// code point (GB11).
+fn split_joined(text: &str) -> impl Iterator<Item = &str> {
+    let mut rest = Some(text);
+
+    std::iter::from_fn(move || {
+        let text = rest?;
+        let split = text.match_indices(ZERO_WIDTH_JOINER).find(|(idx, _)| {
+            let after = idx + ZERO_WIDTH_JOINER.len();
+            follows_pictographic(&text[..*idx])
+                && text[after..]
+                    .chars()
+                    .next()
+                    .is_some_and(is_extended_pictographic)
+        });
+
+        match split {
+            Some((idx, _)) => {
+                rest = Some(&text[idx + ZERO_WIDTH_JOINER.len()..]);
+                Some(&text[..idx])
+            }
+            None => rest.take(),
+        }
+    })
+}
+
+impl<'a> UTF8CharComponent<'a> {
+    fn parse(mut text: &'a str) -> Self {
+        let base = take(&mut text, |_| true).expect("a component is never empty");
+        let presentation = take(&mut text, |cp| {
+            cp == TEXT_PRESENTATION_SELECTOR || cp == EMOJI_PRESENTATION_SELECTOR
+        });
+        let modifier = take(&mut text, is_emoji_modifier);
+        let keycap = take(&mut text, |cp| cp == COMBINING_ENCLOSING_KEYCAP).is_some();
+
+        let tags_len = text.find(|cp| !is_tag(cp)).unwrap_or(text.len());
+        let tag_sequence = (tags_len > 0 && text[tags_len..].starts_with(CANCEL_TAG)).then(|| {
+            let (tag_sequence, rest) = text.split_at(tags_len + CANCEL_TAG.len());
+            text = rest;
+            tag_sequence
+        });
+
+        UTF8CharComponent {
+            base,
+            presentation,
+            modifier,
+            keycap,
+            tag_sequence,
+            extensions: text,
+        }
+    }
+}
+
+/// Take the first code point off the text, if it matches the predicate.
+fn take(text: &mut &str, predicate: impl Fn(char) -> bool) -> Option<char> {
+    let cp = text.chars().next().filter(|&cp| predicate(cp))?;
+
+    *text = &text[cp.len_utf8()..];
+    Some(cp)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ToUTF8Chars, UTF8Char, UTF8CharComponent};
+
+    fn component(base: char) -> UTF8CharComponent<'static> {
+        UTF8CharComponent {
+            base,
+            presentation: None,
+            modifier: None,
+            keycap: false,
+            tag_sequence: None,
+            extensions: "",
+        }
+    }
+
+    #[test]
+    fn utf8_char_code_points() {
+        let utf8_char = UTF8Char::from("🏳️‍🌈");
+
+        assert_eq!(
+            utf8_char.code_points().collect::<Vec<_>>(),
+            ['🏳', '\u{fe0f}', '\u{200d}', '🌈']
+        );
+    }
+
+    #[test]
+    fn components_zero_width_joiner_sequence() {
+        let components = "👩🏽‍💻".utf8_chars().next().unwrap().components().unwrap();
+
+        assert_eq!(
+            components.head,
+            UTF8CharComponent {
+                modifier: Some('\u{1f3fd}'),
+                ..component('👩')
+            }
+        );
+        assert_eq!(components.joined, [component('💻')]);
+    }
+
+    #[test]
+    fn components_keycap_and_tag_sequence() {
+        let keycap = UTF8Char::from("1\u{fe0f}\u{20e3}").components().unwrap();
+        assert_eq!(
+            keycap.head,
+            UTF8CharComponent {
+                presentation: Some('\u{fe0f}'),
+                keycap: true,
+                ..component('1')
+            }
+        );
+
+        let scotland = UTF8Char::from("🏴\u{e0067}\u{e0062}\u{e0073}\u{e0063}\u{e0074}\u{e007f}");
+        assert_eq!(
+            scotland.components().unwrap().head.tag_sequence,
+            Some("\u{e0067}\u{e0062}\u{e0073}\u{e0063}\u{e0074}\u{e007f}")
+        );
+    }
+
+    #[test]
+    fn components_extensions() {
+        let components = UTF8Char::from("e\u{301}\u{200d}").components().unwrap();
+
+        assert_eq!(
+            components.head,
+            UTF8CharComponent {
+                extensions: "\u{301}\u{200d}",
+                ..component('e')
+            }
+        );
+        assert!(components.joined.is_empty());
+    }
+
+    #[test]
+    fn components_joiner_after_non_pictographic_base() {
+        let components = UTF8Char::from("a\u{200d}👨").components().unwrap();
+
+        assert_eq!(
+            components.head,
+            UTF8CharComponent {
+                extensions: "\u{200d}👨",
+                ..component('a')
+            }
+        );
+        assert!(components.joined.is_empty());
+    }
+
+    #[test]
+    fn components_empty_char() {
+        assert_eq!(UTF8Char::from("").components(), None);
+    }
+}
@@ -0,0 +1,161 @@
+use crate::grapheme::{is_boundary_at, prev_boundary, GraphemeState};
+
+/// Random access to the [`UTF8Char`](crate::UTF8Char) boundaries of a string.
+///
+/// Unlike [`UTF8Chars`](crate::UTF8Chars), the queries don't start at the
+/// beginning of the string. They only look at as much of the text in front of
+/// the offset as the boundary rules require, like the preceding run of
+/// regional indicators, or the emoji a zero width joiner follows.
+///
+/// Since regional indicators are paired from the start of their run, a query
+/// inside of a long run takes time proportional to the part of the run in
+/// front of the offset. Stepping through such a run with
+/// [`next_boundary`](Self::next_boundary) or
+/// [`prev_boundary`](Self::prev_boundary) is therefore quadratic, iterate
+/// with [`UTF8Chars`](crate::UTF8Chars) instead, which keeps track of the
+/// pairing.
+#[derive(Debug, Clone, Copy)]
+pub struct GraphemeCursor<'a> {
+    text: &'a str,
+}
+
+impl<'a> GraphemeCursor<'a> {
+    pub fn new(text: &'a str) -> Self {
+        GraphemeCursor { text }
+    }
+
+    /// Check if a [`UTF8Char`](crate::UTF8Char) starts or ends at the given
+    /// byte offset.
+    ///
+    /// The start and end of the text are always boundaries, offsets inside of
+    /// a code point or past the end never are.
+    pub fn is_boundary(&self, offset: usize) -> bool {
+        self.text.is_char_boundary(offset) && is_boundary_at(self.text, offset)
+    }
+
+    /// Get the first boundary after the given byte offset.
+    ///
+    /// Returns [`None`] if the offset is at or past the end of the text.
+    pub fn next_boundary(&self, offset: usize) -> Option<usize> {
+        if offset >= self.text.len() {
+            return None;
+        }
+
+        let start = self.floor_char_boundary(offset);
+        let first = self.text[start..].chars().next()?;
+        let after_first = start + first.len_utf8();
+
+        let mut state = GraphemeState::preceding(&self.text[..after_first])?;
+        for (idx, c) in self.text[after_first..].char_indices() {
+            if state.is_boundary_before(c) {
+                return Some(after_first + idx);
+            }
+        }
+
+        Some(self.text.len())
+    }
+
+    /// Get the last boundary before the given byte offset.
+    ///
+    /// Returns [`None`] if the offset is at the start of the text.
+    pub fn prev_boundary(&self, offset: usize) -> Option<usize> {
+        if offset == 0 {
+            return None;
+        }
+        if offset > self.text.len() {
+            return Some(self.text.len());
+        }
+
+        // An offset inside of a code point is in front of the next one.
+        let end = (offset..self.text.len())
+            .find(|&idx| self.text.is_char_boundary(idx))
+            .unwrap_or(self.text.len());
+
+        Some(prev_boundary(self.text, end))
+    }
+
+    /// Get the start of the code point at the given byte offset.
+    fn floor_char_boundary(&self, offset: usize) -> usize {
+        (0..=offset.min(self.text.len()))
+            .rev()
+            .find(|&idx| self.text.is_char_boundary(idx))
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{GraphemeCursor, ToUTF8Chars};
+
+    #[test]
+    fn is_boundary() {
+        let text = "a🇩🇪🇫🇷e\u{301}";
+        let cursor = GraphemeCursor::new(text);
+
+        let boundaries = (0..=text.len() + 1)
+            .filter(|&offset| cursor.is_boundary(offset))
+            .collect::<Vec<_>>();
+
+        assert_eq!(boundaries, [0, 1, 9, 17, 20]);
+    }
+
+    #[test]
+    fn next_boundary() {
+        let text = "👨‍👩‍👦🇩🇪🇫a";
+        let cursor = GraphemeCursor::new(text);
+
+        assert_eq!(cursor.next_boundary(0), Some(18));
+        assert_eq!(cursor.next_boundary(5), Some(18));
+        assert_eq!(cursor.next_boundary(18), Some(26));
+        assert_eq!(cursor.next_boundary(26), Some(30));
+        assert_eq!(cursor.next_boundary(30), Some(31));
+        assert_eq!(cursor.next_boundary(31), None);
+    }
+
+    #[test]
+    fn prev_boundary() {
+        let text = "👨‍👩‍👦🇩🇪🇫a";
+        let cursor = GraphemeCursor::new(text);
+
+        assert_eq!(cursor.prev_boundary(31), Some(30));
+        assert_eq!(cursor.prev_boundary(30), Some(26));
+        assert_eq!(cursor.prev_boundary(29), Some(26));
+        assert_eq!(cursor.prev_boundary(26), Some(18));
+        assert_eq!(cursor.prev_boundary(18), Some(0));
+        assert_eq!(cursor.prev_boundary(0), None);
+    }
+
+    #[test]
+    fn prev_boundary_long_extend_run() {
+        let text = format!("a{}", "\u{301}".repeat(10_000));
+        let cursor = GraphemeCursor::new(&text);
+
+        assert_eq!(cursor.prev_boundary(text.len()), Some(0));
+        assert_eq!(cursor.prev_boundary(text.len() - 1), Some(0));
+        assert_eq!(cursor.prev_boundary(text.len() + 1), Some(text.len()));
+    }
+
+    #[test]
+    fn boundaries_match_utf8_chars() {
+        let text = "🏳️‍🌈A\u{1100}\u{1161}🇩🇪🇫🇷🇩\r\n\u{915}\u{94d}\u{937}";
+        let cursor = GraphemeCursor::new(text);
+        let starts = text
+            .utf8_char_indices()
+            .map(|(offset, _)| offset)
+            .collect::<Vec<_>>();
+
+        let mut forward = vec![0];
+        while let Some(offset) = cursor.next_boundary(*forward.last().unwrap()) {
+            forward.push(offset);
+        }
+
+        let mut backward = vec![text.len()];
+        while let Some(offset) = cursor.prev_boundary(*backward.last().unwrap()) {
+            backward.push(offset);
+        }
+        backward.reverse();
+
+        assert_eq!(forward[..forward.len() - 1], starts);
+        assert_eq!(forward, backward);
+    }
+}
@@ -0,0 +1,278 @@
+//! The extended grapheme cluster boundary rules of
+//! [UAX #29](https://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundary_Rules).
+
+use crate::tables::{
+    grapheme_cluster_break, indic_conjunct_break, is_extended_pictographic,
+    GraphemeClusterBreak as Gcb, IndicConjunctBreak as InCB,
+};
+
+/// The code point of [`ZERO_WIDTH_JOINER`](crate::ZERO_WIDTH_JOINER).
+pub(crate) const ZWJ: char = '\u{200d}';
+/// Requests the text presentation of the code point in front of it.
+pub(crate) const TEXT_PRESENTATION_SELECTOR: char = '\u{fe0e}';
+/// The code point of [`VARIATION_SELECTOR`](crate::VARIATION_SELECTOR), which
+/// requests the emoji presentation of the code point in front of it.
+pub(crate) const EMOJI_PRESENTATION_SELECTOR: char = '\u{fe0f}';
+/// Encloses the code point in front of it in a keycap.
+pub(crate) const COMBINING_ENCLOSING_KEYCAP: char = '\u{20e3}';
+
+/// Check if `c` is one of the Fitzpatrick skin tone modifiers.
+pub(crate) fn is_emoji_modifier(c: char) -> bool {
+    ('\u{1f3fb}'..='\u{1f3ff}').contains(&c)
+}
+
+/// Check if `c` is a regional indicator symbol, half of a flag.
+pub(crate) fn is_regional_indicator(c: char) -> bool {
+    grapheme_cluster_break(c) == Gcb::RegionalIndicator
+}
+
+/// Check if `c` is a tag character which may appear in the specification of
+/// an emoji tag sequence.
+pub(crate) fn is_tag(c: char) -> bool {
+    ('\u{e0020}'..='\u{e007e}').contains(&c)
+}
+
+/// Check if the text ends in an `Extended_Pictographic` code point, followed
+/// by zero or more `Extend`, that is the context a `ZWJ` must follow to join
+/// with the next pictograph (GB11).
+pub(crate) fn follows_pictographic(text: &str) -> bool {
+    text.chars()
+        .rev()
+        .find(|&c| grapheme_cluster_break(c) != Gcb::Extend)
+        .is_some_and(is_extended_pictographic)
+}
+
+/// Check if the text is enough context for [`GraphemeState::preceding`], even
+/// if there is more text in front of it.
+///
+/// This is the case if the text contains a code point, at which all the look
+/// backs stop.
+pub(crate) fn is_sufficient_context(text: &str) -> bool {
+    text.chars().rev().any(stops_look_back)
+}
+
+/// Check if all the look backs of [`GraphemeState::preceding`] stop at `c`.
+fn stops_look_back(c: char) -> bool {
+    !matches!(
+        grapheme_cluster_break(c),
+        Gcb::RegionalIndicator | Gcb::Extend | Gcb::Zwj
+    ) && !matches!(indic_conjunct_break(c), InCB::Extend | InCB::Linker)
+}
+
+/// Progress of the emoji ZWJ sequence rule (GB11).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EmojiState {
+    None,
+    /// An `Extended_Pictographic` code point, followed by zero or more `Extend`.
+    Pictographic,
+    /// The above, followed by a `ZWJ`.
+    PictographicZwj,
+}
+
+/// Progress of the Indic conjunct rule (GB9c).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ConjunctState {
+    None,
+    /// An `InCB=Consonant`, followed by zero or more `InCB=Extend`.
+    Consonant,
+    /// The above, with at least one `InCB=Linker` in the tail.
+    ConsonantLinker,
+}
+
+/// The context the boundary rules need about the code points that were
+/// already seen.
+///
+/// The state is fed one code point at a time and answers whether there is a
+/// boundary in front of it.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct GraphemeState {
+    prev: Gcb,
+    /// Whether an odd number of regional indicators directly precedes (GB12, GB13).
+    odd_regional_indicators: bool,
+    emoji: EmojiState,
+    conjunct: ConjunctState,
+}
+
+/// Check if there is a boundary at the given byte offset of the text.
+///
+/// Only as much of the text in front of the offset is looked at, as the
+/// rules require.
+pub(crate) fn is_boundary_at(text: &str, offset: usize) -> bool {
+    let Some(next) = text[offset..].chars().next() else {
+        // GB2
+        return true;
+    };
+
+    match GraphemeState::preceding(&text[..offset]) {
+        Some(state) => state.is_boundary(next),
+        // GB1
+        None => true,
+    }
+}
+
+/// Get the last boundary in front of the given byte offset, which must be
+/// the start of a code point, and not 0.
+///
+/// Instead of checking every code point on its own, which would look back over
+/// the same run of extending code points again and again, this walks back to
+/// a code point at which the look backs stop, and applies the rules forward
+/// from there.
+pub(crate) fn prev_boundary(text: &str, offset: usize) -> usize {
+    let mut end = offset;
+
+    loop {
+        let start = text[..end]
+            .char_indices()
+            .rev()
+            .find(|&(_, c)| stops_look_back(c))
+            .map_or(0, |(idx, _)| idx);
+
+        let mut chars = text[start..end].chars();
+        let first = chars.next().expect("the offset is not 0");
+        let mut state = GraphemeState::preceding(&text[..start + first.len_utf8()])
+            .expect("the text is not empty");
+        let mut boundary = is_boundary_at(text, start).then_some(start);
+        let mut idx = start + first.len_utf8();
+
+        for c in chars {
+            if state.is_boundary_before(c) {
+                boundary = Some(idx);
+            }
+            idx += c.len_utf8();
+        }
+
+        match boundary {
+            Some(boundary) => return boundary,
+            None => end = start,
+        }
+    }
+}
+
+impl GraphemeState {
+    /// Create the state after the first code point of a text (GB1).
+    pub(crate) fn new(first: char) -> Self {
+        let mut state = GraphemeState {
+            prev: Gcb::Other,
+            odd_regional_indicators: false,
+            emoji: EmojiState::None,
+            conjunct: ConjunctState::None,
+        };
+        state.update(first);
+        state
+    }
+
+    /// Create the state after the last code point of the given text, by
+    /// looking back at the context the rules require.
+    pub(crate) fn preceding(text: &str) -> Option<Self> {
+        let prev = text.chars().next_back()?;
+        let prev_gcb = grapheme_cluster_break(prev);
+
+        let regional_indicators = text
+            .chars()
+            .rev()
+            .take_while(|&c| grapheme_cluster_break(c) == Gcb::RegionalIndicator)
+            .count();
+
+        let emoji = match prev_gcb {
+            Gcb::Zwj if follows_pictographic(&text[..text.len() - prev.len_utf8()]) => {
+                EmojiState::PictographicZwj
+            }
+            Gcb::Zwj => EmojiState::None,
+            _ if follows_pictographic(text) => EmojiState::Pictographic,
+            _ => EmojiState::None,
+        };
+
+        let mut conjunct = ConjunctState::None;
+        let mut linker = false;
+        for c in text.chars().rev() {
+            match indic_conjunct_break(c) {
+                InCB::Consonant if linker => conjunct = ConjunctState::ConsonantLinker,
+                InCB::Consonant => conjunct = ConjunctState::Consonant,
+                InCB::Linker => {
+                    linker = true;
+                    continue;
+                }
+                InCB::Extend => continue,
+                InCB::None => {}
+            }
+
+            break;
+        }
+
+        Some(GraphemeState {
+            prev: prev_gcb,
+            odd_regional_indicators: regional_indicators % 2 == 1,
+            emoji,
+            conjunct,
+        })
+    }
+
+    /// Check if there is a boundary between the previous code point and `c`,
+    /// and advance past `c`.
+    pub(crate) fn is_boundary_before(&mut self, c: char) -> bool {
+        let boundary = self.is_boundary(c);
+        self.update(c);
+        boundary
+    }
+
+    fn is_boundary(&self, c: char) -> bool {
+        let next = grapheme_cluster_break(c);
+
+        match (self.prev, next) {
+            // GB3
+            (Gcb::Cr, Gcb::Lf) => false,
+            // GB4, GB5
+            (Gcb::Control | Gcb::Cr | Gcb::Lf, _) | (_, Gcb::Control | Gcb::Cr | Gcb::Lf) => true,
+            // GB6
+            (Gcb::L, Gcb::L | Gcb::V | Gcb::Lv | Gcb::Lvt) => false,
+            // GB7
+            (Gcb::Lv | Gcb::V, Gcb::V | Gcb::T) => false,
+            // GB8
+            (Gcb::Lvt | Gcb::T, Gcb::T) => false,
+            // GB9, GB9a
+            (_, Gcb::Extend | Gcb::Zwj | Gcb::SpacingMark) => false,
+            // GB9b
+            (Gcb::Prepend, _) => false,
+            // GB9c
+            _ if self.conjunct == ConjunctState::ConsonantLinker
+                && indic_conjunct_break(c) == InCB::Consonant =>
+            {
+                false
+            }
+            // GB11
+            (Gcb::Zwj, _)
+                if self.emoji == EmojiState::PictographicZwj && is_extended_pictographic(c) =>
+            {
+                false
+            }
+            // GB12, GB13
+            (Gcb::RegionalIndicator, Gcb::RegionalIndicator) => !self.odd_regional_indicators,
+            // GB999
+            _ => true,
+        }
+    }
+
+    fn update(&mut self, c: char) {
+        let gcb = grapheme_cluster_break(c);
+
+        self.odd_regional_indicators =
+            gcb == Gcb::RegionalIndicator && !self.odd_regional_indicators;
+
+        self.emoji = match (self.emoji, gcb) {
+            _ if is_extended_pictographic(c) => EmojiState::Pictographic,
+            (EmojiState::Pictographic, Gcb::Extend) => EmojiState::Pictographic,
+            (EmojiState::Pictographic, Gcb::Zwj) => EmojiState::PictographicZwj,
+            _ => EmojiState::None,
+        };
+
+        self.conjunct = match (self.conjunct, indic_conjunct_break(c)) {
+            (_, InCB::Consonant) => ConjunctState::Consonant,
+            (ConjunctState::None, _) => ConjunctState::None,
+            (_, InCB::Linker) => ConjunctState::ConsonantLinker,
+            (conjunct, InCB::Extend) => conjunct,
+            (_, InCB::None) => ConjunctState::None,
+        };
+
+        self.prev = gcb;
+    }
+}
@@ -0,0 +1,372 @@
+use std::fmt::Display;
+
+use crate::{
+    grapheme::follows_pictographic,
+    tables::{grapheme_cluster_break, is_extended_pictographic, GraphemeClusterBreak as Gcb},
+    UTF8Char, UTF8CharIndices, ZERO_WIDTH_JOINER,
+};
+
+/// What to do with a [`ZERO_WIDTH_JOINER`] inside of a [`UTF8Char`], which
+/// doesn't join two pictographic code points (GB11).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JoinerPolicy {
+    /// Keep the joiner in the char in front of it, as UAX #29 does.
+    #[default]
+    Attach,
+    /// Emit the joiner as its own char.
+    Separate,
+    /// Emit a [`JoinerError`] in place of the joiner.
+    Error,
+    /// Remove the joiner.
+    Drop,
+}
+
+/// Options to customize the behavior of [`UTF8Chars`](crate::UTF8Chars), see
+/// [`ToUTF8Chars::utf8_chars_with`](crate::ToUTF8Chars::utf8_chars_with).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct UTF8CharsOptions {
+    /// The policy for a joiner after a pictographic base, which is not followed
+    /// by another pictographic code point. For example at the end of the
+    /// input: `"👨\u{200d}"`, or in front of a combining mark.
+    pub dangling_joiner: JoinerPolicy,
+    /// The policy for a joiner after a non-pictographic base, which is never
+    /// joined with what follows (GB11). For example: `"a\u{200d}"`.
+    ///
+    /// Extending code points and other joiners between the base and the
+    /// joiner are skipped to find out which kind of joiner it is.
+    pub non_pictographic_joiner: JoinerPolicy,
+}
+
+/// A joiner for which [`JoinerPolicy::Error`] applies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JoinerError {
+    offset: usize,
+    kind: JoinerErrorKind,
+}
+
+impl JoinerError {
+    /// The byte offset of the joiner.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Why the joiner was reported.
+    pub fn kind(&self) -> JoinerErrorKind {
+        self.kind
+    }
+}
+
+impl Display for JoinerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at byte offset {}", self.kind, self.offset)
+    }
+}
+
+impl std::error::Error for JoinerError {}
+
+/// The kind of joiner a [`JoinerError`] was emitted for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoinerErrorKind {
+    /// See [`UTF8CharsOptions::dangling_joiner`].
+    Dangling,
+    /// See [`UTF8CharsOptions::non_pictographic_joiner`].
+    NonPictographic,
+}
+
+impl Display for JoinerErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JoinerErrorKind::Dangling => write!(f, "dangling zero width joiner"),
+            JoinerErrorKind::NonPictographic => {
+                write!(f, "zero width joiner after non-pictographic base")
+            }
+        }
+    }
+}
+
+/// An iterator over the [`UTF8Char`]s of a string, with [`UTF8CharsOptions`]
+/// applied.
+///
+/// Since an option can result in an error, the iterator yields [`Result`]s.
+/// Chars with a joiner which is separated, reported or dropped are split
+/// around it, so every part still points into the text.
+#[derive(Debug)]
+pub struct CheckedUTF8Chars<'a> {
+    chars: UTF8CharIndices<'a>,
+    options: UTF8CharsOptions,
+    /// The char which is currently split up, and its byte offset.
+    current: (usize, &'a str),
+    /// The byte offset of the part of the current char, which is left.
+    position: usize,
+}
+
+impl<'a> CheckedUTF8Chars<'a> {
+    pub(crate) fn new(chars: UTF8CharIndices<'a>, options: UTF8CharsOptions) -> Self {
+        CheckedUTF8Chars {
+            chars,
+            options,
+            current: (0, ""),
+            position: 0,
+        }
+    }
+
+    /// Find the next joiner in the rest of the current char, to which a
+    /// policy other than [`JoinerPolicy::Attach`] applies.
+    fn next_joiner(&self) -> Option<(usize, JoinerPolicy, JoinerErrorKind)> {
+        let (_, utf8_char) = self.current;
+
+        utf8_char[self.position..]
+            .match_indices(ZERO_WIDTH_JOINER)
+            .map(|(idx, _)| self.position + idx)
+            .filter(|&idx| {
+                let joins = follows_pictographic(&utf8_char[..idx])
+                    && utf8_char[idx + ZERO_WIDTH_JOINER.len()..]
+                        .chars()
+                        .next()
+                        .is_some_and(is_extended_pictographic);
+
+                !joins
+            })
+            .map(|idx| {
+                let (policy, kind) = if follows_pictographic_base(&utf8_char[..idx]) {
+                    (self.options.dangling_joiner, JoinerErrorKind::Dangling)
+                } else {
+                    (
+                        self.options.non_pictographic_joiner,
+                        JoinerErrorKind::NonPictographic,
+                    )
+                };
+
+                (idx, policy, kind)
+            })
+            .find(|&(_, policy, _)| policy != JoinerPolicy::Attach)
+    }
+}
+
+impl<'a> Iterator for CheckedUTF8Chars<'a> {
+    type Item = Result<UTF8Char<'a>, JoinerError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.position == self.current.1.len() {
+                let (offset, utf8_char) = self.chars.next()?;
+                self.current = (offset, utf8_char.as_str());
+                self.position = 0;
+            }
+
+            let (offset, utf8_char) = self.current;
+            let start = self.position;
+
+            let Some((idx, policy, kind)) = self.next_joiner() else {
+                self.position = utf8_char.len();
+                return Some(Ok(utf8_char[start..].into()));
+            };
+
+            if idx > start {
+                self.position = idx;
+                return Some(Ok(utf8_char[start..idx].into()));
+            }
+
+            self.position = idx + ZERO_WIDTH_JOINER.len();
+
+            match policy {
+                JoinerPolicy::Attach => unreachable!("attached joiners are not split off"),
+                // The joiner is taken from the text, so that it points into it.
+                JoinerPolicy::Separate => {
+                    return Some(Ok(utf8_char[idx..self.position].into()));
+                }
+                JoinerPolicy::Error => {
+                    return Some(Err(JoinerError {
+                        offset: offset + idx,
+                        kind,
+                    }));
+                }
+                JoinerPolicy::Drop => continue,
+            }
+        }
+    }
+}
+
+/// Check if the text ends in an `Extended_Pictographic` code point, followed
+/// by zero or more `Extend` code points and joiners.
+fn follows_pictographic_base(text: &str) -> bool {
+    text.chars()
+        .rev()
+        .find(|&c| !matches!(grapheme_cluster_break(c), Gcb::Extend | Gcb::Zwj))
+        .is_some_and(is_extended_pictographic)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{JoinerErrorKind, JoinerPolicy, ToUTF8Chars, UTF8Char, UTF8CharsOptions};
+
+    fn options(dangling_joiner: JoinerPolicy) -> UTF8CharsOptions {
+        UTF8CharsOptions {
+            dangling_joiner,
+            non_pictographic_joiner: JoinerPolicy::Attach,
+        }
+    }
+
+    #[test]
+    fn dangling_joiner_attach() {
+        let mut chars = "👨\u{200d}".utf8_chars_with(options(JoinerPolicy::Attach));
+
+        assert_eq!(chars.next(), Some(Ok("👨\u{200d}".into())));
+        assert_eq!(chars.next(), None);
+    }
+
+    #[test]
+    fn dangling_joiner_separate() {
+        let mut chars = "👨\u{200d}a".utf8_chars_with(options(JoinerPolicy::Separate));
+
+        assert_eq!(chars.next(), Some(Ok("👨".into())));
+        assert_eq!(chars.next(), Some(Ok("\u{200d}".into())));
+        assert_eq!(chars.next(), Some(Ok("a".into())));
+        assert_eq!(chars.next(), None);
+    }
+
+    #[test]
+    fn separated_joiner_points_into_text() {
+        let text = "👨\u{200d}";
+        let joiner = text
+            .utf8_chars_with(options(JoinerPolicy::Separate))
+            .nth(1)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(joiner.offset_in(text), Some(4));
+        assert_eq!(joiner, UTF8Char::from("\u{200d}"));
+    }
+
+    #[test]
+    fn dangling_joiner_error() {
+        let mut chars = "a👨\u{fe0f}\u{200d}".utf8_chars_with(options(JoinerPolicy::Error));
+
+        assert_eq!(chars.next(), Some(Ok("a".into())));
+        assert_eq!(chars.next(), Some(Ok("👨\u{fe0f}".into())));
+
+        let err = chars.next().unwrap().unwrap_err();
+        assert_eq!(err.offset(), 8);
+        assert_eq!(err.kind(), JoinerErrorKind::Dangling);
+
+        assert_eq!(chars.next(), None);
+    }
+
+    #[test]
+    fn dangling_joiner_drop() {
+        let mut chars = "👨\u{200d}👨\u{200d}".utf8_chars_with(options(JoinerPolicy::Drop));
+
+        assert_eq!(chars.next(), Some(Ok("👨\u{200d}👨".into())));
+        assert_eq!(chars.next(), None);
+    }
+
+    #[test]
+    fn non_pictographic_joiner() {
+        let options = UTF8CharsOptions {
+            dangling_joiner: JoinerPolicy::Attach,
+            non_pictographic_joiner: JoinerPolicy::Error,
+        };
+        let mut chars = "a\u{200d}b👨\u{200d}".utf8_chars_with(options);
+
+        assert_eq!(chars.next(), Some(Ok("a".into())));
+
+        let err = chars.next().unwrap().unwrap_err();
+        assert_eq!(err.offset(), 1);
+        assert_eq!(err.kind(), JoinerErrorKind::NonPictographic);
+
+        assert_eq!(chars.next(), Some(Ok("b".into())));
+        assert_eq!(chars.next(), Some(Ok("👨\u{200d}".into())));
+        assert_eq!(chars.next(), None);
+    }
+
+    #[test]
+    fn joiner_error_offset_after_first_char() {
+        let options = UTF8CharsOptions {
+            dangling_joiner: JoinerPolicy::Attach,
+            non_pictographic_joiner: JoinerPolicy::Error,
+        };
+        let mut chars = "ab\u{200d}".utf8_chars_with(options);
+
+        assert_eq!(chars.next(), Some(Ok("a".into())));
+        assert_eq!(chars.next(), Some(Ok("b".into())));
+        assert_eq!(chars.next().unwrap().unwrap_err().offset(), 2);
+    }
+
+    #[test]
+    fn non_pictographic_joiner_without_base() {
+        let options = UTF8CharsOptions {
+            dangling_joiner: JoinerPolicy::Attach,
+            non_pictographic_joiner: JoinerPolicy::Drop,
+        };
+        let mut chars = "\n\u{200d}a".utf8_chars_with(options);
+
+        assert_eq!(chars.next(), Some(Ok("\n".into())));
+        assert_eq!(chars.next(), Some(Ok("a".into())));
+        assert_eq!(chars.next(), None);
+    }
+
+    #[test]
+    fn dangling_joiner_before_extend() {
+        let mut chars = "👨\u{200d}\u{fe0f}".utf8_chars_with(options(JoinerPolicy::Drop));
+
+        assert_eq!(chars.next(), Some(Ok("👨".into())));
+        assert_eq!(chars.next(), Some(Ok("\u{fe0f}".into())));
+        assert_eq!(chars.next(), None);
+
+        let mut chars = "👨\u{200d}\u{fe0f}".utf8_chars_with(options(JoinerPolicy::Error));
+
+        assert_eq!(chars.next(), Some(Ok("👨".into())));
+        assert_eq!(
+            chars.next().unwrap().unwrap_err().kind(),
+            JoinerErrorKind::Dangling
+        );
+        assert_eq!(chars.next(), Some(Ok("\u{fe0f}".into())));
+        assert_eq!(chars.next(), None);
+    }
+
+    #[test]
+    fn non_pictographic_joiner_before_extend() {
+        let options = UTF8CharsOptions {
+            dangling_joiner: JoinerPolicy::Attach,
+            non_pictographic_joiner: JoinerPolicy::Separate,
+        };
+        let mut chars = "a\u{200d}\u{301}b".utf8_chars_with(options);
+
+        assert_eq!(chars.next(), Some(Ok("a".into())));
+        assert_eq!(chars.next(), Some(Ok("\u{200d}".into())));
+        assert_eq!(chars.next(), Some(Ok("\u{301}".into())));
+        assert_eq!(chars.next(), Some(Ok("b".into())));
+        assert_eq!(chars.next(), None);
+    }
+
+    #[test]
+    fn consecutive_dangling_joiners() {
+        let text = "👨\u{200d}\u{200d}👩";
+
+        let mut chars = text.utf8_chars_with(options(JoinerPolicy::Drop));
+        assert_eq!(chars.next(), Some(Ok("👨".into())));
+        assert_eq!(chars.next(), Some(Ok("👩".into())));
+        assert_eq!(chars.next(), None);
+
+        let mut chars = text.utf8_chars_with(options(JoinerPolicy::Error));
+        assert_eq!(chars.next(), Some(Ok("👨".into())));
+
+        for offset in [4, 7] {
+            let err = chars.next().unwrap().unwrap_err();
+            assert_eq!(err.offset(), offset);
+            assert_eq!(err.kind(), JoinerErrorKind::Dangling);
+        }
+
+        assert_eq!(chars.next(), Some(Ok("👩".into())));
+        assert_eq!(chars.next(), None);
+    }
+
+    #[test]
+    fn joined_joiners_are_kept() {
+        let mut chars = "👨\u{200d}👩\u{200d}".utf8_chars_with(options(JoinerPolicy::Separate));
+
+        assert_eq!(chars.next(), Some(Ok("👨\u{200d}👩".into())));
+        assert_eq!(chars.next(), Some(Ok("\u{200d}".into())));
+        assert_eq!(chars.next(), None);
+    }
+}
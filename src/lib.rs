@@ -1,9 +1,61 @@
-use std::fmt::Debug;
+mod buf;
+mod bytes;
+mod chunked;
+mod code_point;
+mod components;
+mod cursor;
+mod grapheme;
+mod joiner;
+mod line_break;
+mod reader;
+mod sentence;
+mod tables;
+pub mod text;
+mod truncate;
+mod width;
+mod word;
+
+use std::{
+    fmt::{Debug, Display},
+    hash::{Hash, Hasher},
+    ops::{Bound, RangeBounds},
+};
+
+pub use buf::{ParseUTF8CharError, ParseUTF8CharErrorKind, UTF8CharBuf};
+pub use bytes::{
+    BytesToUTF8Chars, LossyUTF8Chars, RawUTF8Char, RawUTF8Chars, StrictUTF8Chars, Utf8Error,
+};
+pub use chunked::{ChunkEvent, ChunkedSegmenter, ChunkedUTF8Chars};
+#[allow(deprecated)]
+pub use code_point::code_point_len;
+pub use code_point::{try_code_point_len, CodePointError, CodePointErrorKind};
+pub use components::{UTF8CharComponent, UTF8CharComponents};
+pub use cursor::GraphemeCursor;
+pub use joiner::{CheckedUTF8Chars, JoinerError, JoinerErrorKind, JoinerPolicy, UTF8CharsOptions};
+pub use line_break::{wrap, BreakOpportunity, ToUTF8LineBreaks, UTF8LineBreaks};
+pub use reader::UTF8CharReader;
+pub use sentence::{ToUTF8Sentences, UTF8Sentences};
+pub use truncate::{truncate_chars, truncate_width};
+pub use width::{AmbiguousWidth, DisplayWidth};
+pub use word::{ToUTF8Words, UTF8Words};
+
+use code_point::next_code_point;
+use grapheme::{is_regional_indicator, prev_boundary, GraphemeState, EMOJI_PRESENTATION_SELECTOR};
+use tables::is_extended_pictographic;
 
 pub const VARIATION_SELECTOR: &str = "\u{fe0f}";
 pub const ZERO_WIDTH_JOINER: &str = "\u{200d}";
+/// Replaces invalid utf-8 sequences in lossy conversions.
+pub const REPLACEMENT_CHARACTER: &str = "\u{fffd}";
+/// Terminates an emoji tag sequence.
+pub const CANCEL_TAG: &str = "\u{e007f}";
 
-#[derive(PartialEq, Eq)]
+/// An extended grapheme cluster, a user-perceived character.
+///
+/// Chars are ordered by their bytes, which for utf-8 is the same as ordering
+/// them by their code points. The order is not collation-aware, `"é"` sorts
+/// after `"z"`. Hashing is the same as for the `&str` representation.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct UTF8Char<'a> {
     bytes: &'a [u8],
 }
@@ -18,6 +70,38 @@ impl<'a> UTF8Char<'a> {
     pub fn is(&self, right: &str) -> bool {
         self.as_str() == right
     }
+
+    /// Check if the char is a well-formed emoji tag sequence, like the flag of
+    /// Scotland `"🏴󠁧󠁢󠁳󠁣󠁴󠁿"`.
+    ///
+    /// A tag sequence is a pictographic base, optionally followed by an emoji
+    /// presentation selector or modifier, one or more tag characters and a
+    /// terminating [`CANCEL_TAG`]. Chars which contain tag characters, but
+    /// don't have this shape, are malformed.
+    pub fn is_emoji_tag_sequence(&self) -> bool {
+        let Some(UTF8CharComponents { head, joined }) = self.components() else {
+            return false;
+        };
+
+        joined.is_empty()
+            && is_extended_pictographic(head.base)
+            && matches!(
+                (head.presentation, head.modifier),
+                (None | Some(EMOJI_PRESENTATION_SELECTOR), None) | (None, Some(_))
+            )
+            && !head.keycap
+            && head.tag_sequence.is_some()
+            && head.extensions.is_empty()
+    }
+
+    /// Get the byte offset of the char inside of the string it was taken from.
+    ///
+    /// Returns [`None`] if the char does not point into `source`.
+    pub fn offset_in(&self, source: &str) -> Option<usize> {
+        let offset = (self.bytes.as_ptr() as usize).checked_sub(source.as_ptr() as usize)?;
+
+        (offset + self.bytes.len() <= source.len()).then_some(offset)
+    }
 }
 
 impl<'a> Debug for UTF8Char<'a> {
@@ -26,6 +110,48 @@ impl<'a> Debug for UTF8Char<'a> {
     }
 }
 
+impl<'a> Display for UTF8Char<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> Hash for UTF8Char<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl<'a> AsRef<str> for UTF8Char<'a> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<'a> PartialEq<str> for UTF8Char<'a> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<'a> PartialEq<&str> for UTF8Char<'a> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<'a> PartialEq<UTF8Char<'a>> for str {
+    fn eq(&self, other: &UTF8Char<'a>) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl<'a> PartialEq<UTF8Char<'a>> for &str {
+    fn eq(&self, other: &UTF8Char<'a>) -> bool {
+        *self == other.as_str()
+    }
+}
+
 impl<'a> From<&'a str> for UTF8Char<'a> {
     fn from(val: &'a str) -> Self {
         UTF8Char {
@@ -34,154 +160,265 @@ impl<'a> From<&'a str> for UTF8Char<'a> {
     }
 }
 
+/// An iterator over the [`UTF8Char`]s of a string.
+///
+/// Regional indicator symbols are paired two at a time, so that a run of them
+/// results in one [`UTF8Char`] per flag. A left over indicator at the end of an
+/// odd-length run stands on its own.
 #[derive(Debug)]
 pub struct UTF8Chars<'a> {
     bytes: &'a [u8],
+    /// Whether the run of regional indicators at the end of `bytes` is known
+    /// to have an even length, so that flags can be taken off the end without
+    /// looking for the start of the run.
+    paired_regional_indicators: bool,
 }
 
 impl<'a> Iterator for UTF8Chars<'a> {
     type Item = UTF8Char<'a>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.bytes.is_empty() {
-            return None;
-        }
+        let (utf8_char, remaining_bytes) = next_utf8_char(self.bytes)?.ok()?;
 
-        let (cp_bytes, mut remaining_bytes) = next_code_point(self.bytes)?;
-        let mut utf8_char_len = cp_bytes.len();
+        self.bytes = remaining_bytes;
+        Some(utf8_char)
+    }
+}
 
-        // Variation selector
-        if let Some((variant_bytes, variant_remaining)) = variation_selector(remaining_bytes) {
-            utf8_char_len += variant_bytes.len();
-            remaining_bytes = variant_remaining;
+impl<'a> DoubleEndedIterator for UTF8Chars<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let text = self.as_str();
+        if text.is_empty() {
+            return None;
         }
 
-        // Zero with joiner
-        // A utf-8 char can consist out of one or more joined together code points.
-        while let Some((joined_bytes, join_remaining)) = zero_width_joiner(remaining_bytes) {
-            utf8_char_len += joined_bytes.len();
-            remaining_bytes = join_remaining;
-        }
+        let start = self
+            .paired_regional_indicators
+            .then(|| paired_flag_start(text))
+            .flatten()
+            .unwrap_or_else(|| prev_boundary(text, text.len()));
+
+        // A char, which starts with a regional indicator right after another
+        // one, is only split off if the indicators in front of it pair up
+        // (GB12, GB13).
+        self.paired_regional_indicators = text[start..]
+            .chars()
+            .next()
+            .is_some_and(is_regional_indicator)
+            && text[..start]
+                .chars()
+                .next_back()
+                .is_some_and(is_regional_indicator);
+
+        self.bytes = &self.bytes[..start];
+        Some(text[start..].into())
+    }
+}
 
-        let utf8_char = UTF8Char {
-            bytes: &self.bytes[..utf8_char_len],
-        };
+/// Get the start of the flag at the end of a text, in which the regional
+/// indicators at the end pair up, if there is another indicator in front of
+/// the flag. Otherwise the flag might be part of a longer char.
+fn paired_flag_start(text: &str) -> Option<usize> {
+    let mut code_points = text.char_indices().rev();
+
+    match (code_points.next(), code_points.next(), code_points.next()) {
+        (Some((_, last)), Some((start, first)), Some((_, before)))
+            if is_regional_indicator(last)
+                && is_regional_indicator(first)
+                && is_regional_indicator(before) =>
+        {
+            Some(start)
+        }
+        _ => None,
+    }
+}
 
-        self.bytes = remaining_bytes;
-        Some(utf8_char)
+impl<'a> UTF8Chars<'a> {
+    /// View the text which is not yet iterated over.
+    pub fn as_str(&self) -> &'a str {
+        // SAFETY: [`UTF8Chars`] is only constructed from a string.
+        unsafe { std::str::from_utf8_unchecked(self.bytes) }
     }
 }
 
-fn variation_selector(bytes: &[u8]) -> Option<(&[u8], &[u8])> {
-    let (cp_bytes, remainder) = next_code_point(bytes)?;
-    let cp = bytes_as_str(cp_bytes);
+/// Split the [`UTF8Char`] at the start of the given bytes off.
+///
+/// Fails if the bytes don't start with a valid code point. An invalid code
+/// point after the first one ends the char.
+fn next_utf8_char(bytes: &[u8]) -> Option<Result<(UTF8Char<'_>, &[u8]), CodePointError>> {
+    let (first, mut utf8_char_len) = match next_code_point(bytes)? {
+        Ok(code_point) => code_point,
+        Err(err) => return Some(Err(err)),
+    };
+    let mut state = GraphemeState::new(first);
+
+    // A utf-8 char is an extended grapheme cluster, which can consist out of
+    // one or more code points. Extend it until the boundary rules say
+    // otherwise.
+    while let Some(Ok((cp, cp_len))) = next_code_point(&bytes[utf8_char_len..]) {
+        if state.is_boundary_before(cp) {
+            break;
+        }
 
-    if cp == VARIATION_SELECTOR {
-        return Some((cp_bytes, remainder));
+        utf8_char_len += cp_len;
     }
 
-    None
+    let (utf8_char_bytes, remaining_bytes) = bytes.split_at(utf8_char_len);
+    Some(Ok((
+        UTF8Char {
+            bytes: utf8_char_bytes,
+        },
+        remaining_bytes,
+    )))
 }
 
-fn zero_width_joiner(bytes: &[u8]) -> Option<(&[u8], &[u8])> {
-    let (joiner_bytes, remainder) = next_code_point(bytes)?;
-    let joiner = bytes_as_str(joiner_bytes);
+/// An iterator over the [`UTF8Char`]s of a string, and their byte offsets.
+#[derive(Debug)]
+pub struct UTF8CharIndices<'a> {
+    front_offset: usize,
+    chars: UTF8Chars<'a>,
+}
 
-    if joiner != ZERO_WIDTH_JOINER {
-        return None;
-    }
+impl<'a> Iterator for UTF8CharIndices<'a> {
+    type Item = (usize, UTF8Char<'a>);
 
-    if let Some((join_with_bytes, remainder)) = next_code_point(remainder) {
-        return Some((
-            &bytes[..joiner_bytes.len() + join_with_bytes.len()],
-            remainder,
-        ));
-    }
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.front_offset;
+        let utf8_char = self.chars.next()?;
 
-    // If this is reached, no code point followed the joiner. For now it's just
-    // "gracefully" ignored.
-    // TODO: figure out what the standard for error handling is.
-    Some((joiner_bytes, remainder))
+        self.front_offset += utf8_char.bytes.len();
+        Some((offset, utf8_char))
+    }
 }
 
-fn next_code_point(bytes: &[u8]) -> Option<(&[u8], &[u8])> {
-    if bytes.is_empty() {
-        return None;
-    }
+impl<'a> DoubleEndedIterator for UTF8CharIndices<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let utf8_char = self.chars.next_back()?;
 
-    Some(bytes.split_at(code_point_len(bytes)))
+        Some((self.front_offset + self.chars.bytes.len(), utf8_char))
+    }
 }
 
 pub trait ToUTF8Chars<'a> {
     fn utf8_chars(&'a self) -> UTF8Chars<'a>;
+    fn utf8_char_indices(&'a self) -> UTF8CharIndices<'a>;
+    /// Iterate over the [`UTF8Char`]s with the given options applied.
+    fn utf8_chars_with(&'a self, options: UTF8CharsOptions) -> CheckedUTF8Chars<'a>;
 }
 
 impl<'a> ToUTF8Chars<'a> for str {
     fn utf8_chars(&'a self) -> UTF8Chars<'a> {
         UTF8Chars {
             bytes: self.as_bytes(),
+            paired_regional_indicators: false,
+        }
+    }
+
+    fn utf8_char_indices(&'a self) -> UTF8CharIndices<'a> {
+        UTF8CharIndices {
+            front_offset: 0,
+            chars: self.utf8_chars(),
         }
     }
+
+    fn utf8_chars_with(&'a self, options: UTF8CharsOptions) -> CheckedUTF8Chars<'a> {
+        CheckedUTF8Chars::new(self.utf8_char_indices(), options)
+    }
 }
 
-/// Get the length of a code point.
-///
-/// # Panics
+/// Slicing a string by [`UTF8Char`] indices instead of byte offsets.
 ///
-/// If the following assumptions are incorrect:
-///
-/// - The function is called with the start of the code point at index 0.
-/// - The function is called with valid utf-8 bytes.
-pub fn code_point_len(bytes: &[u8]) -> usize {
-    let first_byte = &bytes[0];
+/// The returned slices borrow from the string.
+pub trait SliceUTF8Chars {
+    /// Count the [`UTF8Char`]s of the string.
+    fn grapheme_count(&self) -> usize;
+    /// Get the [`UTF8Char`] at the given index.
+    fn nth_grapheme(&self, n: usize) -> Option<UTF8Char<'_>>;
+    /// Get the [`UTF8Char`]s in the given range of indices.
+    ///
+    /// Returns [`None`] if the range is out of bounds.
+    fn grapheme_slice(&self, range: impl RangeBounds<usize>) -> Option<&str>;
+    /// Split the string in front of the [`UTF8Char`] with the given index.
+    ///
+    /// Returns [`None`] if the index is past the number of chars.
+    fn split_at_grapheme(&self, n: usize) -> Option<(&str, &str)>;
+}
 
-    if first_byte & 0b1000_0000 == 0 {
-        return 1;
+impl SliceUTF8Chars for str {
+    fn grapheme_count(&self) -> usize {
+        self.utf8_chars().count()
     }
 
-    if first_byte & 0b1110_0000 == 0b1100_0000 {
-        return 2;
+    fn nth_grapheme(&self, n: usize) -> Option<UTF8Char<'_>> {
+        self.utf8_chars().nth(n)
     }
 
-    if first_byte & 0b1111_0000 == 0b1110_0000 {
-        return 3;
-    }
+    fn grapheme_slice(&self, range: impl RangeBounds<usize>) -> Option<&str> {
+        let start_idx = match range.start_bound() {
+            Bound::Included(&idx) => idx,
+            Bound::Excluded(&idx) => idx.checked_add(1)?,
+            Bound::Unbounded => 0,
+        };
+        let end_idx = match range.end_bound() {
+            Bound::Included(&idx) => Some(idx.checked_add(1)?),
+            Bound::Excluded(&idx) => Some(idx),
+            Bound::Unbounded => None,
+        };
+
+        let start = grapheme_offset(self, start_idx)?;
+        let rest = &self[start..];
 
-    if first_byte & 0b1111_1000 == 0b1111_0000 {
-        return 4;
+        match end_idx {
+            Some(end_idx) => {
+                let len = grapheme_offset(rest, end_idx.checked_sub(start_idx)?)?;
+                Some(&rest[..len])
+            }
+            None => Some(rest),
+        }
     }
 
-    panic!("invalid first byte '{:08b}'", first_byte);
+    fn split_at_grapheme(&self, n: usize) -> Option<(&str, &str)> {
+        Some(self.split_at(grapheme_offset(self, n)?))
+    }
 }
 
-/// Converts the given byte array to an utf-8 string.
-///
-/// This conversion assumes that valid utf-8 bytes are passed.
-fn bytes_as_str(bytes: &[u8]) -> &str {
-    // SAFETY: This function should only be called with valid utf-8 bytes.
-    unsafe { std::str::from_utf8_unchecked(bytes) }
+/// Get the byte offset at which the [`UTF8Char`] with the given index starts,
+/// or the length of the text if the index is the number of chars.
+fn grapheme_offset(text: &str, n: usize) -> Option<usize> {
+    let Some(last) = n.checked_sub(1) else {
+        return Some(0);
+    };
+
+    let utf8_char = text.utf8_chars().nth(last)?;
+    Some(utf8_char.offset_in(text)? + utf8_char.bytes.len())
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{code_point_len, ToUTF8Chars};
+    #[allow(deprecated)]
+    use crate::code_point_len;
+    use crate::{SliceUTF8Chars, ToUTF8Chars, UTF8Char, VARIATION_SELECTOR};
 
     #[test]
+    #[allow(deprecated)]
     fn single_byte_code_point_len() {
         assert_eq!(code_point_len("A".as_bytes()), 1);
     }
 
     #[test]
+    #[allow(deprecated)]
     fn double_byte_code_point_len() {
         assert_eq!(code_point_len("±".as_bytes()), 2);
     }
 
     #[test]
+    #[allow(deprecated)]
     fn triple_byte_code_point_len() {
         assert_eq!(code_point_len("⚽".as_bytes()), 3);
     }
 
     #[test]
+    #[allow(deprecated)]
     fn quadruple_byte_code_point_len() {
         assert_eq!(code_point_len("🫥".as_bytes()), 4);
     }
@@ -213,6 +450,60 @@ mod tests {
         assert_eq!(chars.next(), None);
     }
 
+    #[test]
+    fn utf8_char_zero_width_joiner_component_suffixes() {
+        let sequences = [
+            "👁️‍🗨️",
+            "🏳️‍⚧️",
+            "🏴‍☠️",
+            "❤️‍🔥",
+            "❤️‍🩹",
+            "😶‍🌫️",
+            "🐻‍❄️",
+            "🧔🏻‍♂️",
+            "🏃🏾‍♀️‍➡️",
+            "👩🏽‍❤️‍💋‍👨🏾",
+            "👨🏻‍🤝‍👨🏿",
+            "🏴󠁧󠁢󠁳󠁣󠁴󠁿‍🔥",
+        ];
+
+        for sequence in sequences {
+            let input = format!("{sequence}{sequence}");
+            let mut chars = input.utf8_chars();
+
+            assert_eq!(chars.next(), Some(sequence.into()));
+            assert_eq!(chars.next(), Some(sequence.into()));
+            assert_eq!(chars.next(), None);
+        }
+    }
+
+    #[test]
+    fn utf8_char_emoji_modifier() {
+        for modifier in '\u{1f3fb}'..='\u{1f3ff}' {
+            let waving_hand = format!("👋{modifier}");
+            let input = format!("{waving_hand}A");
+            let mut chars = input.utf8_chars();
+
+            assert_eq!(chars.next(), Some(waving_hand.as_str().into()));
+            assert_eq!(chars.next(), Some("A".into()));
+            assert_eq!(chars.next(), None);
+        }
+    }
+
+    #[test]
+    fn utf8_char_emoji_modifier_zero_width_joiner() {
+        for modifier in '\u{1f3fb}'..='\u{1f3ff}' {
+            let astronaut = format!("🧑{modifier}\u{200d}🚀");
+            let couple = format!("🧑{modifier}\u{200d}🤝\u{200d}🧑{modifier}");
+            let input = format!("{astronaut}{couple}");
+            let mut chars = input.utf8_chars();
+
+            assert_eq!(chars.next(), Some(astronaut.as_str().into()));
+            assert_eq!(chars.next(), Some(couple.as_str().into()));
+            assert_eq!(chars.next(), None);
+        }
+    }
+
     #[test]
     fn utf_8_char_variation_selector_zero_width_joiner() {
         let mut chars = "🏳️‍🌈".utf8_chars();
@@ -220,4 +511,282 @@ mod tests {
         assert_eq!(chars.next(), Some("🏳️‍🌈".into()));
         assert_eq!(chars.next(), None);
     }
+
+    #[test]
+    fn utf8_char_keycap() {
+        for base in "0123456789#*".chars() {
+            let keycap = format!("{base}{VARIATION_SELECTOR}\u{20e3}");
+            let input = format!("{keycap}{keycap}");
+            let mut chars = input.utf8_chars();
+
+            assert_eq!(chars.next(), Some(keycap.as_str().into()));
+            assert_eq!(chars.next(), Some(keycap.as_str().into()));
+            assert_eq!(chars.next(), None);
+        }
+    }
+
+    #[test]
+    fn utf8_char_keycap_without_variation_selector() {
+        for base in "0123456789#*".chars() {
+            let keycap = format!("{base}\u{20e3}");
+            let input = format!("{keycap}{base}");
+            let mut chars = input.utf8_chars();
+
+            assert_eq!(chars.next(), Some(keycap.as_str().into()));
+            assert_eq!(chars.next(), Some(base.to_string().as_str().into()));
+            assert_eq!(chars.next(), None);
+        }
+    }
+
+    #[test]
+    fn utf8_char_tag_sequence() {
+        let mut chars = "🏴󠁧󠁢󠁳󠁣󠁴󠁿🏴󠁧󠁢󠁥󠁮󠁧󠁿🏴".utf8_chars();
+
+        assert_eq!(chars.next(), Some("🏴󠁧󠁢󠁳󠁣󠁴󠁿".into()));
+        assert_eq!(chars.next(), Some("🏴󠁧󠁢󠁥󠁮󠁧󠁿".into()));
+        assert_eq!(chars.next(), Some("🏴".into()));
+        assert_eq!(chars.next(), None);
+    }
+
+    #[test]
+    fn well_formed_tag_sequence() {
+        assert!(UTF8Char::from("🏴󠁧󠁢󠁳󠁣󠁴󠁿").is_emoji_tag_sequence());
+        assert!(UTF8Char::from("🏴\u{fe0f}\u{e0067}\u{e007f}").is_emoji_tag_sequence());
+    }
+
+    #[test]
+    fn malformed_tag_sequence() {
+        assert!(!UTF8Char::from("🏴").is_emoji_tag_sequence());
+        assert!(!UTF8Char::from("🏴\u{e007f}").is_emoji_tag_sequence());
+        assert!(!UTF8Char::from("🏴\u{e0067}\u{e0062}").is_emoji_tag_sequence());
+        assert!(!UTF8Char::from("🏴\u{e0067}\u{e007f}\u{e007f}").is_emoji_tag_sequence());
+        assert!(!UTF8Char::from("A\u{e0067}\u{e007f}").is_emoji_tag_sequence());
+        assert!(!UTF8Char::from("🏴\u{fe0e}\u{e0067}\u{e007f}").is_emoji_tag_sequence());
+    }
+
+    #[test]
+    fn utf8_char_combining_marks() {
+        let mut chars = "e\u{301}a\u{308}\u{323}".utf8_chars();
+
+        assert_eq!(chars.next(), Some("e\u{301}".into()));
+        assert_eq!(chars.next(), Some("a\u{308}\u{323}".into()));
+        assert_eq!(chars.next(), None);
+    }
+
+    #[test]
+    fn utf8_char_hangul_syllables() {
+        let mut chars = "\u{1100}\u{1161}\u{11a8}\u{ac00}\u{11a8}\u{1100}".utf8_chars();
+
+        assert_eq!(chars.next(), Some("\u{1100}\u{1161}\u{11a8}".into()));
+        assert_eq!(chars.next(), Some("\u{ac00}\u{11a8}".into()));
+        assert_eq!(chars.next(), Some("\u{1100}".into()));
+        assert_eq!(chars.next(), None);
+    }
+
+    #[test]
+    fn utf8_char_prepend() {
+        let mut chars = "\u{600}1".utf8_chars();
+
+        assert_eq!(chars.next(), Some("\u{600}1".into()));
+        assert_eq!(chars.next(), None);
+    }
+
+    #[test]
+    fn utf8_char_spacing_mark() {
+        let mut chars = "\u{915}\u{93f}".utf8_chars();
+
+        assert_eq!(chars.next(), Some("\u{915}\u{93f}".into()));
+        assert_eq!(chars.next(), None);
+    }
+
+    #[test]
+    fn utf8_char_indic_conjunct() {
+        let mut chars = "\u{915}\u{94d}\u{937}\u{93f}".utf8_chars();
+
+        assert_eq!(chars.next(), Some("\u{915}\u{94d}\u{937}\u{93f}".into()));
+        assert_eq!(chars.next(), None);
+    }
+
+    #[test]
+    fn utf8_char_carriage_return_line_feed() {
+        let mut chars = "\r\n\n\r\u{301}".utf8_chars();
+
+        assert_eq!(chars.next(), Some("\r\n".into()));
+        assert_eq!(chars.next(), Some("\n".into()));
+        assert_eq!(chars.next(), Some("\r".into()));
+        assert_eq!(chars.next(), Some("\u{301}".into()));
+        assert_eq!(chars.next(), None);
+    }
+
+    #[test]
+    fn utf8_char_zero_width_joiner_without_pictographic() {
+        let mut chars = "a\u{200d}b".utf8_chars();
+
+        assert_eq!(chars.next(), Some("a\u{200d}".into()));
+        assert_eq!(chars.next(), Some("b".into()));
+        assert_eq!(chars.next(), None);
+    }
+
+    #[test]
+    fn utf8_char_regional_indicator_pairs() {
+        let mut chars = "🇩🇪🇫🇷".utf8_chars();
+
+        assert_eq!(chars.next(), Some("🇩🇪".into()));
+        assert_eq!(chars.next(), Some("🇫🇷".into()));
+        assert_eq!(chars.next(), None);
+    }
+
+    #[test]
+    fn utf8_char_regional_indicator_odd_run() {
+        let mut chars = "🇩🇪🇫A🇫".utf8_chars();
+
+        assert_eq!(chars.next(), Some("🇩🇪".into()));
+        assert_eq!(chars.next(), Some("🇫".into()));
+        assert_eq!(chars.next(), Some("A".into()));
+        assert_eq!(chars.next(), Some("🇫".into()));
+        assert_eq!(chars.next(), None);
+    }
+
+    #[test]
+    fn utf8_char_regional_indicator_extended() {
+        let mut chars = "🇩\u{308}🇪🇫\u{200d}🇷".utf8_chars();
+
+        assert_eq!(chars.next(), Some("🇩\u{308}".into()));
+        assert_eq!(chars.next(), Some("🇪🇫\u{200d}".into()));
+        assert_eq!(chars.next(), Some("🇷".into()));
+        assert_eq!(chars.next(), None);
+    }
+
+    #[test]
+    fn utf8_chars_rev() {
+        let inputs = [
+            "A±⚽🫥",
+            "👨‍👩‍👦🏳️‍🌈👁️‍🗨️",
+            "🇩🇪🇫🇷🇩",
+            "A🇩🇪🇫🇷🇩🇪🇫",
+            "👋🏽🧑🏽‍🚀1️⃣#⃣",
+            "🏴󠁧󠁢󠁳󠁣󠁴󠁿🏴",
+            "e\u{301}\r\n\r\u{301}\u{600}1",
+            "\u{1100}\u{1161}\u{11a8}\u{ac00}\u{11a8}\u{1100}",
+            "\u{915}\u{94d}\u{937}\u{93f}\u{915}\u{94d}a",
+            "a\u{200d}b👨\u{200d}",
+        ];
+
+        for input in inputs {
+            let mut forward = input.utf8_chars().collect::<Vec<_>>();
+            forward.reverse();
+
+            assert_eq!(input.utf8_chars().rev().collect::<Vec<_>>(), forward);
+        }
+    }
+
+    #[test]
+    fn utf8_chars_rev_long_extend_run() {
+        let text = format!("a{}b\u{301}", "\u{301}".repeat(20_000));
+        let mut chars = text.utf8_chars();
+
+        assert_eq!(chars.next_back(), Some("b\u{301}".into()));
+        assert_eq!(chars.next_back(), Some(text[..text.len() - 3].into()));
+        assert_eq!(chars.next_back(), None);
+    }
+
+    #[test]
+    fn utf8_chars_rev_long_regional_indicator_run() {
+        let text = format!("a{}🇩\u{301}", "🇩🇪".repeat(20_000));
+
+        let mut forward = text.utf8_chars().collect::<Vec<_>>();
+        forward.reverse();
+
+        assert_eq!(text.utf8_chars().rev().collect::<Vec<_>>(), forward);
+        assert_eq!(forward.len(), 20_002);
+    }
+
+    #[test]
+    fn utf8_chars_both_ends() {
+        let mut chars = "🇩🇪🇫🇷🇮🇹🇩🇪".utf8_chars();
+
+        assert_eq!(chars.next_back(), Some("🇩🇪".into()));
+        assert_eq!(chars.next(), Some("🇩🇪".into()));
+        assert_eq!(chars.next_back(), Some("🇮🇹".into()));
+        assert_eq!(chars.as_str(), "🇫🇷");
+        assert_eq!(chars.next(), Some("🇫🇷".into()));
+        assert_eq!(chars.next_back(), None);
+    }
+
+    #[test]
+    fn utf8_char_indices() {
+        let mut indices = "A🏳️‍🌈e\u{301}🇩🇪".utf8_char_indices();
+
+        assert_eq!(indices.next(), Some((0, "A".into())));
+        assert_eq!(indices.next_back(), Some((18, "🇩🇪".into())));
+        assert_eq!(indices.next(), Some((1, "🏳️‍🌈".into())));
+        assert_eq!(indices.next_back(), Some((15, "e\u{301}".into())));
+        assert_eq!(indices.next(), None);
+    }
+
+    #[test]
+    fn utf8_char_offset_in() {
+        let source = "A🏳️‍🌈e\u{301}";
+
+        for (offset, utf8_char) in source.utf8_char_indices() {
+            assert_eq!(utf8_char.offset_in(source), Some(offset));
+        }
+
+        let other = String::from(source);
+        let utf8_char = source.utf8_chars().next().unwrap();
+        assert_eq!(utf8_char.offset_in(&other), None);
+        assert_eq!(utf8_char.offset_in(&source[1..]), None);
+    }
+
+    #[test]
+    fn utf8_char_compare_with_str() {
+        let chars = "a🏳️‍🌈".utf8_chars().collect::<Vec<_>>();
+
+        assert!(chars[1] == "🏳️‍🌈");
+        assert!("🏳️‍🌈" == chars[1]);
+        assert!(chars[0] != "🏳️‍🌈");
+        assert_eq!(format!("{}{}", chars[0], chars[1]), "a🏳️‍🌈");
+    }
+
+    #[test]
+    fn utf8_char_in_collections() {
+        use std::collections::{BTreeSet, HashSet};
+
+        let text = "ée\u{301}aze\u{301}";
+        let unique = text.utf8_chars().collect::<HashSet<_>>();
+        let sorted = text.utf8_chars().collect::<BTreeSet<_>>();
+
+        assert_eq!(unique.len(), 4);
+        // Ordered by code point, not collation-aware.
+        assert_eq!(
+            sorted.into_iter().map(|c| c.as_str()).collect::<Vec<_>>(),
+            ["a", "e\u{301}", "z", "é"]
+        );
+    }
+
+    #[test]
+    fn grapheme_slice() {
+        let text = "a👨‍👩‍👦🇩🇪e\u{301}b";
+
+        assert_eq!(text.grapheme_count(), 5);
+        assert_eq!(text.nth_grapheme(2), Some("🇩🇪".into()));
+        assert_eq!(text.nth_grapheme(5), None);
+
+        assert_eq!(text.grapheme_slice(1..3), Some("👨‍👩‍👦🇩🇪"));
+        assert_eq!(text.grapheme_slice(3..), Some("e\u{301}b"));
+        assert_eq!(text.grapheme_slice(..=1), Some("a👨‍👩‍👦"));
+        assert_eq!(text.grapheme_slice(5..5), Some(""));
+        assert_eq!(text.grapheme_slice(4..6), None);
+        assert_eq!(text.grapheme_slice(6..), None);
+    }
+
+    #[test]
+    fn split_at_grapheme() {
+        let text = "🇩🇪🇫🇷🇮🇹";
+
+        assert_eq!(text.split_at_grapheme(0), Some(("", text)));
+        assert_eq!(text.split_at_grapheme(1), Some(("🇩🇪", "🇫🇷🇮🇹")));
+        assert_eq!(text.split_at_grapheme(3), Some((text, "")));
+        assert_eq!(text.split_at_grapheme(4), None);
+    }
 }
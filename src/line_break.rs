@@ -0,0 +1,530 @@
+use crate::{
+    grapheme::ZWJ,
+    tables::{is_east_asian, is_final_quote, is_initial_quote, line_break, LineBreak},
+    truncate_width, DisplayWidth, ToUTF8Chars, UTF8Char, UTF8CharIndices, UTF8Chars,
+};
+
+/// The kind of a line break opportunity, see [`UTF8LineBreaks`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BreakOpportunity {
+    /// The line has to end here, like after a line feed.
+    Mandatory,
+    /// The line may end here.
+    Allowed,
+}
+
+/// An iterator over the line break opportunities of a string, see
+/// [UAX #14](https://www.unicode.org/reports/tr14/).
+///
+/// Yields the byte offset at which the next line would start, and whether
+/// the break is mandatory. The end of the text is always a mandatory break.
+/// Breaks are only looked for between [`UTF8Char`]s, so a line never ends
+/// inside of one.
+#[derive(Debug)]
+pub struct UTF8LineBreaks<'a> {
+    text: &'a str,
+    chars: UTF8CharIndices<'a>,
+    state: Option<LineBreakState>,
+}
+
+impl<'a> Iterator for UTF8LineBreaks<'a> {
+    type Item = (usize, BreakOpportunity);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (offset, utf8_char) in self.chars.by_ref() {
+            let info = CharInfo::new(utf8_char);
+
+            match &mut self.state {
+                Some(state) => {
+                    let rest = &self.text[offset + utf8_char.as_str().len()..];
+
+                    if let Some(opportunity) = state.break_before(info, rest.utf8_chars()) {
+                        return Some((offset, opportunity));
+                    }
+                }
+                // LB2
+                None => self.state = Some(LineBreakState::new(info)),
+            }
+        }
+
+        // LB3
+        self.state
+            .take()
+            .map(|_| (self.text.len(), BreakOpportunity::Mandatory))
+    }
+}
+
+pub trait ToUTF8LineBreaks<'a> {
+    fn utf8_line_breaks(&'a self) -> UTF8LineBreaks<'a>;
+}
+
+impl<'a> ToUTF8LineBreaks<'a> for str {
+    fn utf8_line_breaks(&'a self) -> UTF8LineBreaks<'a> {
+        UTF8LineBreaks {
+            text: self,
+            chars: self.utf8_char_indices(),
+            state: None,
+        }
+    }
+}
+
+/// Wrap the text into lines of at most `width` terminal cells, see
+/// [`UTF8Char::display_width`].
+///
+/// The lines are filled greedily, and end at the break opportunities of
+/// [`UTF8LineBreaks`]. Trailing white space and line feeds are removed from
+/// every line, as is indentation which doesn't fit with the first word.
+/// Words which don't fit into a line on their own are split between
+/// [`UTF8Char`]s.
+pub fn wrap(text: &str, width: usize) -> Vec<&str> {
+    let mut lines = Vec::new();
+    let mut line_start = 0;
+    // The last allowed break in the current line.
+    let mut last_break = None;
+
+    for (offset, opportunity) in text.utf8_line_breaks() {
+        if text[line_start..offset].trim_end().display_width() > width {
+            if let Some(last_break) = last_break.take() {
+                let line = text[line_start..last_break].trim_end();
+                // Indentation which doesn't fit with the first word is
+                // dropped, like the spaces at any other break.
+                if !line.is_empty() {
+                    lines.push(line);
+                }
+                line_start = last_break;
+            }
+
+            while text[line_start..offset].trim_end().display_width() > width {
+                let segment = &text[line_start..offset];
+                let line = match truncate_width(segment, width, None) {
+                    // A single char might be wider than a line.
+                    "" => segment.utf8_chars().next().map_or("", |c| c.as_str()),
+                    line => line,
+                };
+
+                lines.push(line);
+                line_start += line.len();
+            }
+        }
+
+        match opportunity {
+            // The line might already be ended by splitting a word.
+            _ if offset == line_start => last_break = None,
+            BreakOpportunity::Mandatory => {
+                lines.push(text[line_start..offset].trim_end());
+                line_start = offset;
+                last_break = None;
+            }
+            BreakOpportunity::Allowed => last_break = Some(offset),
+        }
+    }
+
+    lines
+}
+
+/// The line break properties of an [`UTF8Char`].
+#[derive(Debug, Clone, Copy)]
+struct CharInfo {
+    /// The class the char has towards the one in front of it. Combining marks
+    /// have the class of their base (LB9), or are alphabetic if they have
+    /// none (LB10).
+    start: LineBreak,
+    /// The class the char has towards the one after it.
+    end: LineBreak,
+    first: char,
+    /// The first code point after `first`, which is not a combining mark.
+    second_base: Option<char>,
+    /// The last code point, which is not a combining mark.
+    last_base: char,
+    /// The code point in front of `last_base`, which is not a combining mark.
+    before_last_base: Option<char>,
+    /// The char only consists of combining marks.
+    is_mark: bool,
+    ends_with_zwj: bool,
+    regional_indicators: usize,
+}
+
+impl CharInfo {
+    fn new(utf8_char: UTF8Char) -> Self {
+        let is_mark = |cp| matches!(line_break(cp), LineBreak::Cm | LineBreak::Zwj);
+        let first = utf8_char
+            .code_points()
+            .next()
+            .expect("a char is never empty");
+        let last = utf8_char.code_points().next_back().unwrap_or(first);
+        // Marks are combined with the code point in front of them (LB9), but
+        // the char can contain other code points, like emoji modifiers.
+        let mut bases = utf8_char.code_points().rev().filter(|&cp| !is_mark(cp));
+        let last_base = bases.next();
+        let before_last_base = bases.next();
+
+        let start = match line_break(first) {
+            LineBreak::Cm | LineBreak::Zwj => LineBreak::Al,
+            class => class,
+        };
+        let end = match last_base.map(line_break) {
+            // LB10
+            None => LineBreak::Al,
+            Some(
+                LineBreak::Bk
+                | LineBreak::Cr
+                | LineBreak::Lf
+                | LineBreak::Nl
+                | LineBreak::Sp
+                | LineBreak::Zw,
+            ) if last_base != Some(last) => LineBreak::Al,
+            Some(class) => class,
+        };
+
+        CharInfo {
+            start,
+            end,
+            first,
+            second_base: utf8_char.code_points().skip(1).find(|&cp| !is_mark(cp)),
+            last_base: last_base.unwrap_or(last),
+            before_last_base,
+            is_mark: last_base.is_none(),
+            ends_with_zwj: last == ZWJ,
+            regional_indicators: utf8_char
+                .code_points()
+                .filter(|&cp| line_break(cp) == LineBreak::Ri)
+                .count(),
+        }
+    }
+}
+
+/// Check if the code point is an aksara, or the dotted circle which stands in
+/// for one (LB28a).
+fn is_aksara(c: char) -> bool {
+    matches!(line_break(c), LineBreak::Ak | LineBreak::As) || c == '\u{25cc}'
+}
+
+/// How far a `NU (SY|IS)* (CL|CP)?` sequence got (LB25).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NumberPhase {
+    Digits,
+    Close,
+}
+
+/// The state of the line breaking rules, after the chars seen so far.
+#[derive(Debug, Clone, Copy)]
+struct LineBreakState {
+    prev: CharInfo,
+    before_prev: Option<CharInfo>,
+    /// The class of the last char, which is not a space.
+    before_spaces: LineBreak,
+    /// Whether the last char, which is not a space, is a quotation mark which
+    /// opens a quote (LB15a).
+    opening_quote: bool,
+    number: Option<NumberPhase>,
+    odd_regional_indicators: bool,
+}
+
+impl LineBreakState {
+    fn new(first: CharInfo) -> Self {
+        LineBreakState {
+            prev: first,
+            before_prev: None,
+            before_spaces: first.end,
+            opening_quote: first.end == LineBreak::Qu && is_initial_quote(first.last_base),
+            number: (first.end == LineBreak::Nu).then_some(NumberPhase::Digits),
+            odd_regional_indicators: first.regional_indicators % 2 == 1,
+        }
+    }
+
+    /// Check if there is a break opportunity in front of the char, and add
+    /// it to the state.
+    ///
+    /// `rest` are the chars after it, which some rules look ahead into.
+    fn break_before(&mut self, info: CharInfo, rest: UTF8Chars) -> Option<BreakOpportunity> {
+        use LineBreak::*;
+
+        // LB9, for marks which are not part of the char in front of them,
+        // like those after a control character.
+        let opportunity = if matches!(line_break(info.first), Cm | Zwj)
+            && !matches!(self.prev.end, Bk | Cr | Lf | Nl | Sp | Zw)
+        {
+            if info.is_mark {
+                self.prev.ends_with_zwj = info.ends_with_zwj;
+                return None;
+            }
+
+            None
+        } else {
+            self.opportunity(info, rest)
+        };
+        let (prev, next) = (self.prev.end, info.start);
+
+        if prev == Ri && next == Ri {
+            self.odd_regional_indicators ^= info.regional_indicators % 2 == 1;
+        } else {
+            self.odd_regional_indicators = info.regional_indicators % 2 == 1;
+        }
+        if info.end != Sp {
+            self.opening_quote = info.end == Qu
+                && is_initial_quote(info.last_base)
+                && matches!(prev, Bk | Cr | Lf | Nl | Op | Qu | Gl | Sp | Zw);
+            self.before_spaces = info.end;
+        }
+        self.number = match (self.number, info.end) {
+            (_, Nu) => Some(NumberPhase::Digits),
+            (Some(NumberPhase::Digits), Sy | Is) => Some(NumberPhase::Digits),
+            (Some(NumberPhase::Digits), Cl | Cp) => Some(NumberPhase::Close),
+            _ => None,
+        };
+        self.before_prev = Some(self.prev);
+        self.prev = info;
+
+        opportunity
+    }
+
+    fn opportunity(&self, info: CharInfo, rest: UTF8Chars) -> Option<BreakOpportunity> {
+        use LineBreak::*;
+
+        // The class in front of the spaces the previous chars end with, if
+        // any.
+        let spaced = match self.prev.end {
+            Sp => self.before_spaces,
+            class => class,
+        };
+        // The code points after the first one of the char, which are not
+        // combining marks, and their classes. Of the next chars, only the first
+        // code points are looked at.
+        let following = || {
+            let next_chars = rest.as_str().utf8_chars().map(CharInfo::new);
+
+            info.second_base
+                .map(|c| (c, line_break(c)))
+                .into_iter()
+                .chain(next_chars.map(|next| (next.first, next.start)))
+        };
+        let after = || following().next().map(|(_, class)| class);
+        // The code point in front of the last one of the previous char, which
+        // is not a combining mark.
+        let before_prev_base = self
+            .prev
+            .before_last_base
+            .or(self.before_prev.map(|before| before.last_base));
+
+        let allowed = match (self.prev.end, info.start) {
+            // LB4, LB5
+            (Bk | Cr | Lf | Nl, _) => return Some(BreakOpportunity::Mandatory),
+            // LB6, LB7
+            (_, Bk | Cr | Lf | Nl | Sp | Zw) => false,
+            // LB8
+            _ if spaced == Zw => true,
+            // LB8a
+            _ if self.prev.ends_with_zwj => false,
+            // LB11, LB12, LB12a
+            (Wj | Gl, _) | (_, Wj) => false,
+            (prev, Gl) if !matches!(prev, Sp | Ba | Hy) => false,
+            // LB13
+            (_, Cl | Cp | Ex | Sy) => false,
+            // LB14, LB15a, LB15b
+            _ if spaced == Op => false,
+            _ if self.opening_quote => false,
+            (_, Qu)
+                if is_final_quote(info.first)
+                    && after().is_none_or(|next| {
+                        matches!(
+                            next,
+                            Sp | Gl | Wj | Cl | Qu | Cp | Ex | Is | Sy | Bk | Cr | Lf | Nl | Zw
+                        )
+                    }) =>
+            {
+                false
+            }
+            // LB15c, LB15d
+            (Sp, Is) if after() == Some(Nu) => true,
+            (_, Is) => false,
+            // LB16, LB17
+            (_, Ns) if matches!(spaced, Cl | Cp) => false,
+            (_, B2) if spaced == B2 => false,
+            // LB18
+            (Sp, _) => true,
+            // LB19
+            (_, Qu) if !is_initial_quote(info.first) => false,
+            (Qu, _) if !is_final_quote(self.prev.last_base) => false,
+            // LB19a
+            (_, Qu) if !is_east_asian(self.prev.last_base) => false,
+            (_, Qu) if following().next().is_none_or(|(c, _)| !is_east_asian(c)) => false,
+            (Qu, _) if !is_east_asian(info.first) => false,
+            (Qu, _)
+                if self
+                    .before_prev
+                    .is_none_or(|before| !is_east_asian(before.last_base)) =>
+            {
+                false
+            }
+            // LB20
+            (Cb, _) | (_, Cb) => true,
+            // LB20a
+            (Hy | Ba, Al | Hl)
+                if (self.prev.end == Hy || self.prev.last_base == '\u{2010}')
+                    && self.before_prev.is_none_or(|before| {
+                        matches!(before.end, Bk | Cr | Lf | Nl | Sp | Zw | Cb | Gl)
+                    }) =>
+            {
+                false
+            }
+            // LB21, LB21a, LB21b
+            (_, Ba | Hy | Ns) | (Bb, _) => false,
+            (Hy | Ba, next)
+                if self.before_prev.map(|before| before.end) == Some(Hl)
+                    && (self.prev.end == Hy || !is_east_asian(self.prev.last_base))
+                    && next != Hl =>
+            {
+                false
+            }
+            (Sy, Hl) => false,
+            // LB22
+            (_, In) => false,
+            // LB23, LB23a, LB24
+            (Al | Hl, Nu) | (Nu, Al | Hl) => false,
+            (Pr, Id | Eb | Em) | (Id | Eb | Em, Po) => false,
+            (Pr | Po, Al | Hl) | (Al | Hl, Pr | Po) => false,
+            // LB25
+            (_, Po | Pr) if self.number.is_some() => false,
+            (_, Nu) if self.number == Some(NumberPhase::Digits) => false,
+            (Po | Pr, Op)
+                if {
+                    let mut ahead = following().map(|(_, class)| class);
+                    match ahead.next() {
+                        Some(Nu) => true,
+                        Some(Is) => ahead.next() == Some(Nu),
+                        _ => false,
+                    }
+                } =>
+            {
+                false
+            }
+            (Po | Pr | Hy | Is, Nu) => false,
+            // LB26, LB27
+            (Jl, Jl | Jv | H2 | H3) | (Jv | H2, Jv | Jt) | (Jt | H3, Jt) => false,
+            (Jl | Jv | Jt | H2 | H3, Po) | (Pr, Jl | Jv | Jt | H2 | H3) => false,
+            // LB28
+            (Al | Hl, Al | Hl) => false,
+            // LB28a
+            (Ap, _) if is_aksara(info.first) => false,
+            (_, Vf | Vi) if is_aksara(self.prev.last_base) => false,
+            (Vi, Ak | Al)
+                if before_prev_base.is_some_and(is_aksara)
+                    && (info.start == Ak || info.first == '\u{25cc}') =>
+            {
+                false
+            }
+            _ if is_aksara(self.prev.last_base) && is_aksara(info.first) && after() == Some(Vf) => {
+                false
+            }
+            // LB29
+            (Is, Al | Hl) => false,
+            // LB30
+            (Al | Hl | Nu, Op) if !is_east_asian(info.first) => false,
+            (Cp, Al | Hl | Nu) if !is_east_asian(self.prev.last_base) => false,
+            // LB30a
+            (Ri, Ri) => !self.odd_regional_indicators,
+            // LB30b, without unassigned pictographic code points, which the
+            // tables don't tell apart.
+            (Eb, Em) => false,
+            // LB31
+            _ => true,
+        };
+
+        allowed.then_some(BreakOpportunity::Allowed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{wrap, BreakOpportunity, ToUTF8LineBreaks};
+
+    fn offsets(text: &str) -> Vec<usize> {
+        text.utf8_line_breaks().map(|(offset, _)| offset).collect()
+    }
+
+    #[test]
+    fn utf8_line_breaks() {
+        use BreakOpportunity::*;
+
+        let text = "Hello, (world)!\nIt's 3.5 km-long 漢字。👨‍👩‍👦🇩🇪";
+        let breaks = text.utf8_line_breaks().collect::<Vec<_>>();
+
+        assert_eq!(
+            breaks,
+            [
+                (7, Allowed),
+                (16, Mandatory),
+                (21, Allowed),
+                (25, Allowed),
+                (28, Allowed),
+                (33, Allowed),
+                (36, Allowed),
+                (42, Allowed),
+                (60, Allowed),
+                (68, Mandatory)
+            ]
+        );
+    }
+
+    #[test]
+    fn utf8_line_breaks_quotes_and_numbers() {
+        assert_eq!(offsets("He said « Bonjour » and left."), [3, 8, 22, 26, 31]);
+        assert_eq!(offsets("“漢字”漢字"), [6, 12, 15, 18]);
+        assert_eq!(offsets("$(12.5)% or x .5"), [9, 12, 14, 16]);
+    }
+
+    #[test]
+    fn utf8_line_breaks_aksaras() {
+        // A virama joins the aksaras around it, but they break otherwise.
+        assert_eq!(offsets("\u{1b13}\u{1b44}\u{1b13}\u{1b13}"), [9, 12]);
+    }
+
+    #[test]
+    fn utf8_line_breaks_marks_after_control() {
+        assert_eq!(offsets("a\t\u{301}b"), [4, 5]);
+    }
+
+    #[test]
+    fn utf8_line_breaks_empty() {
+        assert_eq!("".utf8_line_breaks().next(), None);
+    }
+
+    #[test]
+    fn wrap_greedy() {
+        let text = "The quick brown fox jumps over the lazy dog";
+
+        assert_eq!(
+            wrap(text, 10),
+            ["The quick", "brown fox", "jumps over", "the lazy", "dog"]
+        );
+    }
+
+    #[test]
+    fn wrap_mixed_text() {
+        let text = "漢字かな👨‍👩‍👦🏳️‍🌈 ok\n\nSupercalifragilistic";
+
+        assert_eq!(
+            wrap(text, 6),
+            [
+                "漢字か",
+                "な👨‍👩‍👦🏳️‍🌈",
+                "ok",
+                "",
+                "Superc",
+                "alifra",
+                "gilist",
+                "ic"
+            ]
+        );
+        assert_eq!(wrap("👨‍👩‍👦", 1), ["👨‍👩‍👦"]);
+    }
+
+    #[test]
+    fn wrap_indented() {
+        assert_eq!(
+            wrap("    indented paragraph text", 10),
+            ["indented", "paragraph", "text"]
+        );
+        assert_eq!(wrap("  indented text", 10), ["  indented", "text"]);
+    }
+}
@@ -0,0 +1,220 @@
+use std::io::{self, BufRead};
+
+use crate::{code_point::next_code_point, grapheme::GraphemeState, CodePointErrorKind, Utf8Error};
+
+/// An iterator over the [`UTF8Char`](crate::UTF8Char)s read from a
+/// [`BufRead`], which yields owned chars.
+///
+/// Chars and code points which are cut at the edge of the reader's buffer are
+/// put together, so the chars are the same as those of
+/// [`UTF8Chars`](crate::UTF8Chars). Invalid sequences yield an error of the
+/// kind [`io::ErrorKind::InvalidData`], which wraps a [`Utf8Error`].
+///
+/// A plain [`Read`](std::io::Read) can be wrapped in a
+/// [`BufReader`](std::io::BufReader).
+#[derive(Debug)]
+pub struct UTF8CharReader<R> {
+    reader: R,
+    /// Bytes which were read. Those in front of `start` were already
+    /// returned, and are only removed when the buffer is refilled.
+    buf: Vec<u8>,
+    start: usize,
+    /// The byte offset in the stream of `start`.
+    offset: usize,
+    /// The number of bytes after `start`, which belong to the current char.
+    char_len: usize,
+    state: Option<GraphemeState>,
+    eof: bool,
+}
+
+impl<R: BufRead> UTF8CharReader<R> {
+    pub fn new(reader: R) -> Self {
+        UTF8CharReader {
+            reader,
+            buf: Vec::new(),
+            start: 0,
+            offset: 0,
+            char_len: 0,
+            state: None,
+            eof: false,
+        }
+    }
+
+    /// Get back the underlying reader.
+    ///
+    /// Bytes which were already read, but not yet returned as chars, are lost.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Read more bytes into the buffer.
+    fn fill(&mut self) -> io::Result<()> {
+        let available = loop {
+            match self.reader.fill_buf() {
+                Ok(available) => break available,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err),
+            }
+        };
+
+        let len = available.len();
+        self.buf.drain(..self.start);
+        self.start = 0;
+        self.buf.extend_from_slice(available);
+        self.reader.consume(len);
+        self.eof = len == 0;
+
+        Ok(())
+    }
+
+    /// Take the current char out of the buffer.
+    fn take_char(&mut self) -> String {
+        let bytes = self.buf[self.start..self.start + self.char_len].to_vec();
+
+        self.start += self.char_len;
+        self.offset += self.char_len;
+        self.char_len = 0;
+
+        // SAFETY: The bytes of the char are validated when they are added.
+        unsafe { String::from_utf8_unchecked(bytes) }
+    }
+}
+
+impl<R: BufRead> Iterator for UTF8CharReader<R> {
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match next_code_point(&self.buf[self.start + self.char_len..]) {
+                None if self.eof => {
+                    return (self.char_len > 0).then(|| Ok(self.take_char()));
+                }
+                // The next code point is cut at the edge of the buffer, or
+                // there is no next one yet.
+                None => {}
+                Some(Err(err)) if err.kind() == CodePointErrorKind::UnexpectedEnd && !self.eof => {}
+                Some(Err(_)) if self.char_len > 0 => {
+                    self.state = None;
+                    return Some(Ok(self.take_char()));
+                }
+                Some(Err(error)) => {
+                    let err = Utf8Error::new(self.offset, error);
+
+                    self.start += err.error_len();
+                    self.offset += err.error_len();
+                    self.state = None;
+
+                    return Some(Err(io::Error::new(io::ErrorKind::InvalidData, err)));
+                }
+                Some(Ok((cp, cp_len))) => {
+                    let boundary = match &mut self.state {
+                        Some(state) => state.is_boundary_before(cp),
+                        None => {
+                            self.state = Some(GraphemeState::new(cp));
+                            false
+                        }
+                    };
+
+                    let utf8_char = boundary.then(|| self.take_char());
+                    self.char_len += cp_len;
+
+                    if let Some(utf8_char) = utf8_char {
+                        return Some(Ok(utf8_char));
+                    }
+
+                    continue;
+                }
+            }
+
+            if let Err(err) = self.fill() {
+                return Some(Err(err));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, BufReader, Read};
+
+    use crate::{CodePointErrorKind, ToUTF8Chars, UTF8CharReader, Utf8Error};
+
+    /// A reader which only ever returns one byte.
+    struct ByteReader<'a>(&'a [u8]);
+
+    impl<'a> Read for ByteReader<'a> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let Some((&byte, rest)) = self.0.split_first() else {
+                return Ok(0);
+            };
+
+            buf[0] = byte;
+            self.0 = rest;
+            Ok(1)
+        }
+    }
+
+    #[test]
+    fn utf8_char_reader_one_byte_at_a_time() {
+        let text = "A±⚽🫥👨‍👩‍👦🇩🇪🇫🇷🇩e\u{301}\r\n🏳️‍🌈\u{915}\u{94d}\u{937}1️⃣";
+        let reader = BufReader::with_capacity(1, ByteReader(text.as_bytes()));
+
+        let chars = UTF8CharReader::new(reader)
+            .collect::<io::Result<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(
+            chars,
+            text.utf8_chars().map(|c| c.as_str()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn utf8_char_reader_large_buffer() {
+        let text = "A±⚽🫥👨‍👩‍👦🇩🇪e\u{301}\r\n".repeat(10_000);
+        let reader = BufReader::with_capacity(1 << 20, text.as_bytes());
+
+        let chars = UTF8CharReader::new(reader)
+            .collect::<io::Result<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(
+            chars,
+            text.utf8_chars().map(|c| c.as_str()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn utf8_char_reader_invalid_data() {
+        let bytes = b"a\xcc\x81\xe2\x82\xf0\x9f\x98\x80";
+        let reader = BufReader::with_capacity(1, ByteReader(bytes));
+        let mut chars = UTF8CharReader::new(reader);
+
+        assert_eq!(chars.next().unwrap().unwrap(), "a\u{301}");
+
+        let err = chars.next().unwrap().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        let err = err.get_ref().unwrap().downcast_ref::<Utf8Error>().unwrap();
+        assert_eq!(err.offset(), 3);
+        assert_eq!(err.error_len(), 2);
+        assert_eq!(err.kind(), CodePointErrorKind::ExpectedContinuationByte);
+
+        assert_eq!(chars.next().unwrap().unwrap(), "😀");
+        assert!(chars.next().is_none());
+    }
+
+    #[test]
+    fn utf8_char_reader_truncated() {
+        let reader = BufReader::with_capacity(1, ByteReader(b"a\xf0\x9f"));
+        let mut chars = UTF8CharReader::new(reader);
+
+        assert_eq!(chars.next().unwrap().unwrap(), "a");
+
+        let err = chars.next().unwrap().unwrap_err();
+        let err = err.get_ref().unwrap().downcast_ref::<Utf8Error>().unwrap();
+        assert_eq!(err.kind(), CodePointErrorKind::UnexpectedEnd);
+
+        assert!(chars.next().is_none());
+    }
+}
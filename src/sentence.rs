@@ -0,0 +1,281 @@
+use crate::{
+    tables::{sentence_break, SentenceBreak},
+    ToUTF8Chars, UTF8Char, UTF8Chars,
+};
+
+/// An iterator over the sentences of a string, see
+/// [UAX #29](https://www.unicode.org/reports/tr29/#Sentence_Boundaries).
+///
+/// Sentence boundaries are only looked for between [`UTF8Char`]s, so a
+/// sentence never ends inside of one.
+#[derive(Debug)]
+pub struct UTF8Sentences<'a> {
+    text: &'a str,
+}
+
+impl<'a> Iterator for UTF8Sentences<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.text.is_empty() {
+            return None;
+        }
+
+        let (sentence, rest) = self.text.split_at(sentence_len(self.text));
+        self.text = rest;
+
+        Some(sentence)
+    }
+}
+
+pub trait ToUTF8Sentences<'a> {
+    fn utf8_sentences(&'a self) -> UTF8Sentences<'a>;
+}
+
+impl<'a> ToUTF8Sentences<'a> for str {
+    fn utf8_sentences(&'a self) -> UTF8Sentences<'a> {
+        UTF8Sentences { text: self }
+    }
+}
+
+/// Get the sentence break property of an [`UTF8Char`], which is that of the
+/// first code point not ignored by SB5.
+fn char_sentence_break(utf8_char: UTF8Char) -> SentenceBreak {
+    let mut code_points = utf8_char.code_points().map(sentence_break);
+    let first = code_points.next().expect("a char is never empty");
+
+    std::iter::once(first)
+        .chain(code_points)
+        .find(|&sb| !is_ignored(sb))
+        .unwrap_or(first)
+}
+
+fn is_ignored(sb: SentenceBreak) -> bool {
+    matches!(sb, SentenceBreak::Extend | SentenceBreak::Format)
+}
+
+fn is_para_sep(sb: SentenceBreak) -> bool {
+    matches!(
+        sb,
+        SentenceBreak::Sep | SentenceBreak::Cr | SentenceBreak::Lf
+    )
+}
+
+fn is_sa_term(sb: SentenceBreak) -> bool {
+    matches!(sb, SentenceBreak::STerm | SentenceBreak::ATerm)
+}
+
+/// How far a `SATerm Close* Sp* ParaSep?` sequence got.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TermPhase {
+    Close,
+    Sp,
+    ParaSep,
+}
+
+/// The sentence terminator the chars seen so far end with.
+#[derive(Debug, Clone, Copy)]
+struct Term {
+    is_a_term: bool,
+    phase: TermPhase,
+    /// The result of the SB8 look ahead, which finds the same char from
+    /// everywhere in the sequence, so it is only done once.
+    is_lower_ahead: Option<bool>,
+}
+
+/// The state of the sentence boundary rules, after the chars seen so far.
+#[derive(Debug, Clone, Copy)]
+struct SentenceState {
+    /// The property of the last char, which was not ignored.
+    before: SentenceBreak,
+    /// The property of the not ignored char in front of that.
+    before_before: Option<SentenceBreak>,
+    term: Option<Term>,
+}
+
+impl SentenceState {
+    fn new(first: SentenceBreak) -> Self {
+        let mut state = SentenceState {
+            before: first,
+            before_before: None,
+            term: None,
+        };
+        state.update_term(first);
+
+        state
+    }
+
+    /// Check if there is a boundary in front of the char, and add it to the
+    /// state.
+    ///
+    /// `rest` are the chars starting with it, which SB8 looks ahead into.
+    fn is_boundary_before(&mut self, sb: SentenceBreak, rest: UTF8Chars) -> bool {
+        use SentenceBreak::*;
+
+        // SB4
+        if is_para_sep(self.before) {
+            *self = SentenceState::new(sb);
+            return true;
+        }
+
+        // SB5
+        if is_ignored(sb) {
+            return false;
+        }
+
+        // The terminator sequence the chars end with, unless it is already
+        // ended by a paragraph separator.
+        let term = self.term.filter(|term| term.phase != TermPhase::ParaSep);
+
+        let boundary = match (self.before_before, self.before, sb) {
+            // SB6
+            (_, ATerm, Numeric) => false,
+            // SB7
+            (Some(Upper | Lower), ATerm, Upper) => false,
+            _ => match term {
+                // SB8
+                Some(Term {
+                    is_a_term: true, ..
+                }) if self.is_lower_ahead(rest) => false,
+                // SB8a
+                Some(_) if sb == SContinue || is_sa_term(sb) => false,
+                // SB9
+                Some(Term {
+                    phase: TermPhase::Close,
+                    ..
+                }) if sb == Close => false,
+                // SB9, SB10
+                Some(_) if sb == Sp || is_para_sep(sb) => false,
+                // SB11
+                Some(_) => true,
+                // SB998
+                None => false,
+            },
+        };
+
+        if boundary {
+            *self = SentenceState::new(sb);
+        } else {
+            self.before_before = Some(self.before);
+            self.before = sb;
+            self.update_term(sb);
+        }
+
+        boundary
+    }
+
+    /// Check if the terminator sequence is followed by a lower case letter
+    /// (SB8).
+    fn is_lower_ahead(&mut self, rest: UTF8Chars) -> bool {
+        let Some(term) = &mut self.term else {
+            return false;
+        };
+
+        *term
+            .is_lower_ahead
+            .get_or_insert_with(|| is_lower_ahead(rest))
+    }
+
+    fn update_term(&mut self, sb: SentenceBreak) {
+        let phase = self.term.map(|term| term.phase);
+
+        self.term = match (phase, sb) {
+            (_, sb) if is_sa_term(sb) => Some(Term {
+                is_a_term: sb == SentenceBreak::ATerm,
+                phase: TermPhase::Close,
+                is_lower_ahead: None,
+            }),
+            (Some(TermPhase::Close), SentenceBreak::Close) => self.term,
+            (Some(TermPhase::Close | TermPhase::Sp), SentenceBreak::Sp) => {
+                self.term.map(|term| Term {
+                    phase: TermPhase::Sp,
+                    ..term
+                })
+            }
+            (Some(TermPhase::Close | TermPhase::Sp), sb) if is_para_sep(sb) => {
+                self.term.map(|term| Term {
+                    phase: TermPhase::ParaSep,
+                    ..term
+                })
+            }
+            _ => None,
+        };
+    }
+}
+
+/// Check if the first char, which is a letter, a paragraph separator or a
+/// sentence terminator, is lower case (SB8).
+fn is_lower_ahead(rest: UTF8Chars) -> bool {
+    use SentenceBreak::*;
+
+    rest.map(char_sentence_break)
+        .find(|&sb| matches!(sb, OLetter | Upper | Lower) || is_para_sep(sb) || is_sa_term(sb))
+        == Some(Lower)
+}
+
+/// Get the byte length of the sentence at the start of the text.
+fn sentence_len(text: &str) -> usize {
+    let mut chars = text.utf8_chars();
+    let Some(first) = chars.next() else {
+        return 0;
+    };
+    let mut state = SentenceState::new(char_sentence_break(first));
+
+    loop {
+        let rest = chars.as_str();
+        let Some(utf8_char) = chars.next() else {
+            return text.len();
+        };
+
+        if state.is_boundary_before(char_sentence_break(utf8_char), rest.utf8_chars()) {
+            return text.len() - rest.len();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ToUTF8Sentences;
+
+    #[test]
+    fn utf8_sentences() {
+        let sentences =
+            "Mr. Smith went to Washington. (He said \"Hi!\") It's 3.5 km, etc. and so on?!\nYes."
+                .utf8_sentences()
+                .collect::<Vec<_>>();
+
+        assert_eq!(
+            sentences,
+            [
+                "Mr. ",
+                "Smith went to Washington. ",
+                "(He said \"Hi!\") ",
+                "It's 3.5 km, etc. and so on?!\n",
+                "Yes."
+            ]
+        );
+    }
+
+    #[test]
+    fn utf8_sentences_never_split_chars() {
+        let sentences = "Wow.\u{301} 👨‍👩‍👦 Great! 🏳️‍🌈"
+            .utf8_sentences()
+            .collect::<Vec<_>>();
+
+        assert_eq!(sentences, ["Wow.\u{301} ", "👨‍👩‍👦 Great! ", "🏳️‍🌈"]);
+    }
+
+    #[test]
+    fn utf8_sentences_long_space_run() {
+        let spaces = " ".repeat(100_000);
+
+        let text = format!("etc.{spaces}and so on.");
+        assert_eq!(text.utf8_sentences().collect::<Vec<_>>(), [text.as_str()]);
+
+        let text = format!("Done.{spaces}Next.");
+        assert_eq!(
+            text.utf8_sentences().collect::<Vec<_>>(),
+            [format!("Done.{spaces}").as_str(), "Next."]
+        );
+    }
+}
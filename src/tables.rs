@@ -0,0 +1,56 @@
+//! Unicode character property tables.
+//!
+//! The tables in the sub-modules are sorted, non-overlapping code point
+//! ranges. Code points that are not listed have the default value of the
+//! respective property.
+//!
+//! The sub-modules are generated by `scripts/gen_tables.py`, which downloads
+//! the Unicode Character Database version it is pinned to.
+
+mod grapheme;
+mod line_break;
+mod sentence;
+mod width;
+mod word;
+
+pub(crate) use grapheme::{
+    grapheme_cluster_break, indic_conjunct_break, is_extended_pictographic, GraphemeClusterBreak,
+    IndicConjunctBreak,
+};
+pub(crate) use line_break::{
+    is_east_asian, is_final_quote, is_initial_quote, line_break, LineBreak,
+};
+pub(crate) use sentence::{sentence_break, SentenceBreak};
+pub(crate) use width::{char_width, is_emoji, CharWidth};
+pub(crate) use word::{word_break, WordBreak};
+
+/// Look up the value of `c` in a range table.
+fn lookup<T: Copy>(c: char, table: &[(char, char, T)]) -> Option<T> {
+    table
+        .binary_search_by(|&(start, end, _)| {
+            if c < start {
+                std::cmp::Ordering::Greater
+            } else if c > end {
+                std::cmp::Ordering::Less
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .ok()
+        .map(|idx| table[idx].2)
+}
+
+/// Check if `c` is inside one of the ranges of a table.
+fn contains(c: char, table: &[(char, char)]) -> bool {
+    table
+        .binary_search_by(|&(start, end)| {
+            if c < start {
+                std::cmp::Ordering::Greater
+            } else if c > end {
+                std::cmp::Ordering::Less
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .is_ok()
+}
@@ -0,0 +1,849 @@
+//! Grapheme cluster related properties, see [UAX #29](https://www.unicode.org/reports/tr29/)
+//! and [UTS #51](https://www.unicode.org/reports/tr51/).
+//!
+//! Generated from the Unicode Character Database 16.0.0 by
+//! `scripts/gen_tables.py`, do not edit by hand.
+
+use super::{contains, lookup};
+
+/// The `Grapheme_Cluster_Break` property of a code point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum GraphemeClusterBreak {
+    Other,
+    Cr,
+    Lf,
+    Control,
+    Extend,
+    Zwj,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    L,
+    V,
+    T,
+    Lv,
+    Lvt,
+}
+
+/// The `Indic_Conjunct_Break` property of a code point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum IndicConjunctBreak {
+    None,
+    Consonant,
+    Extend,
+    Linker,
+}
+
+pub(crate) fn grapheme_cluster_break(c: char) -> GraphemeClusterBreak {
+    lookup(c, gcb::GRAPHEME_CLUSTER_BREAK).unwrap_or(GraphemeClusterBreak::Other)
+}
+
+pub(crate) fn indic_conjunct_break(c: char) -> IndicConjunctBreak {
+    lookup(c, incb::INDIC_CONJUNCT_BREAK).unwrap_or(IndicConjunctBreak::None)
+}
+
+pub(crate) fn is_extended_pictographic(c: char) -> bool {
+    contains(c, EXTENDED_PICTOGRAPHIC)
+}
+
+mod gcb {
+    use super::GraphemeClusterBreak::{self, *};
+
+    #[rustfmt::skip]
+    pub(super) const GRAPHEME_CLUSTER_BREAK: &[(char, char, GraphemeClusterBreak)] = &[
+        ('\u{0}', '\u{9}', Control), ('\u{a}', '\u{a}', Lf), ('\u{b}', '\u{c}', Control),
+        ('\u{d}', '\u{d}', Cr), ('\u{e}', '\u{1f}', Control), ('\u{7f}', '\u{9f}', Control),
+        ('\u{ad}', '\u{ad}', Control), ('\u{300}', '\u{36f}', Extend),
+        ('\u{483}', '\u{489}', Extend), ('\u{591}', '\u{5bd}', Extend),
+        ('\u{5bf}', '\u{5bf}', Extend), ('\u{5c1}', '\u{5c2}', Extend),
+        ('\u{5c4}', '\u{5c5}', Extend), ('\u{5c7}', '\u{5c7}', Extend),
+        ('\u{600}', '\u{605}', Prepend), ('\u{610}', '\u{61a}', Extend),
+        ('\u{61c}', '\u{61c}', Control), ('\u{64b}', '\u{65f}', Extend),
+        ('\u{670}', '\u{670}', Extend), ('\u{6d6}', '\u{6dc}', Extend),
+        ('\u{6dd}', '\u{6dd}', Prepend), ('\u{6df}', '\u{6e4}', Extend),
+        ('\u{6e7}', '\u{6e8}', Extend), ('\u{6ea}', '\u{6ed}', Extend),
+        ('\u{70f}', '\u{70f}', Prepend), ('\u{711}', '\u{711}', Extend),
+        ('\u{730}', '\u{74a}', Extend), ('\u{7a6}', '\u{7b0}', Extend),
+        ('\u{7eb}', '\u{7f3}', Extend), ('\u{7fd}', '\u{7fd}', Extend),
+        ('\u{816}', '\u{819}', Extend), ('\u{81b}', '\u{823}', Extend),
+        ('\u{825}', '\u{827}', Extend), ('\u{829}', '\u{82d}', Extend),
+        ('\u{859}', '\u{85b}', Extend), ('\u{890}', '\u{891}', Prepend),
+        ('\u{897}', '\u{89f}', Extend), ('\u{8ca}', '\u{8e1}', Extend),
+        ('\u{8e2}', '\u{8e2}', Prepend), ('\u{8e3}', '\u{902}', Extend),
+        ('\u{903}', '\u{903}', SpacingMark), ('\u{93a}', '\u{93a}', Extend),
+        ('\u{93b}', '\u{93b}', SpacingMark), ('\u{93c}', '\u{93c}', Extend),
+        ('\u{93e}', '\u{940}', SpacingMark), ('\u{941}', '\u{948}', Extend),
+        ('\u{949}', '\u{94c}', SpacingMark), ('\u{94d}', '\u{94d}', Extend),
+        ('\u{94e}', '\u{94f}', SpacingMark), ('\u{951}', '\u{957}', Extend),
+        ('\u{962}', '\u{963}', Extend), ('\u{981}', '\u{981}', Extend),
+        ('\u{982}', '\u{983}', SpacingMark), ('\u{9bc}', '\u{9bc}', Extend),
+        ('\u{9be}', '\u{9be}', Extend), ('\u{9bf}', '\u{9c0}', SpacingMark),
+        ('\u{9c1}', '\u{9c4}', Extend), ('\u{9c7}', '\u{9c8}', SpacingMark),
+        ('\u{9cb}', '\u{9cc}', SpacingMark), ('\u{9cd}', '\u{9cd}', Extend),
+        ('\u{9d7}', '\u{9d7}', Extend), ('\u{9e2}', '\u{9e3}', Extend),
+        ('\u{9fe}', '\u{9fe}', Extend), ('\u{a01}', '\u{a02}', Extend),
+        ('\u{a03}', '\u{a03}', SpacingMark), ('\u{a3c}', '\u{a3c}', Extend),
+        ('\u{a3e}', '\u{a40}', SpacingMark), ('\u{a41}', '\u{a42}', Extend),
+        ('\u{a47}', '\u{a48}', Extend), ('\u{a4b}', '\u{a4d}', Extend),
+        ('\u{a51}', '\u{a51}', Extend), ('\u{a70}', '\u{a71}', Extend),
+        ('\u{a75}', '\u{a75}', Extend), ('\u{a81}', '\u{a82}', Extend),
+        ('\u{a83}', '\u{a83}', SpacingMark), ('\u{abc}', '\u{abc}', Extend),
+        ('\u{abe}', '\u{ac0}', SpacingMark), ('\u{ac1}', '\u{ac5}', Extend),
+        ('\u{ac7}', '\u{ac8}', Extend), ('\u{ac9}', '\u{ac9}', SpacingMark),
+        ('\u{acb}', '\u{acc}', SpacingMark), ('\u{acd}', '\u{acd}', Extend),
+        ('\u{ae2}', '\u{ae3}', Extend), ('\u{afa}', '\u{aff}', Extend),
+        ('\u{b01}', '\u{b01}', Extend), ('\u{b02}', '\u{b03}', SpacingMark),
+        ('\u{b3c}', '\u{b3c}', Extend), ('\u{b3e}', '\u{b3f}', Extend),
+        ('\u{b40}', '\u{b40}', SpacingMark), ('\u{b41}', '\u{b44}', Extend),
+        ('\u{b47}', '\u{b48}', SpacingMark), ('\u{b4b}', '\u{b4c}', SpacingMark),
+        ('\u{b4d}', '\u{b4d}', Extend), ('\u{b55}', '\u{b57}', Extend),
+        ('\u{b62}', '\u{b63}', Extend), ('\u{b82}', '\u{b82}', Extend),
+        ('\u{bbe}', '\u{bbe}', Extend), ('\u{bbf}', '\u{bbf}', SpacingMark),
+        ('\u{bc0}', '\u{bc0}', Extend), ('\u{bc1}', '\u{bc2}', SpacingMark),
+        ('\u{bc6}', '\u{bc8}', SpacingMark), ('\u{bca}', '\u{bcc}', SpacingMark),
+        ('\u{bcd}', '\u{bcd}', Extend), ('\u{bd7}', '\u{bd7}', Extend),
+        ('\u{c00}', '\u{c00}', Extend), ('\u{c01}', '\u{c03}', SpacingMark),
+        ('\u{c04}', '\u{c04}', Extend), ('\u{c3c}', '\u{c3c}', Extend),
+        ('\u{c3e}', '\u{c40}', Extend), ('\u{c41}', '\u{c44}', SpacingMark),
+        ('\u{c46}', '\u{c48}', Extend), ('\u{c4a}', '\u{c4d}', Extend),
+        ('\u{c55}', '\u{c56}', Extend), ('\u{c62}', '\u{c63}', Extend),
+        ('\u{c81}', '\u{c81}', Extend), ('\u{c82}', '\u{c83}', SpacingMark),
+        ('\u{cbc}', '\u{cbc}', Extend), ('\u{cbe}', '\u{cbe}', SpacingMark),
+        ('\u{cbf}', '\u{cc0}', Extend), ('\u{cc1}', '\u{cc1}', SpacingMark),
+        ('\u{cc2}', '\u{cc2}', Extend), ('\u{cc3}', '\u{cc4}', SpacingMark),
+        ('\u{cc6}', '\u{cc8}', Extend), ('\u{cca}', '\u{ccd}', Extend),
+        ('\u{cd5}', '\u{cd6}', Extend), ('\u{ce2}', '\u{ce3}', Extend),
+        ('\u{cf3}', '\u{cf3}', SpacingMark), ('\u{d00}', '\u{d01}', Extend),
+        ('\u{d02}', '\u{d03}', SpacingMark), ('\u{d3b}', '\u{d3c}', Extend),
+        ('\u{d3e}', '\u{d3e}', Extend), ('\u{d3f}', '\u{d40}', SpacingMark),
+        ('\u{d41}', '\u{d44}', Extend), ('\u{d46}', '\u{d48}', SpacingMark),
+        ('\u{d4a}', '\u{d4c}', SpacingMark), ('\u{d4d}', '\u{d4d}', Extend),
+        ('\u{d4e}', '\u{d4e}', Prepend), ('\u{d57}', '\u{d57}', Extend),
+        ('\u{d62}', '\u{d63}', Extend), ('\u{d81}', '\u{d81}', Extend),
+        ('\u{d82}', '\u{d83}', SpacingMark), ('\u{dca}', '\u{dca}', Extend),
+        ('\u{dcf}', '\u{dcf}', Extend), ('\u{dd0}', '\u{dd1}', SpacingMark),
+        ('\u{dd2}', '\u{dd4}', Extend), ('\u{dd6}', '\u{dd6}', Extend),
+        ('\u{dd8}', '\u{dde}', SpacingMark), ('\u{ddf}', '\u{ddf}', Extend),
+        ('\u{df2}', '\u{df3}', SpacingMark), ('\u{e31}', '\u{e31}', Extend),
+        ('\u{e33}', '\u{e33}', SpacingMark), ('\u{e34}', '\u{e3a}', Extend),
+        ('\u{e47}', '\u{e4e}', Extend), ('\u{eb1}', '\u{eb1}', Extend),
+        ('\u{eb3}', '\u{eb3}', SpacingMark), ('\u{eb4}', '\u{ebc}', Extend),
+        ('\u{ec8}', '\u{ece}', Extend), ('\u{f18}', '\u{f19}', Extend),
+        ('\u{f35}', '\u{f35}', Extend), ('\u{f37}', '\u{f37}', Extend),
+        ('\u{f39}', '\u{f39}', Extend), ('\u{f3e}', '\u{f3f}', SpacingMark),
+        ('\u{f71}', '\u{f7e}', Extend), ('\u{f7f}', '\u{f7f}', SpacingMark),
+        ('\u{f80}', '\u{f84}', Extend), ('\u{f86}', '\u{f87}', Extend),
+        ('\u{f8d}', '\u{f97}', Extend), ('\u{f99}', '\u{fbc}', Extend),
+        ('\u{fc6}', '\u{fc6}', Extend), ('\u{102d}', '\u{1030}', Extend),
+        ('\u{1031}', '\u{1031}', SpacingMark), ('\u{1032}', '\u{1037}', Extend),
+        ('\u{1039}', '\u{103a}', Extend), ('\u{103b}', '\u{103c}', SpacingMark),
+        ('\u{103d}', '\u{103e}', Extend), ('\u{1056}', '\u{1057}', SpacingMark),
+        ('\u{1058}', '\u{1059}', Extend), ('\u{105e}', '\u{1060}', Extend),
+        ('\u{1071}', '\u{1074}', Extend), ('\u{1082}', '\u{1082}', Extend),
+        ('\u{1084}', '\u{1084}', SpacingMark), ('\u{1085}', '\u{1086}', Extend),
+        ('\u{108d}', '\u{108d}', Extend), ('\u{109d}', '\u{109d}', Extend),
+        ('\u{1100}', '\u{115f}', L), ('\u{1160}', '\u{11a7}', V), ('\u{11a8}', '\u{11ff}', T),
+        ('\u{135d}', '\u{135f}', Extend), ('\u{1712}', '\u{1715}', Extend),
+        ('\u{1732}', '\u{1734}', Extend), ('\u{1752}', '\u{1753}', Extend),
+        ('\u{1772}', '\u{1773}', Extend), ('\u{17b4}', '\u{17b5}', Extend),
+        ('\u{17b6}', '\u{17b6}', SpacingMark), ('\u{17b7}', '\u{17bd}', Extend),
+        ('\u{17be}', '\u{17c5}', SpacingMark), ('\u{17c6}', '\u{17c6}', Extend),
+        ('\u{17c7}', '\u{17c8}', SpacingMark), ('\u{17c9}', '\u{17d3}', Extend),
+        ('\u{17dd}', '\u{17dd}', Extend), ('\u{180b}', '\u{180d}', Extend),
+        ('\u{180e}', '\u{180e}', Control), ('\u{180f}', '\u{180f}', Extend),
+        ('\u{1885}', '\u{1886}', Extend), ('\u{18a9}', '\u{18a9}', Extend),
+        ('\u{1920}', '\u{1922}', Extend), ('\u{1923}', '\u{1926}', SpacingMark),
+        ('\u{1927}', '\u{1928}', Extend), ('\u{1929}', '\u{192b}', SpacingMark),
+        ('\u{1930}', '\u{1931}', SpacingMark), ('\u{1932}', '\u{1932}', Extend),
+        ('\u{1933}', '\u{1938}', SpacingMark), ('\u{1939}', '\u{193b}', Extend),
+        ('\u{1a17}', '\u{1a18}', Extend), ('\u{1a19}', '\u{1a1a}', SpacingMark),
+        ('\u{1a1b}', '\u{1a1b}', Extend), ('\u{1a55}', '\u{1a55}', SpacingMark),
+        ('\u{1a56}', '\u{1a56}', Extend), ('\u{1a57}', '\u{1a57}', SpacingMark),
+        ('\u{1a58}', '\u{1a5e}', Extend), ('\u{1a60}', '\u{1a60}', Extend),
+        ('\u{1a62}', '\u{1a62}', Extend), ('\u{1a65}', '\u{1a6c}', Extend),
+        ('\u{1a6d}', '\u{1a72}', SpacingMark), ('\u{1a73}', '\u{1a7c}', Extend),
+        ('\u{1a7f}', '\u{1a7f}', Extend), ('\u{1ab0}', '\u{1ace}', Extend),
+        ('\u{1b00}', '\u{1b03}', Extend), ('\u{1b04}', '\u{1b04}', SpacingMark),
+        ('\u{1b34}', '\u{1b3d}', Extend), ('\u{1b3e}', '\u{1b41}', SpacingMark),
+        ('\u{1b42}', '\u{1b44}', Extend), ('\u{1b6b}', '\u{1b73}', Extend),
+        ('\u{1b80}', '\u{1b81}', Extend), ('\u{1b82}', '\u{1b82}', SpacingMark),
+        ('\u{1ba1}', '\u{1ba1}', SpacingMark), ('\u{1ba2}', '\u{1ba5}', Extend),
+        ('\u{1ba6}', '\u{1ba7}', SpacingMark), ('\u{1ba8}', '\u{1bad}', Extend),
+        ('\u{1be6}', '\u{1be6}', Extend), ('\u{1be7}', '\u{1be7}', SpacingMark),
+        ('\u{1be8}', '\u{1be9}', Extend), ('\u{1bea}', '\u{1bec}', SpacingMark),
+        ('\u{1bed}', '\u{1bed}', Extend), ('\u{1bee}', '\u{1bee}', SpacingMark),
+        ('\u{1bef}', '\u{1bf3}', Extend), ('\u{1c24}', '\u{1c2b}', SpacingMark),
+        ('\u{1c2c}', '\u{1c33}', Extend), ('\u{1c34}', '\u{1c35}', SpacingMark),
+        ('\u{1c36}', '\u{1c37}', Extend), ('\u{1cd0}', '\u{1cd2}', Extend),
+        ('\u{1cd4}', '\u{1ce0}', Extend), ('\u{1ce1}', '\u{1ce1}', SpacingMark),
+        ('\u{1ce2}', '\u{1ce8}', Extend), ('\u{1ced}', '\u{1ced}', Extend),
+        ('\u{1cf4}', '\u{1cf4}', Extend), ('\u{1cf7}', '\u{1cf7}', SpacingMark),
+        ('\u{1cf8}', '\u{1cf9}', Extend), ('\u{1dc0}', '\u{1dff}', Extend),
+        ('\u{200b}', '\u{200b}', Control), ('\u{200c}', '\u{200c}', Extend),
+        ('\u{200d}', '\u{200d}', Zwj), ('\u{200e}', '\u{200f}', Control),
+        ('\u{2028}', '\u{202e}', Control), ('\u{2060}', '\u{206f}', Control),
+        ('\u{20d0}', '\u{20f0}', Extend), ('\u{2cef}', '\u{2cf1}', Extend),
+        ('\u{2d7f}', '\u{2d7f}', Extend), ('\u{2de0}', '\u{2dff}', Extend),
+        ('\u{302a}', '\u{302f}', Extend), ('\u{3099}', '\u{309a}', Extend),
+        ('\u{a66f}', '\u{a672}', Extend), ('\u{a674}', '\u{a67d}', Extend),
+        ('\u{a69e}', '\u{a69f}', Extend), ('\u{a6f0}', '\u{a6f1}', Extend),
+        ('\u{a802}', '\u{a802}', Extend), ('\u{a806}', '\u{a806}', Extend),
+        ('\u{a80b}', '\u{a80b}', Extend), ('\u{a823}', '\u{a824}', SpacingMark),
+        ('\u{a825}', '\u{a826}', Extend), ('\u{a827}', '\u{a827}', SpacingMark),
+        ('\u{a82c}', '\u{a82c}', Extend), ('\u{a880}', '\u{a881}', SpacingMark),
+        ('\u{a8b4}', '\u{a8c3}', SpacingMark), ('\u{a8c4}', '\u{a8c5}', Extend),
+        ('\u{a8e0}', '\u{a8f1}', Extend), ('\u{a8ff}', '\u{a8ff}', Extend),
+        ('\u{a926}', '\u{a92d}', Extend), ('\u{a947}', '\u{a951}', Extend),
+        ('\u{a952}', '\u{a952}', SpacingMark), ('\u{a953}', '\u{a953}', Extend),
+        ('\u{a960}', '\u{a97c}', L), ('\u{a980}', '\u{a982}', Extend),
+        ('\u{a983}', '\u{a983}', SpacingMark), ('\u{a9b3}', '\u{a9b3}', Extend),
+        ('\u{a9b4}', '\u{a9b5}', SpacingMark), ('\u{a9b6}', '\u{a9b9}', Extend),
+        ('\u{a9ba}', '\u{a9bb}', SpacingMark), ('\u{a9bc}', '\u{a9bd}', Extend),
+        ('\u{a9be}', '\u{a9bf}', SpacingMark), ('\u{a9c0}', '\u{a9c0}', Extend),
+        ('\u{a9e5}', '\u{a9e5}', Extend), ('\u{aa29}', '\u{aa2e}', Extend),
+        ('\u{aa2f}', '\u{aa30}', SpacingMark), ('\u{aa31}', '\u{aa32}', Extend),
+        ('\u{aa33}', '\u{aa34}', SpacingMark), ('\u{aa35}', '\u{aa36}', Extend),
+        ('\u{aa43}', '\u{aa43}', Extend), ('\u{aa4c}', '\u{aa4c}', Extend),
+        ('\u{aa4d}', '\u{aa4d}', SpacingMark), ('\u{aa7c}', '\u{aa7c}', Extend),
+        ('\u{aab0}', '\u{aab0}', Extend), ('\u{aab2}', '\u{aab4}', Extend),
+        ('\u{aab7}', '\u{aab8}', Extend), ('\u{aabe}', '\u{aabf}', Extend),
+        ('\u{aac1}', '\u{aac1}', Extend), ('\u{aaeb}', '\u{aaeb}', SpacingMark),
+        ('\u{aaec}', '\u{aaed}', Extend), ('\u{aaee}', '\u{aaef}', SpacingMark),
+        ('\u{aaf5}', '\u{aaf5}', SpacingMark), ('\u{aaf6}', '\u{aaf6}', Extend),
+        ('\u{abe3}', '\u{abe4}', SpacingMark), ('\u{abe5}', '\u{abe5}', Extend),
+        ('\u{abe6}', '\u{abe7}', SpacingMark), ('\u{abe8}', '\u{abe8}', Extend),
+        ('\u{abe9}', '\u{abea}', SpacingMark), ('\u{abec}', '\u{abec}', SpacingMark),
+        ('\u{abed}', '\u{abed}', Extend), ('\u{ac00}', '\u{ac00}', Lv),
+        ('\u{ac01}', '\u{ac1b}', Lvt), ('\u{ac1c}', '\u{ac1c}', Lv), ('\u{ac1d}', '\u{ac37}', Lvt),
+        ('\u{ac38}', '\u{ac38}', Lv), ('\u{ac39}', '\u{ac53}', Lvt), ('\u{ac54}', '\u{ac54}', Lv),
+        ('\u{ac55}', '\u{ac6f}', Lvt), ('\u{ac70}', '\u{ac70}', Lv), ('\u{ac71}', '\u{ac8b}', Lvt),
+        ('\u{ac8c}', '\u{ac8c}', Lv), ('\u{ac8d}', '\u{aca7}', Lvt), ('\u{aca8}', '\u{aca8}', Lv),
+        ('\u{aca9}', '\u{acc3}', Lvt), ('\u{acc4}', '\u{acc4}', Lv), ('\u{acc5}', '\u{acdf}', Lvt),
+        ('\u{ace0}', '\u{ace0}', Lv), ('\u{ace1}', '\u{acfb}', Lvt), ('\u{acfc}', '\u{acfc}', Lv),
+        ('\u{acfd}', '\u{ad17}', Lvt), ('\u{ad18}', '\u{ad18}', Lv), ('\u{ad19}', '\u{ad33}', Lvt),
+        ('\u{ad34}', '\u{ad34}', Lv), ('\u{ad35}', '\u{ad4f}', Lvt), ('\u{ad50}', '\u{ad50}', Lv),
+        ('\u{ad51}', '\u{ad6b}', Lvt), ('\u{ad6c}', '\u{ad6c}', Lv), ('\u{ad6d}', '\u{ad87}', Lvt),
+        ('\u{ad88}', '\u{ad88}', Lv), ('\u{ad89}', '\u{ada3}', Lvt), ('\u{ada4}', '\u{ada4}', Lv),
+        ('\u{ada5}', '\u{adbf}', Lvt), ('\u{adc0}', '\u{adc0}', Lv), ('\u{adc1}', '\u{addb}', Lvt),
+        ('\u{addc}', '\u{addc}', Lv), ('\u{addd}', '\u{adf7}', Lvt), ('\u{adf8}', '\u{adf8}', Lv),
+        ('\u{adf9}', '\u{ae13}', Lvt), ('\u{ae14}', '\u{ae14}', Lv), ('\u{ae15}', '\u{ae2f}', Lvt),
+        ('\u{ae30}', '\u{ae30}', Lv), ('\u{ae31}', '\u{ae4b}', Lvt), ('\u{ae4c}', '\u{ae4c}', Lv),
+        ('\u{ae4d}', '\u{ae67}', Lvt), ('\u{ae68}', '\u{ae68}', Lv), ('\u{ae69}', '\u{ae83}', Lvt),
+        ('\u{ae84}', '\u{ae84}', Lv), ('\u{ae85}', '\u{ae9f}', Lvt), ('\u{aea0}', '\u{aea0}', Lv),
+        ('\u{aea1}', '\u{aebb}', Lvt), ('\u{aebc}', '\u{aebc}', Lv), ('\u{aebd}', '\u{aed7}', Lvt),
+        ('\u{aed8}', '\u{aed8}', Lv), ('\u{aed9}', '\u{aef3}', Lvt), ('\u{aef4}', '\u{aef4}', Lv),
+        ('\u{aef5}', '\u{af0f}', Lvt), ('\u{af10}', '\u{af10}', Lv), ('\u{af11}', '\u{af2b}', Lvt),
+        ('\u{af2c}', '\u{af2c}', Lv), ('\u{af2d}', '\u{af47}', Lvt), ('\u{af48}', '\u{af48}', Lv),
+        ('\u{af49}', '\u{af63}', Lvt), ('\u{af64}', '\u{af64}', Lv), ('\u{af65}', '\u{af7f}', Lvt),
+        ('\u{af80}', '\u{af80}', Lv), ('\u{af81}', '\u{af9b}', Lvt), ('\u{af9c}', '\u{af9c}', Lv),
+        ('\u{af9d}', '\u{afb7}', Lvt), ('\u{afb8}', '\u{afb8}', Lv), ('\u{afb9}', '\u{afd3}', Lvt),
+        ('\u{afd4}', '\u{afd4}', Lv), ('\u{afd5}', '\u{afef}', Lvt), ('\u{aff0}', '\u{aff0}', Lv),
+        ('\u{aff1}', '\u{b00b}', Lvt), ('\u{b00c}', '\u{b00c}', Lv), ('\u{b00d}', '\u{b027}', Lvt),
+        ('\u{b028}', '\u{b028}', Lv), ('\u{b029}', '\u{b043}', Lvt), ('\u{b044}', '\u{b044}', Lv),
+        ('\u{b045}', '\u{b05f}', Lvt), ('\u{b060}', '\u{b060}', Lv), ('\u{b061}', '\u{b07b}', Lvt),
+        ('\u{b07c}', '\u{b07c}', Lv), ('\u{b07d}', '\u{b097}', Lvt), ('\u{b098}', '\u{b098}', Lv),
+        ('\u{b099}', '\u{b0b3}', Lvt), ('\u{b0b4}', '\u{b0b4}', Lv), ('\u{b0b5}', '\u{b0cf}', Lvt),
+        ('\u{b0d0}', '\u{b0d0}', Lv), ('\u{b0d1}', '\u{b0eb}', Lvt), ('\u{b0ec}', '\u{b0ec}', Lv),
+        ('\u{b0ed}', '\u{b107}', Lvt), ('\u{b108}', '\u{b108}', Lv), ('\u{b109}', '\u{b123}', Lvt),
+        ('\u{b124}', '\u{b124}', Lv), ('\u{b125}', '\u{b13f}', Lvt), ('\u{b140}', '\u{b140}', Lv),
+        ('\u{b141}', '\u{b15b}', Lvt), ('\u{b15c}', '\u{b15c}', Lv), ('\u{b15d}', '\u{b177}', Lvt),
+        ('\u{b178}', '\u{b178}', Lv), ('\u{b179}', '\u{b193}', Lvt), ('\u{b194}', '\u{b194}', Lv),
+        ('\u{b195}', '\u{b1af}', Lvt), ('\u{b1b0}', '\u{b1b0}', Lv), ('\u{b1b1}', '\u{b1cb}', Lvt),
+        ('\u{b1cc}', '\u{b1cc}', Lv), ('\u{b1cd}', '\u{b1e7}', Lvt), ('\u{b1e8}', '\u{b1e8}', Lv),
+        ('\u{b1e9}', '\u{b203}', Lvt), ('\u{b204}', '\u{b204}', Lv), ('\u{b205}', '\u{b21f}', Lvt),
+        ('\u{b220}', '\u{b220}', Lv), ('\u{b221}', '\u{b23b}', Lvt), ('\u{b23c}', '\u{b23c}', Lv),
+        ('\u{b23d}', '\u{b257}', Lvt), ('\u{b258}', '\u{b258}', Lv), ('\u{b259}', '\u{b273}', Lvt),
+        ('\u{b274}', '\u{b274}', Lv), ('\u{b275}', '\u{b28f}', Lvt), ('\u{b290}', '\u{b290}', Lv),
+        ('\u{b291}', '\u{b2ab}', Lvt), ('\u{b2ac}', '\u{b2ac}', Lv), ('\u{b2ad}', '\u{b2c7}', Lvt),
+        ('\u{b2c8}', '\u{b2c8}', Lv), ('\u{b2c9}', '\u{b2e3}', Lvt), ('\u{b2e4}', '\u{b2e4}', Lv),
+        ('\u{b2e5}', '\u{b2ff}', Lvt), ('\u{b300}', '\u{b300}', Lv), ('\u{b301}', '\u{b31b}', Lvt),
+        ('\u{b31c}', '\u{b31c}', Lv), ('\u{b31d}', '\u{b337}', Lvt), ('\u{b338}', '\u{b338}', Lv),
+        ('\u{b339}', '\u{b353}', Lvt), ('\u{b354}', '\u{b354}', Lv), ('\u{b355}', '\u{b36f}', Lvt),
+        ('\u{b370}', '\u{b370}', Lv), ('\u{b371}', '\u{b38b}', Lvt), ('\u{b38c}', '\u{b38c}', Lv),
+        ('\u{b38d}', '\u{b3a7}', Lvt), ('\u{b3a8}', '\u{b3a8}', Lv), ('\u{b3a9}', '\u{b3c3}', Lvt),
+        ('\u{b3c4}', '\u{b3c4}', Lv), ('\u{b3c5}', '\u{b3df}', Lvt), ('\u{b3e0}', '\u{b3e0}', Lv),
+        ('\u{b3e1}', '\u{b3fb}', Lvt), ('\u{b3fc}', '\u{b3fc}', Lv), ('\u{b3fd}', '\u{b417}', Lvt),
+        ('\u{b418}', '\u{b418}', Lv), ('\u{b419}', '\u{b433}', Lvt), ('\u{b434}', '\u{b434}', Lv),
+        ('\u{b435}', '\u{b44f}', Lvt), ('\u{b450}', '\u{b450}', Lv), ('\u{b451}', '\u{b46b}', Lvt),
+        ('\u{b46c}', '\u{b46c}', Lv), ('\u{b46d}', '\u{b487}', Lvt), ('\u{b488}', '\u{b488}', Lv),
+        ('\u{b489}', '\u{b4a3}', Lvt), ('\u{b4a4}', '\u{b4a4}', Lv), ('\u{b4a5}', '\u{b4bf}', Lvt),
+        ('\u{b4c0}', '\u{b4c0}', Lv), ('\u{b4c1}', '\u{b4db}', Lvt), ('\u{b4dc}', '\u{b4dc}', Lv),
+        ('\u{b4dd}', '\u{b4f7}', Lvt), ('\u{b4f8}', '\u{b4f8}', Lv), ('\u{b4f9}', '\u{b513}', Lvt),
+        ('\u{b514}', '\u{b514}', Lv), ('\u{b515}', '\u{b52f}', Lvt), ('\u{b530}', '\u{b530}', Lv),
+        ('\u{b531}', '\u{b54b}', Lvt), ('\u{b54c}', '\u{b54c}', Lv), ('\u{b54d}', '\u{b567}', Lvt),
+        ('\u{b568}', '\u{b568}', Lv), ('\u{b569}', '\u{b583}', Lvt), ('\u{b584}', '\u{b584}', Lv),
+        ('\u{b585}', '\u{b59f}', Lvt), ('\u{b5a0}', '\u{b5a0}', Lv), ('\u{b5a1}', '\u{b5bb}', Lvt),
+        ('\u{b5bc}', '\u{b5bc}', Lv), ('\u{b5bd}', '\u{b5d7}', Lvt), ('\u{b5d8}', '\u{b5d8}', Lv),
+        ('\u{b5d9}', '\u{b5f3}', Lvt), ('\u{b5f4}', '\u{b5f4}', Lv), ('\u{b5f5}', '\u{b60f}', Lvt),
+        ('\u{b610}', '\u{b610}', Lv), ('\u{b611}', '\u{b62b}', Lvt), ('\u{b62c}', '\u{b62c}', Lv),
+        ('\u{b62d}', '\u{b647}', Lvt), ('\u{b648}', '\u{b648}', Lv), ('\u{b649}', '\u{b663}', Lvt),
+        ('\u{b664}', '\u{b664}', Lv), ('\u{b665}', '\u{b67f}', Lvt), ('\u{b680}', '\u{b680}', Lv),
+        ('\u{b681}', '\u{b69b}', Lvt), ('\u{b69c}', '\u{b69c}', Lv), ('\u{b69d}', '\u{b6b7}', Lvt),
+        ('\u{b6b8}', '\u{b6b8}', Lv), ('\u{b6b9}', '\u{b6d3}', Lvt), ('\u{b6d4}', '\u{b6d4}', Lv),
+        ('\u{b6d5}', '\u{b6ef}', Lvt), ('\u{b6f0}', '\u{b6f0}', Lv), ('\u{b6f1}', '\u{b70b}', Lvt),
+        ('\u{b70c}', '\u{b70c}', Lv), ('\u{b70d}', '\u{b727}', Lvt), ('\u{b728}', '\u{b728}', Lv),
+        ('\u{b729}', '\u{b743}', Lvt), ('\u{b744}', '\u{b744}', Lv), ('\u{b745}', '\u{b75f}', Lvt),
+        ('\u{b760}', '\u{b760}', Lv), ('\u{b761}', '\u{b77b}', Lvt), ('\u{b77c}', '\u{b77c}', Lv),
+        ('\u{b77d}', '\u{b797}', Lvt), ('\u{b798}', '\u{b798}', Lv), ('\u{b799}', '\u{b7b3}', Lvt),
+        ('\u{b7b4}', '\u{b7b4}', Lv), ('\u{b7b5}', '\u{b7cf}', Lvt), ('\u{b7d0}', '\u{b7d0}', Lv),
+        ('\u{b7d1}', '\u{b7eb}', Lvt), ('\u{b7ec}', '\u{b7ec}', Lv), ('\u{b7ed}', '\u{b807}', Lvt),
+        ('\u{b808}', '\u{b808}', Lv), ('\u{b809}', '\u{b823}', Lvt), ('\u{b824}', '\u{b824}', Lv),
+        ('\u{b825}', '\u{b83f}', Lvt), ('\u{b840}', '\u{b840}', Lv), ('\u{b841}', '\u{b85b}', Lvt),
+        ('\u{b85c}', '\u{b85c}', Lv), ('\u{b85d}', '\u{b877}', Lvt), ('\u{b878}', '\u{b878}', Lv),
+        ('\u{b879}', '\u{b893}', Lvt), ('\u{b894}', '\u{b894}', Lv), ('\u{b895}', '\u{b8af}', Lvt),
+        ('\u{b8b0}', '\u{b8b0}', Lv), ('\u{b8b1}', '\u{b8cb}', Lvt), ('\u{b8cc}', '\u{b8cc}', Lv),
+        ('\u{b8cd}', '\u{b8e7}', Lvt), ('\u{b8e8}', '\u{b8e8}', Lv), ('\u{b8e9}', '\u{b903}', Lvt),
+        ('\u{b904}', '\u{b904}', Lv), ('\u{b905}', '\u{b91f}', Lvt), ('\u{b920}', '\u{b920}', Lv),
+        ('\u{b921}', '\u{b93b}', Lvt), ('\u{b93c}', '\u{b93c}', Lv), ('\u{b93d}', '\u{b957}', Lvt),
+        ('\u{b958}', '\u{b958}', Lv), ('\u{b959}', '\u{b973}', Lvt), ('\u{b974}', '\u{b974}', Lv),
+        ('\u{b975}', '\u{b98f}', Lvt), ('\u{b990}', '\u{b990}', Lv), ('\u{b991}', '\u{b9ab}', Lvt),
+        ('\u{b9ac}', '\u{b9ac}', Lv), ('\u{b9ad}', '\u{b9c7}', Lvt), ('\u{b9c8}', '\u{b9c8}', Lv),
+        ('\u{b9c9}', '\u{b9e3}', Lvt), ('\u{b9e4}', '\u{b9e4}', Lv), ('\u{b9e5}', '\u{b9ff}', Lvt),
+        ('\u{ba00}', '\u{ba00}', Lv), ('\u{ba01}', '\u{ba1b}', Lvt), ('\u{ba1c}', '\u{ba1c}', Lv),
+        ('\u{ba1d}', '\u{ba37}', Lvt), ('\u{ba38}', '\u{ba38}', Lv), ('\u{ba39}', '\u{ba53}', Lvt),
+        ('\u{ba54}', '\u{ba54}', Lv), ('\u{ba55}', '\u{ba6f}', Lvt), ('\u{ba70}', '\u{ba70}', Lv),
+        ('\u{ba71}', '\u{ba8b}', Lvt), ('\u{ba8c}', '\u{ba8c}', Lv), ('\u{ba8d}', '\u{baa7}', Lvt),
+        ('\u{baa8}', '\u{baa8}', Lv), ('\u{baa9}', '\u{bac3}', Lvt), ('\u{bac4}', '\u{bac4}', Lv),
+        ('\u{bac5}', '\u{badf}', Lvt), ('\u{bae0}', '\u{bae0}', Lv), ('\u{bae1}', '\u{bafb}', Lvt),
+        ('\u{bafc}', '\u{bafc}', Lv), ('\u{bafd}', '\u{bb17}', Lvt), ('\u{bb18}', '\u{bb18}', Lv),
+        ('\u{bb19}', '\u{bb33}', Lvt), ('\u{bb34}', '\u{bb34}', Lv), ('\u{bb35}', '\u{bb4f}', Lvt),
+        ('\u{bb50}', '\u{bb50}', Lv), ('\u{bb51}', '\u{bb6b}', Lvt), ('\u{bb6c}', '\u{bb6c}', Lv),
+        ('\u{bb6d}', '\u{bb87}', Lvt), ('\u{bb88}', '\u{bb88}', Lv), ('\u{bb89}', '\u{bba3}', Lvt),
+        ('\u{bba4}', '\u{bba4}', Lv), ('\u{bba5}', '\u{bbbf}', Lvt), ('\u{bbc0}', '\u{bbc0}', Lv),
+        ('\u{bbc1}', '\u{bbdb}', Lvt), ('\u{bbdc}', '\u{bbdc}', Lv), ('\u{bbdd}', '\u{bbf7}', Lvt),
+        ('\u{bbf8}', '\u{bbf8}', Lv), ('\u{bbf9}', '\u{bc13}', Lvt), ('\u{bc14}', '\u{bc14}', Lv),
+        ('\u{bc15}', '\u{bc2f}', Lvt), ('\u{bc30}', '\u{bc30}', Lv), ('\u{bc31}', '\u{bc4b}', Lvt),
+        ('\u{bc4c}', '\u{bc4c}', Lv), ('\u{bc4d}', '\u{bc67}', Lvt), ('\u{bc68}', '\u{bc68}', Lv),
+        ('\u{bc69}', '\u{bc83}', Lvt), ('\u{bc84}', '\u{bc84}', Lv), ('\u{bc85}', '\u{bc9f}', Lvt),
+        ('\u{bca0}', '\u{bca0}', Lv), ('\u{bca1}', '\u{bcbb}', Lvt), ('\u{bcbc}', '\u{bcbc}', Lv),
+        ('\u{bcbd}', '\u{bcd7}', Lvt), ('\u{bcd8}', '\u{bcd8}', Lv), ('\u{bcd9}', '\u{bcf3}', Lvt),
+        ('\u{bcf4}', '\u{bcf4}', Lv), ('\u{bcf5}', '\u{bd0f}', Lvt), ('\u{bd10}', '\u{bd10}', Lv),
+        ('\u{bd11}', '\u{bd2b}', Lvt), ('\u{bd2c}', '\u{bd2c}', Lv), ('\u{bd2d}', '\u{bd47}', Lvt),
+        ('\u{bd48}', '\u{bd48}', Lv), ('\u{bd49}', '\u{bd63}', Lvt), ('\u{bd64}', '\u{bd64}', Lv),
+        ('\u{bd65}', '\u{bd7f}', Lvt), ('\u{bd80}', '\u{bd80}', Lv), ('\u{bd81}', '\u{bd9b}', Lvt),
+        ('\u{bd9c}', '\u{bd9c}', Lv), ('\u{bd9d}', '\u{bdb7}', Lvt), ('\u{bdb8}', '\u{bdb8}', Lv),
+        ('\u{bdb9}', '\u{bdd3}', Lvt), ('\u{bdd4}', '\u{bdd4}', Lv), ('\u{bdd5}', '\u{bdef}', Lvt),
+        ('\u{bdf0}', '\u{bdf0}', Lv), ('\u{bdf1}', '\u{be0b}', Lvt), ('\u{be0c}', '\u{be0c}', Lv),
+        ('\u{be0d}', '\u{be27}', Lvt), ('\u{be28}', '\u{be28}', Lv), ('\u{be29}', '\u{be43}', Lvt),
+        ('\u{be44}', '\u{be44}', Lv), ('\u{be45}', '\u{be5f}', Lvt), ('\u{be60}', '\u{be60}', Lv),
+        ('\u{be61}', '\u{be7b}', Lvt), ('\u{be7c}', '\u{be7c}', Lv), ('\u{be7d}', '\u{be97}', Lvt),
+        ('\u{be98}', '\u{be98}', Lv), ('\u{be99}', '\u{beb3}', Lvt), ('\u{beb4}', '\u{beb4}', Lv),
+        ('\u{beb5}', '\u{becf}', Lvt), ('\u{bed0}', '\u{bed0}', Lv), ('\u{bed1}', '\u{beeb}', Lvt),
+        ('\u{beec}', '\u{beec}', Lv), ('\u{beed}', '\u{bf07}', Lvt), ('\u{bf08}', '\u{bf08}', Lv),
+        ('\u{bf09}', '\u{bf23}', Lvt), ('\u{bf24}', '\u{bf24}', Lv), ('\u{bf25}', '\u{bf3f}', Lvt),
+        ('\u{bf40}', '\u{bf40}', Lv), ('\u{bf41}', '\u{bf5b}', Lvt), ('\u{bf5c}', '\u{bf5c}', Lv),
+        ('\u{bf5d}', '\u{bf77}', Lvt), ('\u{bf78}', '\u{bf78}', Lv), ('\u{bf79}', '\u{bf93}', Lvt),
+        ('\u{bf94}', '\u{bf94}', Lv), ('\u{bf95}', '\u{bfaf}', Lvt), ('\u{bfb0}', '\u{bfb0}', Lv),
+        ('\u{bfb1}', '\u{bfcb}', Lvt), ('\u{bfcc}', '\u{bfcc}', Lv), ('\u{bfcd}', '\u{bfe7}', Lvt),
+        ('\u{bfe8}', '\u{bfe8}', Lv), ('\u{bfe9}', '\u{c003}', Lvt), ('\u{c004}', '\u{c004}', Lv),
+        ('\u{c005}', '\u{c01f}', Lvt), ('\u{c020}', '\u{c020}', Lv), ('\u{c021}', '\u{c03b}', Lvt),
+        ('\u{c03c}', '\u{c03c}', Lv), ('\u{c03d}', '\u{c057}', Lvt), ('\u{c058}', '\u{c058}', Lv),
+        ('\u{c059}', '\u{c073}', Lvt), ('\u{c074}', '\u{c074}', Lv), ('\u{c075}', '\u{c08f}', Lvt),
+        ('\u{c090}', '\u{c090}', Lv), ('\u{c091}', '\u{c0ab}', Lvt), ('\u{c0ac}', '\u{c0ac}', Lv),
+        ('\u{c0ad}', '\u{c0c7}', Lvt), ('\u{c0c8}', '\u{c0c8}', Lv), ('\u{c0c9}', '\u{c0e3}', Lvt),
+        ('\u{c0e4}', '\u{c0e4}', Lv), ('\u{c0e5}', '\u{c0ff}', Lvt), ('\u{c100}', '\u{c100}', Lv),
+        ('\u{c101}', '\u{c11b}', Lvt), ('\u{c11c}', '\u{c11c}', Lv), ('\u{c11d}', '\u{c137}', Lvt),
+        ('\u{c138}', '\u{c138}', Lv), ('\u{c139}', '\u{c153}', Lvt), ('\u{c154}', '\u{c154}', Lv),
+        ('\u{c155}', '\u{c16f}', Lvt), ('\u{c170}', '\u{c170}', Lv), ('\u{c171}', '\u{c18b}', Lvt),
+        ('\u{c18c}', '\u{c18c}', Lv), ('\u{c18d}', '\u{c1a7}', Lvt), ('\u{c1a8}', '\u{c1a8}', Lv),
+        ('\u{c1a9}', '\u{c1c3}', Lvt), ('\u{c1c4}', '\u{c1c4}', Lv), ('\u{c1c5}', '\u{c1df}', Lvt),
+        ('\u{c1e0}', '\u{c1e0}', Lv), ('\u{c1e1}', '\u{c1fb}', Lvt), ('\u{c1fc}', '\u{c1fc}', Lv),
+        ('\u{c1fd}', '\u{c217}', Lvt), ('\u{c218}', '\u{c218}', Lv), ('\u{c219}', '\u{c233}', Lvt),
+        ('\u{c234}', '\u{c234}', Lv), ('\u{c235}', '\u{c24f}', Lvt), ('\u{c250}', '\u{c250}', Lv),
+        ('\u{c251}', '\u{c26b}', Lvt), ('\u{c26c}', '\u{c26c}', Lv), ('\u{c26d}', '\u{c287}', Lvt),
+        ('\u{c288}', '\u{c288}', Lv), ('\u{c289}', '\u{c2a3}', Lvt), ('\u{c2a4}', '\u{c2a4}', Lv),
+        ('\u{c2a5}', '\u{c2bf}', Lvt), ('\u{c2c0}', '\u{c2c0}', Lv), ('\u{c2c1}', '\u{c2db}', Lvt),
+        ('\u{c2dc}', '\u{c2dc}', Lv), ('\u{c2dd}', '\u{c2f7}', Lvt), ('\u{c2f8}', '\u{c2f8}', Lv),
+        ('\u{c2f9}', '\u{c313}', Lvt), ('\u{c314}', '\u{c314}', Lv), ('\u{c315}', '\u{c32f}', Lvt),
+        ('\u{c330}', '\u{c330}', Lv), ('\u{c331}', '\u{c34b}', Lvt), ('\u{c34c}', '\u{c34c}', Lv),
+        ('\u{c34d}', '\u{c367}', Lvt), ('\u{c368}', '\u{c368}', Lv), ('\u{c369}', '\u{c383}', Lvt),
+        ('\u{c384}', '\u{c384}', Lv), ('\u{c385}', '\u{c39f}', Lvt), ('\u{c3a0}', '\u{c3a0}', Lv),
+        ('\u{c3a1}', '\u{c3bb}', Lvt), ('\u{c3bc}', '\u{c3bc}', Lv), ('\u{c3bd}', '\u{c3d7}', Lvt),
+        ('\u{c3d8}', '\u{c3d8}', Lv), ('\u{c3d9}', '\u{c3f3}', Lvt), ('\u{c3f4}', '\u{c3f4}', Lv),
+        ('\u{c3f5}', '\u{c40f}', Lvt), ('\u{c410}', '\u{c410}', Lv), ('\u{c411}', '\u{c42b}', Lvt),
+        ('\u{c42c}', '\u{c42c}', Lv), ('\u{c42d}', '\u{c447}', Lvt), ('\u{c448}', '\u{c448}', Lv),
+        ('\u{c449}', '\u{c463}', Lvt), ('\u{c464}', '\u{c464}', Lv), ('\u{c465}', '\u{c47f}', Lvt),
+        ('\u{c480}', '\u{c480}', Lv), ('\u{c481}', '\u{c49b}', Lvt), ('\u{c49c}', '\u{c49c}', Lv),
+        ('\u{c49d}', '\u{c4b7}', Lvt), ('\u{c4b8}', '\u{c4b8}', Lv), ('\u{c4b9}', '\u{c4d3}', Lvt),
+        ('\u{c4d4}', '\u{c4d4}', Lv), ('\u{c4d5}', '\u{c4ef}', Lvt), ('\u{c4f0}', '\u{c4f0}', Lv),
+        ('\u{c4f1}', '\u{c50b}', Lvt), ('\u{c50c}', '\u{c50c}', Lv), ('\u{c50d}', '\u{c527}', Lvt),
+        ('\u{c528}', '\u{c528}', Lv), ('\u{c529}', '\u{c543}', Lvt), ('\u{c544}', '\u{c544}', Lv),
+        ('\u{c545}', '\u{c55f}', Lvt), ('\u{c560}', '\u{c560}', Lv), ('\u{c561}', '\u{c57b}', Lvt),
+        ('\u{c57c}', '\u{c57c}', Lv), ('\u{c57d}', '\u{c597}', Lvt), ('\u{c598}', '\u{c598}', Lv),
+        ('\u{c599}', '\u{c5b3}', Lvt), ('\u{c5b4}', '\u{c5b4}', Lv), ('\u{c5b5}', '\u{c5cf}', Lvt),
+        ('\u{c5d0}', '\u{c5d0}', Lv), ('\u{c5d1}', '\u{c5eb}', Lvt), ('\u{c5ec}', '\u{c5ec}', Lv),
+        ('\u{c5ed}', '\u{c607}', Lvt), ('\u{c608}', '\u{c608}', Lv), ('\u{c609}', '\u{c623}', Lvt),
+        ('\u{c624}', '\u{c624}', Lv), ('\u{c625}', '\u{c63f}', Lvt), ('\u{c640}', '\u{c640}', Lv),
+        ('\u{c641}', '\u{c65b}', Lvt), ('\u{c65c}', '\u{c65c}', Lv), ('\u{c65d}', '\u{c677}', Lvt),
+        ('\u{c678}', '\u{c678}', Lv), ('\u{c679}', '\u{c693}', Lvt), ('\u{c694}', '\u{c694}', Lv),
+        ('\u{c695}', '\u{c6af}', Lvt), ('\u{c6b0}', '\u{c6b0}', Lv), ('\u{c6b1}', '\u{c6cb}', Lvt),
+        ('\u{c6cc}', '\u{c6cc}', Lv), ('\u{c6cd}', '\u{c6e7}', Lvt), ('\u{c6e8}', '\u{c6e8}', Lv),
+        ('\u{c6e9}', '\u{c703}', Lvt), ('\u{c704}', '\u{c704}', Lv), ('\u{c705}', '\u{c71f}', Lvt),
+        ('\u{c720}', '\u{c720}', Lv), ('\u{c721}', '\u{c73b}', Lvt), ('\u{c73c}', '\u{c73c}', Lv),
+        ('\u{c73d}', '\u{c757}', Lvt), ('\u{c758}', '\u{c758}', Lv), ('\u{c759}', '\u{c773}', Lvt),
+        ('\u{c774}', '\u{c774}', Lv), ('\u{c775}', '\u{c78f}', Lvt), ('\u{c790}', '\u{c790}', Lv),
+        ('\u{c791}', '\u{c7ab}', Lvt), ('\u{c7ac}', '\u{c7ac}', Lv), ('\u{c7ad}', '\u{c7c7}', Lvt),
+        ('\u{c7c8}', '\u{c7c8}', Lv), ('\u{c7c9}', '\u{c7e3}', Lvt), ('\u{c7e4}', '\u{c7e4}', Lv),
+        ('\u{c7e5}', '\u{c7ff}', Lvt), ('\u{c800}', '\u{c800}', Lv), ('\u{c801}', '\u{c81b}', Lvt),
+        ('\u{c81c}', '\u{c81c}', Lv), ('\u{c81d}', '\u{c837}', Lvt), ('\u{c838}', '\u{c838}', Lv),
+        ('\u{c839}', '\u{c853}', Lvt), ('\u{c854}', '\u{c854}', Lv), ('\u{c855}', '\u{c86f}', Lvt),
+        ('\u{c870}', '\u{c870}', Lv), ('\u{c871}', '\u{c88b}', Lvt), ('\u{c88c}', '\u{c88c}', Lv),
+        ('\u{c88d}', '\u{c8a7}', Lvt), ('\u{c8a8}', '\u{c8a8}', Lv), ('\u{c8a9}', '\u{c8c3}', Lvt),
+        ('\u{c8c4}', '\u{c8c4}', Lv), ('\u{c8c5}', '\u{c8df}', Lvt), ('\u{c8e0}', '\u{c8e0}', Lv),
+        ('\u{c8e1}', '\u{c8fb}', Lvt), ('\u{c8fc}', '\u{c8fc}', Lv), ('\u{c8fd}', '\u{c917}', Lvt),
+        ('\u{c918}', '\u{c918}', Lv), ('\u{c919}', '\u{c933}', Lvt), ('\u{c934}', '\u{c934}', Lv),
+        ('\u{c935}', '\u{c94f}', Lvt), ('\u{c950}', '\u{c950}', Lv), ('\u{c951}', '\u{c96b}', Lvt),
+        ('\u{c96c}', '\u{c96c}', Lv), ('\u{c96d}', '\u{c987}', Lvt), ('\u{c988}', '\u{c988}', Lv),
+        ('\u{c989}', '\u{c9a3}', Lvt), ('\u{c9a4}', '\u{c9a4}', Lv), ('\u{c9a5}', '\u{c9bf}', Lvt),
+        ('\u{c9c0}', '\u{c9c0}', Lv), ('\u{c9c1}', '\u{c9db}', Lvt), ('\u{c9dc}', '\u{c9dc}', Lv),
+        ('\u{c9dd}', '\u{c9f7}', Lvt), ('\u{c9f8}', '\u{c9f8}', Lv), ('\u{c9f9}', '\u{ca13}', Lvt),
+        ('\u{ca14}', '\u{ca14}', Lv), ('\u{ca15}', '\u{ca2f}', Lvt), ('\u{ca30}', '\u{ca30}', Lv),
+        ('\u{ca31}', '\u{ca4b}', Lvt), ('\u{ca4c}', '\u{ca4c}', Lv), ('\u{ca4d}', '\u{ca67}', Lvt),
+        ('\u{ca68}', '\u{ca68}', Lv), ('\u{ca69}', '\u{ca83}', Lvt), ('\u{ca84}', '\u{ca84}', Lv),
+        ('\u{ca85}', '\u{ca9f}', Lvt), ('\u{caa0}', '\u{caa0}', Lv), ('\u{caa1}', '\u{cabb}', Lvt),
+        ('\u{cabc}', '\u{cabc}', Lv), ('\u{cabd}', '\u{cad7}', Lvt), ('\u{cad8}', '\u{cad8}', Lv),
+        ('\u{cad9}', '\u{caf3}', Lvt), ('\u{caf4}', '\u{caf4}', Lv), ('\u{caf5}', '\u{cb0f}', Lvt),
+        ('\u{cb10}', '\u{cb10}', Lv), ('\u{cb11}', '\u{cb2b}', Lvt), ('\u{cb2c}', '\u{cb2c}', Lv),
+        ('\u{cb2d}', '\u{cb47}', Lvt), ('\u{cb48}', '\u{cb48}', Lv), ('\u{cb49}', '\u{cb63}', Lvt),
+        ('\u{cb64}', '\u{cb64}', Lv), ('\u{cb65}', '\u{cb7f}', Lvt), ('\u{cb80}', '\u{cb80}', Lv),
+        ('\u{cb81}', '\u{cb9b}', Lvt), ('\u{cb9c}', '\u{cb9c}', Lv), ('\u{cb9d}', '\u{cbb7}', Lvt),
+        ('\u{cbb8}', '\u{cbb8}', Lv), ('\u{cbb9}', '\u{cbd3}', Lvt), ('\u{cbd4}', '\u{cbd4}', Lv),
+        ('\u{cbd5}', '\u{cbef}', Lvt), ('\u{cbf0}', '\u{cbf0}', Lv), ('\u{cbf1}', '\u{cc0b}', Lvt),
+        ('\u{cc0c}', '\u{cc0c}', Lv), ('\u{cc0d}', '\u{cc27}', Lvt), ('\u{cc28}', '\u{cc28}', Lv),
+        ('\u{cc29}', '\u{cc43}', Lvt), ('\u{cc44}', '\u{cc44}', Lv), ('\u{cc45}', '\u{cc5f}', Lvt),
+        ('\u{cc60}', '\u{cc60}', Lv), ('\u{cc61}', '\u{cc7b}', Lvt), ('\u{cc7c}', '\u{cc7c}', Lv),
+        ('\u{cc7d}', '\u{cc97}', Lvt), ('\u{cc98}', '\u{cc98}', Lv), ('\u{cc99}', '\u{ccb3}', Lvt),
+        ('\u{ccb4}', '\u{ccb4}', Lv), ('\u{ccb5}', '\u{cccf}', Lvt), ('\u{ccd0}', '\u{ccd0}', Lv),
+        ('\u{ccd1}', '\u{cceb}', Lvt), ('\u{ccec}', '\u{ccec}', Lv), ('\u{cced}', '\u{cd07}', Lvt),
+        ('\u{cd08}', '\u{cd08}', Lv), ('\u{cd09}', '\u{cd23}', Lvt), ('\u{cd24}', '\u{cd24}', Lv),
+        ('\u{cd25}', '\u{cd3f}', Lvt), ('\u{cd40}', '\u{cd40}', Lv), ('\u{cd41}', '\u{cd5b}', Lvt),
+        ('\u{cd5c}', '\u{cd5c}', Lv), ('\u{cd5d}', '\u{cd77}', Lvt), ('\u{cd78}', '\u{cd78}', Lv),
+        ('\u{cd79}', '\u{cd93}', Lvt), ('\u{cd94}', '\u{cd94}', Lv), ('\u{cd95}', '\u{cdaf}', Lvt),
+        ('\u{cdb0}', '\u{cdb0}', Lv), ('\u{cdb1}', '\u{cdcb}', Lvt), ('\u{cdcc}', '\u{cdcc}', Lv),
+        ('\u{cdcd}', '\u{cde7}', Lvt), ('\u{cde8}', '\u{cde8}', Lv), ('\u{cde9}', '\u{ce03}', Lvt),
+        ('\u{ce04}', '\u{ce04}', Lv), ('\u{ce05}', '\u{ce1f}', Lvt), ('\u{ce20}', '\u{ce20}', Lv),
+        ('\u{ce21}', '\u{ce3b}', Lvt), ('\u{ce3c}', '\u{ce3c}', Lv), ('\u{ce3d}', '\u{ce57}', Lvt),
+        ('\u{ce58}', '\u{ce58}', Lv), ('\u{ce59}', '\u{ce73}', Lvt), ('\u{ce74}', '\u{ce74}', Lv),
+        ('\u{ce75}', '\u{ce8f}', Lvt), ('\u{ce90}', '\u{ce90}', Lv), ('\u{ce91}', '\u{ceab}', Lvt),
+        ('\u{ceac}', '\u{ceac}', Lv), ('\u{cead}', '\u{cec7}', Lvt), ('\u{cec8}', '\u{cec8}', Lv),
+        ('\u{cec9}', '\u{cee3}', Lvt), ('\u{cee4}', '\u{cee4}', Lv), ('\u{cee5}', '\u{ceff}', Lvt),
+        ('\u{cf00}', '\u{cf00}', Lv), ('\u{cf01}', '\u{cf1b}', Lvt), ('\u{cf1c}', '\u{cf1c}', Lv),
+        ('\u{cf1d}', '\u{cf37}', Lvt), ('\u{cf38}', '\u{cf38}', Lv), ('\u{cf39}', '\u{cf53}', Lvt),
+        ('\u{cf54}', '\u{cf54}', Lv), ('\u{cf55}', '\u{cf6f}', Lvt), ('\u{cf70}', '\u{cf70}', Lv),
+        ('\u{cf71}', '\u{cf8b}', Lvt), ('\u{cf8c}', '\u{cf8c}', Lv), ('\u{cf8d}', '\u{cfa7}', Lvt),
+        ('\u{cfa8}', '\u{cfa8}', Lv), ('\u{cfa9}', '\u{cfc3}', Lvt), ('\u{cfc4}', '\u{cfc4}', Lv),
+        ('\u{cfc5}', '\u{cfdf}', Lvt), ('\u{cfe0}', '\u{cfe0}', Lv), ('\u{cfe1}', '\u{cffb}', Lvt),
+        ('\u{cffc}', '\u{cffc}', Lv), ('\u{cffd}', '\u{d017}', Lvt), ('\u{d018}', '\u{d018}', Lv),
+        ('\u{d019}', '\u{d033}', Lvt), ('\u{d034}', '\u{d034}', Lv), ('\u{d035}', '\u{d04f}', Lvt),
+        ('\u{d050}', '\u{d050}', Lv), ('\u{d051}', '\u{d06b}', Lvt), ('\u{d06c}', '\u{d06c}', Lv),
+        ('\u{d06d}', '\u{d087}', Lvt), ('\u{d088}', '\u{d088}', Lv), ('\u{d089}', '\u{d0a3}', Lvt),
+        ('\u{d0a4}', '\u{d0a4}', Lv), ('\u{d0a5}', '\u{d0bf}', Lvt), ('\u{d0c0}', '\u{d0c0}', Lv),
+        ('\u{d0c1}', '\u{d0db}', Lvt), ('\u{d0dc}', '\u{d0dc}', Lv), ('\u{d0dd}', '\u{d0f7}', Lvt),
+        ('\u{d0f8}', '\u{d0f8}', Lv), ('\u{d0f9}', '\u{d113}', Lvt), ('\u{d114}', '\u{d114}', Lv),
+        ('\u{d115}', '\u{d12f}', Lvt), ('\u{d130}', '\u{d130}', Lv), ('\u{d131}', '\u{d14b}', Lvt),
+        ('\u{d14c}', '\u{d14c}', Lv), ('\u{d14d}', '\u{d167}', Lvt), ('\u{d168}', '\u{d168}', Lv),
+        ('\u{d169}', '\u{d183}', Lvt), ('\u{d184}', '\u{d184}', Lv), ('\u{d185}', '\u{d19f}', Lvt),
+        ('\u{d1a0}', '\u{d1a0}', Lv), ('\u{d1a1}', '\u{d1bb}', Lvt), ('\u{d1bc}', '\u{d1bc}', Lv),
+        ('\u{d1bd}', '\u{d1d7}', Lvt), ('\u{d1d8}', '\u{d1d8}', Lv), ('\u{d1d9}', '\u{d1f3}', Lvt),
+        ('\u{d1f4}', '\u{d1f4}', Lv), ('\u{d1f5}', '\u{d20f}', Lvt), ('\u{d210}', '\u{d210}', Lv),
+        ('\u{d211}', '\u{d22b}', Lvt), ('\u{d22c}', '\u{d22c}', Lv), ('\u{d22d}', '\u{d247}', Lvt),
+        ('\u{d248}', '\u{d248}', Lv), ('\u{d249}', '\u{d263}', Lvt), ('\u{d264}', '\u{d264}', Lv),
+        ('\u{d265}', '\u{d27f}', Lvt), ('\u{d280}', '\u{d280}', Lv), ('\u{d281}', '\u{d29b}', Lvt),
+        ('\u{d29c}', '\u{d29c}', Lv), ('\u{d29d}', '\u{d2b7}', Lvt), ('\u{d2b8}', '\u{d2b8}', Lv),
+        ('\u{d2b9}', '\u{d2d3}', Lvt), ('\u{d2d4}', '\u{d2d4}', Lv), ('\u{d2d5}', '\u{d2ef}', Lvt),
+        ('\u{d2f0}', '\u{d2f0}', Lv), ('\u{d2f1}', '\u{d30b}', Lvt), ('\u{d30c}', '\u{d30c}', Lv),
+        ('\u{d30d}', '\u{d327}', Lvt), ('\u{d328}', '\u{d328}', Lv), ('\u{d329}', '\u{d343}', Lvt),
+        ('\u{d344}', '\u{d344}', Lv), ('\u{d345}', '\u{d35f}', Lvt), ('\u{d360}', '\u{d360}', Lv),
+        ('\u{d361}', '\u{d37b}', Lvt), ('\u{d37c}', '\u{d37c}', Lv), ('\u{d37d}', '\u{d397}', Lvt),
+        ('\u{d398}', '\u{d398}', Lv), ('\u{d399}', '\u{d3b3}', Lvt), ('\u{d3b4}', '\u{d3b4}', Lv),
+        ('\u{d3b5}', '\u{d3cf}', Lvt), ('\u{d3d0}', '\u{d3d0}', Lv), ('\u{d3d1}', '\u{d3eb}', Lvt),
+        ('\u{d3ec}', '\u{d3ec}', Lv), ('\u{d3ed}', '\u{d407}', Lvt), ('\u{d408}', '\u{d408}', Lv),
+        ('\u{d409}', '\u{d423}', Lvt), ('\u{d424}', '\u{d424}', Lv), ('\u{d425}', '\u{d43f}', Lvt),
+        ('\u{d440}', '\u{d440}', Lv), ('\u{d441}', '\u{d45b}', Lvt), ('\u{d45c}', '\u{d45c}', Lv),
+        ('\u{d45d}', '\u{d477}', Lvt), ('\u{d478}', '\u{d478}', Lv), ('\u{d479}', '\u{d493}', Lvt),
+        ('\u{d494}', '\u{d494}', Lv), ('\u{d495}', '\u{d4af}', Lvt), ('\u{d4b0}', '\u{d4b0}', Lv),
+        ('\u{d4b1}', '\u{d4cb}', Lvt), ('\u{d4cc}', '\u{d4cc}', Lv), ('\u{d4cd}', '\u{d4e7}', Lvt),
+        ('\u{d4e8}', '\u{d4e8}', Lv), ('\u{d4e9}', '\u{d503}', Lvt), ('\u{d504}', '\u{d504}', Lv),
+        ('\u{d505}', '\u{d51f}', Lvt), ('\u{d520}', '\u{d520}', Lv), ('\u{d521}', '\u{d53b}', Lvt),
+        ('\u{d53c}', '\u{d53c}', Lv), ('\u{d53d}', '\u{d557}', Lvt), ('\u{d558}', '\u{d558}', Lv),
+        ('\u{d559}', '\u{d573}', Lvt), ('\u{d574}', '\u{d574}', Lv), ('\u{d575}', '\u{d58f}', Lvt),
+        ('\u{d590}', '\u{d590}', Lv), ('\u{d591}', '\u{d5ab}', Lvt), ('\u{d5ac}', '\u{d5ac}', Lv),
+        ('\u{d5ad}', '\u{d5c7}', Lvt), ('\u{d5c8}', '\u{d5c8}', Lv), ('\u{d5c9}', '\u{d5e3}', Lvt),
+        ('\u{d5e4}', '\u{d5e4}', Lv), ('\u{d5e5}', '\u{d5ff}', Lvt), ('\u{d600}', '\u{d600}', Lv),
+        ('\u{d601}', '\u{d61b}', Lvt), ('\u{d61c}', '\u{d61c}', Lv), ('\u{d61d}', '\u{d637}', Lvt),
+        ('\u{d638}', '\u{d638}', Lv), ('\u{d639}', '\u{d653}', Lvt), ('\u{d654}', '\u{d654}', Lv),
+        ('\u{d655}', '\u{d66f}', Lvt), ('\u{d670}', '\u{d670}', Lv), ('\u{d671}', '\u{d68b}', Lvt),
+        ('\u{d68c}', '\u{d68c}', Lv), ('\u{d68d}', '\u{d6a7}', Lvt), ('\u{d6a8}', '\u{d6a8}', Lv),
+        ('\u{d6a9}', '\u{d6c3}', Lvt), ('\u{d6c4}', '\u{d6c4}', Lv), ('\u{d6c5}', '\u{d6df}', Lvt),
+        ('\u{d6e0}', '\u{d6e0}', Lv), ('\u{d6e1}', '\u{d6fb}', Lvt), ('\u{d6fc}', '\u{d6fc}', Lv),
+        ('\u{d6fd}', '\u{d717}', Lvt), ('\u{d718}', '\u{d718}', Lv), ('\u{d719}', '\u{d733}', Lvt),
+        ('\u{d734}', '\u{d734}', Lv), ('\u{d735}', '\u{d74f}', Lvt), ('\u{d750}', '\u{d750}', Lv),
+        ('\u{d751}', '\u{d76b}', Lvt), ('\u{d76c}', '\u{d76c}', Lv), ('\u{d76d}', '\u{d787}', Lvt),
+        ('\u{d788}', '\u{d788}', Lv), ('\u{d789}', '\u{d7a3}', Lvt), ('\u{d7b0}', '\u{d7c6}', V),
+        ('\u{d7cb}', '\u{d7fb}', T), ('\u{fb1e}', '\u{fb1e}', Extend),
+        ('\u{fe00}', '\u{fe0f}', Extend), ('\u{fe20}', '\u{fe2f}', Extend),
+        ('\u{feff}', '\u{feff}', Control), ('\u{ff9e}', '\u{ff9f}', Extend),
+        ('\u{fff0}', '\u{fffb}', Control), ('\u{101fd}', '\u{101fd}', Extend),
+        ('\u{102e0}', '\u{102e0}', Extend), ('\u{10376}', '\u{1037a}', Extend),
+        ('\u{10a01}', '\u{10a03}', Extend), ('\u{10a05}', '\u{10a06}', Extend),
+        ('\u{10a0c}', '\u{10a0f}', Extend), ('\u{10a38}', '\u{10a3a}', Extend),
+        ('\u{10a3f}', '\u{10a3f}', Extend), ('\u{10ae5}', '\u{10ae6}', Extend),
+        ('\u{10d24}', '\u{10d27}', Extend), ('\u{10d69}', '\u{10d6d}', Extend),
+        ('\u{10eab}', '\u{10eac}', Extend), ('\u{10efc}', '\u{10eff}', Extend),
+        ('\u{10f46}', '\u{10f50}', Extend), ('\u{10f82}', '\u{10f85}', Extend),
+        ('\u{11000}', '\u{11000}', SpacingMark), ('\u{11001}', '\u{11001}', Extend),
+        ('\u{11002}', '\u{11002}', SpacingMark), ('\u{11038}', '\u{11046}', Extend),
+        ('\u{11070}', '\u{11070}', Extend), ('\u{11073}', '\u{11074}', Extend),
+        ('\u{1107f}', '\u{11081}', Extend), ('\u{11082}', '\u{11082}', SpacingMark),
+        ('\u{110b0}', '\u{110b2}', SpacingMark), ('\u{110b3}', '\u{110b6}', Extend),
+        ('\u{110b7}', '\u{110b8}', SpacingMark), ('\u{110b9}', '\u{110ba}', Extend),
+        ('\u{110bd}', '\u{110bd}', Prepend), ('\u{110c2}', '\u{110c2}', Extend),
+        ('\u{110cd}', '\u{110cd}', Prepend), ('\u{11100}', '\u{11102}', Extend),
+        ('\u{11127}', '\u{1112b}', Extend), ('\u{1112c}', '\u{1112c}', SpacingMark),
+        ('\u{1112d}', '\u{11134}', Extend), ('\u{11145}', '\u{11146}', SpacingMark),
+        ('\u{11173}', '\u{11173}', Extend), ('\u{11180}', '\u{11181}', Extend),
+        ('\u{11182}', '\u{11182}', SpacingMark), ('\u{111b3}', '\u{111b5}', SpacingMark),
+        ('\u{111b6}', '\u{111be}', Extend), ('\u{111bf}', '\u{111bf}', SpacingMark),
+        ('\u{111c0}', '\u{111c0}', Extend), ('\u{111c2}', '\u{111c3}', Prepend),
+        ('\u{111c9}', '\u{111cc}', Extend), ('\u{111ce}', '\u{111ce}', SpacingMark),
+        ('\u{111cf}', '\u{111cf}', Extend), ('\u{1122c}', '\u{1122e}', SpacingMark),
+        ('\u{1122f}', '\u{11231}', Extend), ('\u{11232}', '\u{11233}', SpacingMark),
+        ('\u{11234}', '\u{11237}', Extend), ('\u{1123e}', '\u{1123e}', Extend),
+        ('\u{11241}', '\u{11241}', Extend), ('\u{112df}', '\u{112df}', Extend),
+        ('\u{112e0}', '\u{112e2}', SpacingMark), ('\u{112e3}', '\u{112ea}', Extend),
+        ('\u{11300}', '\u{11301}', Extend), ('\u{11302}', '\u{11303}', SpacingMark),
+        ('\u{1133b}', '\u{1133c}', Extend), ('\u{1133e}', '\u{1133e}', Extend),
+        ('\u{1133f}', '\u{1133f}', SpacingMark), ('\u{11340}', '\u{11340}', Extend),
+        ('\u{11341}', '\u{11344}', SpacingMark), ('\u{11347}', '\u{11348}', SpacingMark),
+        ('\u{1134b}', '\u{1134c}', SpacingMark), ('\u{1134d}', '\u{1134d}', Extend),
+        ('\u{11357}', '\u{11357}', Extend), ('\u{11362}', '\u{11363}', SpacingMark),
+        ('\u{11366}', '\u{1136c}', Extend), ('\u{11370}', '\u{11374}', Extend),
+        ('\u{113b8}', '\u{113b8}', Extend), ('\u{113b9}', '\u{113ba}', SpacingMark),
+        ('\u{113bb}', '\u{113c0}', Extend), ('\u{113c2}', '\u{113c2}', Extend),
+        ('\u{113c5}', '\u{113c5}', Extend), ('\u{113c7}', '\u{113c9}', Extend),
+        ('\u{113ca}', '\u{113ca}', SpacingMark), ('\u{113cc}', '\u{113cd}', SpacingMark),
+        ('\u{113ce}', '\u{113d0}', Extend), ('\u{113d1}', '\u{113d1}', Prepend),
+        ('\u{113d2}', '\u{113d2}', Extend), ('\u{113e1}', '\u{113e2}', Extend),
+        ('\u{11435}', '\u{11437}', SpacingMark), ('\u{11438}', '\u{1143f}', Extend),
+        ('\u{11440}', '\u{11441}', SpacingMark), ('\u{11442}', '\u{11444}', Extend),
+        ('\u{11445}', '\u{11445}', SpacingMark), ('\u{11446}', '\u{11446}', Extend),
+        ('\u{1145e}', '\u{1145e}', Extend), ('\u{114b0}', '\u{114b0}', Extend),
+        ('\u{114b1}', '\u{114b2}', SpacingMark), ('\u{114b3}', '\u{114b8}', Extend),
+        ('\u{114b9}', '\u{114b9}', SpacingMark), ('\u{114ba}', '\u{114ba}', Extend),
+        ('\u{114bb}', '\u{114bc}', SpacingMark), ('\u{114bd}', '\u{114bd}', Extend),
+        ('\u{114be}', '\u{114be}', SpacingMark), ('\u{114bf}', '\u{114c0}', Extend),
+        ('\u{114c1}', '\u{114c1}', SpacingMark), ('\u{114c2}', '\u{114c3}', Extend),
+        ('\u{115af}', '\u{115af}', Extend), ('\u{115b0}', '\u{115b1}', SpacingMark),
+        ('\u{115b2}', '\u{115b5}', Extend), ('\u{115b8}', '\u{115bb}', SpacingMark),
+        ('\u{115bc}', '\u{115bd}', Extend), ('\u{115be}', '\u{115be}', SpacingMark),
+        ('\u{115bf}', '\u{115c0}', Extend), ('\u{115dc}', '\u{115dd}', Extend),
+        ('\u{11630}', '\u{11632}', SpacingMark), ('\u{11633}', '\u{1163a}', Extend),
+        ('\u{1163b}', '\u{1163c}', SpacingMark), ('\u{1163d}', '\u{1163d}', Extend),
+        ('\u{1163e}', '\u{1163e}', SpacingMark), ('\u{1163f}', '\u{11640}', Extend),
+        ('\u{116ab}', '\u{116ab}', Extend), ('\u{116ac}', '\u{116ac}', SpacingMark),
+        ('\u{116ad}', '\u{116ad}', Extend), ('\u{116ae}', '\u{116af}', SpacingMark),
+        ('\u{116b0}', '\u{116b7}', Extend), ('\u{1171d}', '\u{1171d}', Extend),
+        ('\u{1171e}', '\u{1171e}', SpacingMark), ('\u{1171f}', '\u{1171f}', Extend),
+        ('\u{11722}', '\u{11725}', Extend), ('\u{11726}', '\u{11726}', SpacingMark),
+        ('\u{11727}', '\u{1172b}', Extend), ('\u{1182c}', '\u{1182e}', SpacingMark),
+        ('\u{1182f}', '\u{11837}', Extend), ('\u{11838}', '\u{11838}', SpacingMark),
+        ('\u{11839}', '\u{1183a}', Extend), ('\u{11930}', '\u{11930}', Extend),
+        ('\u{11931}', '\u{11935}', SpacingMark), ('\u{11937}', '\u{11938}', SpacingMark),
+        ('\u{1193b}', '\u{1193e}', Extend), ('\u{1193f}', '\u{1193f}', Prepend),
+        ('\u{11940}', '\u{11940}', SpacingMark), ('\u{11941}', '\u{11941}', Prepend),
+        ('\u{11942}', '\u{11942}', SpacingMark), ('\u{11943}', '\u{11943}', Extend),
+        ('\u{119d1}', '\u{119d3}', SpacingMark), ('\u{119d4}', '\u{119d7}', Extend),
+        ('\u{119da}', '\u{119db}', Extend), ('\u{119dc}', '\u{119df}', SpacingMark),
+        ('\u{119e0}', '\u{119e0}', Extend), ('\u{119e4}', '\u{119e4}', SpacingMark),
+        ('\u{11a01}', '\u{11a0a}', Extend), ('\u{11a33}', '\u{11a38}', Extend),
+        ('\u{11a39}', '\u{11a39}', SpacingMark), ('\u{11a3a}', '\u{11a3a}', Prepend),
+        ('\u{11a3b}', '\u{11a3e}', Extend), ('\u{11a47}', '\u{11a47}', Extend),
+        ('\u{11a51}', '\u{11a56}', Extend), ('\u{11a57}', '\u{11a58}', SpacingMark),
+        ('\u{11a59}', '\u{11a5b}', Extend), ('\u{11a84}', '\u{11a89}', Prepend),
+        ('\u{11a8a}', '\u{11a96}', Extend), ('\u{11a97}', '\u{11a97}', SpacingMark),
+        ('\u{11a98}', '\u{11a99}', Extend), ('\u{11c2f}', '\u{11c2f}', SpacingMark),
+        ('\u{11c30}', '\u{11c36}', Extend), ('\u{11c38}', '\u{11c3d}', Extend),
+        ('\u{11c3e}', '\u{11c3e}', SpacingMark), ('\u{11c3f}', '\u{11c3f}', Extend),
+        ('\u{11c92}', '\u{11ca7}', Extend), ('\u{11ca9}', '\u{11ca9}', SpacingMark),
+        ('\u{11caa}', '\u{11cb0}', Extend), ('\u{11cb1}', '\u{11cb1}', SpacingMark),
+        ('\u{11cb2}', '\u{11cb3}', Extend), ('\u{11cb4}', '\u{11cb4}', SpacingMark),
+        ('\u{11cb5}', '\u{11cb6}', Extend), ('\u{11d31}', '\u{11d36}', Extend),
+        ('\u{11d3a}', '\u{11d3a}', Extend), ('\u{11d3c}', '\u{11d3d}', Extend),
+        ('\u{11d3f}', '\u{11d45}', Extend), ('\u{11d46}', '\u{11d46}', Prepend),
+        ('\u{11d47}', '\u{11d47}', Extend), ('\u{11d8a}', '\u{11d8e}', SpacingMark),
+        ('\u{11d90}', '\u{11d91}', Extend), ('\u{11d93}', '\u{11d94}', SpacingMark),
+        ('\u{11d95}', '\u{11d95}', Extend), ('\u{11d96}', '\u{11d96}', SpacingMark),
+        ('\u{11d97}', '\u{11d97}', Extend), ('\u{11ef3}', '\u{11ef4}', Extend),
+        ('\u{11ef5}', '\u{11ef6}', SpacingMark), ('\u{11f00}', '\u{11f01}', Extend),
+        ('\u{11f02}', '\u{11f02}', Prepend), ('\u{11f03}', '\u{11f03}', SpacingMark),
+        ('\u{11f34}', '\u{11f35}', SpacingMark), ('\u{11f36}', '\u{11f3a}', Extend),
+        ('\u{11f3e}', '\u{11f3f}', SpacingMark), ('\u{11f40}', '\u{11f42}', Extend),
+        ('\u{11f5a}', '\u{11f5a}', Extend), ('\u{13430}', '\u{1343f}', Control),
+        ('\u{13440}', '\u{13440}', Extend), ('\u{13447}', '\u{13455}', Extend),
+        ('\u{1611e}', '\u{16129}', Extend), ('\u{1612a}', '\u{1612c}', SpacingMark),
+        ('\u{1612d}', '\u{1612f}', Extend), ('\u{16af0}', '\u{16af4}', Extend),
+        ('\u{16b30}', '\u{16b36}', Extend), ('\u{16d63}', '\u{16d63}', V),
+        ('\u{16d67}', '\u{16d6a}', V), ('\u{16f4f}', '\u{16f4f}', Extend),
+        ('\u{16f51}', '\u{16f87}', SpacingMark), ('\u{16f8f}', '\u{16f92}', Extend),
+        ('\u{16fe4}', '\u{16fe4}', Extend), ('\u{16ff0}', '\u{16ff1}', Extend),
+        ('\u{1bc9d}', '\u{1bc9e}', Extend), ('\u{1bca0}', '\u{1bca3}', Control),
+        ('\u{1cf00}', '\u{1cf2d}', Extend), ('\u{1cf30}', '\u{1cf46}', Extend),
+        ('\u{1d165}', '\u{1d169}', Extend), ('\u{1d16d}', '\u{1d172}', Extend),
+        ('\u{1d173}', '\u{1d17a}', Control), ('\u{1d17b}', '\u{1d182}', Extend),
+        ('\u{1d185}', '\u{1d18b}', Extend), ('\u{1d1aa}', '\u{1d1ad}', Extend),
+        ('\u{1d242}', '\u{1d244}', Extend), ('\u{1da00}', '\u{1da36}', Extend),
+        ('\u{1da3b}', '\u{1da6c}', Extend), ('\u{1da75}', '\u{1da75}', Extend),
+        ('\u{1da84}', '\u{1da84}', Extend), ('\u{1da9b}', '\u{1da9f}', Extend),
+        ('\u{1daa1}', '\u{1daaf}', Extend), ('\u{1e000}', '\u{1e006}', Extend),
+        ('\u{1e008}', '\u{1e018}', Extend), ('\u{1e01b}', '\u{1e021}', Extend),
+        ('\u{1e023}', '\u{1e024}', Extend), ('\u{1e026}', '\u{1e02a}', Extend),
+        ('\u{1e08f}', '\u{1e08f}', Extend), ('\u{1e130}', '\u{1e136}', Extend),
+        ('\u{1e2ae}', '\u{1e2ae}', Extend), ('\u{1e2ec}', '\u{1e2ef}', Extend),
+        ('\u{1e4ec}', '\u{1e4ef}', Extend), ('\u{1e5ee}', '\u{1e5ef}', Extend),
+        ('\u{1e8d0}', '\u{1e8d6}', Extend), ('\u{1e944}', '\u{1e94a}', Extend),
+        ('\u{1f1e6}', '\u{1f1ff}', RegionalIndicator), ('\u{1f3fb}', '\u{1f3ff}', Extend),
+        ('\u{e0000}', '\u{e001f}', Control), ('\u{e0020}', '\u{e007f}', Extend),
+        ('\u{e0080}', '\u{e00ff}', Control), ('\u{e0100}', '\u{e01ef}', Extend),
+        ('\u{e01f0}', '\u{e0fff}', Control),
+    ];
+}
+
+mod incb {
+    use super::IndicConjunctBreak::{self, *};
+
+    #[rustfmt::skip]
+    pub(super) const INDIC_CONJUNCT_BREAK: &[(char, char, IndicConjunctBreak)] = &[
+        ('\u{300}', '\u{36f}', Extend), ('\u{483}', '\u{489}', Extend),
+        ('\u{591}', '\u{5bd}', Extend), ('\u{5bf}', '\u{5bf}', Extend),
+        ('\u{5c1}', '\u{5c2}', Extend), ('\u{5c4}', '\u{5c5}', Extend),
+        ('\u{5c7}', '\u{5c7}', Extend), ('\u{610}', '\u{61a}', Extend),
+        ('\u{64b}', '\u{65f}', Extend), ('\u{670}', '\u{670}', Extend),
+        ('\u{6d6}', '\u{6dc}', Extend), ('\u{6df}', '\u{6e4}', Extend),
+        ('\u{6e7}', '\u{6e8}', Extend), ('\u{6ea}', '\u{6ed}', Extend),
+        ('\u{711}', '\u{711}', Extend), ('\u{730}', '\u{74a}', Extend),
+        ('\u{7a6}', '\u{7b0}', Extend), ('\u{7eb}', '\u{7f3}', Extend),
+        ('\u{7fd}', '\u{7fd}', Extend), ('\u{816}', '\u{819}', Extend),
+        ('\u{81b}', '\u{823}', Extend), ('\u{825}', '\u{827}', Extend),
+        ('\u{829}', '\u{82d}', Extend), ('\u{859}', '\u{85b}', Extend),
+        ('\u{897}', '\u{89f}', Extend), ('\u{8ca}', '\u{8e1}', Extend),
+        ('\u{8e3}', '\u{902}', Extend), ('\u{915}', '\u{939}', Consonant),
+        ('\u{93a}', '\u{93a}', Extend), ('\u{93c}', '\u{93c}', Extend),
+        ('\u{941}', '\u{948}', Extend), ('\u{94d}', '\u{94d}', Linker),
+        ('\u{951}', '\u{957}', Extend), ('\u{958}', '\u{95f}', Consonant),
+        ('\u{962}', '\u{963}', Extend), ('\u{978}', '\u{97f}', Consonant),
+        ('\u{981}', '\u{981}', Extend), ('\u{995}', '\u{9a8}', Consonant),
+        ('\u{9aa}', '\u{9b0}', Consonant), ('\u{9b2}', '\u{9b2}', Consonant),
+        ('\u{9b6}', '\u{9b9}', Consonant), ('\u{9bc}', '\u{9bc}', Extend),
+        ('\u{9be}', '\u{9be}', Extend), ('\u{9c1}', '\u{9c4}', Extend),
+        ('\u{9cd}', '\u{9cd}', Linker), ('\u{9d7}', '\u{9d7}', Extend),
+        ('\u{9dc}', '\u{9dd}', Consonant), ('\u{9df}', '\u{9df}', Consonant),
+        ('\u{9e2}', '\u{9e3}', Extend), ('\u{9f0}', '\u{9f1}', Consonant),
+        ('\u{9fe}', '\u{9fe}', Extend), ('\u{a01}', '\u{a02}', Extend),
+        ('\u{a3c}', '\u{a3c}', Extend), ('\u{a41}', '\u{a42}', Extend),
+        ('\u{a47}', '\u{a48}', Extend), ('\u{a4b}', '\u{a4d}', Extend),
+        ('\u{a51}', '\u{a51}', Extend), ('\u{a70}', '\u{a71}', Extend),
+        ('\u{a75}', '\u{a75}', Extend), ('\u{a81}', '\u{a82}', Extend),
+        ('\u{a95}', '\u{aa8}', Consonant), ('\u{aaa}', '\u{ab0}', Consonant),
+        ('\u{ab2}', '\u{ab3}', Consonant), ('\u{ab5}', '\u{ab9}', Consonant),
+        ('\u{abc}', '\u{abc}', Extend), ('\u{ac1}', '\u{ac5}', Extend),
+        ('\u{ac7}', '\u{ac8}', Extend), ('\u{acd}', '\u{acd}', Linker),
+        ('\u{ae2}', '\u{ae3}', Extend), ('\u{af9}', '\u{af9}', Consonant),
+        ('\u{afa}', '\u{aff}', Extend), ('\u{b01}', '\u{b01}', Extend),
+        ('\u{b15}', '\u{b28}', Consonant), ('\u{b2a}', '\u{b30}', Consonant),
+        ('\u{b32}', '\u{b33}', Consonant), ('\u{b35}', '\u{b39}', Consonant),
+        ('\u{b3c}', '\u{b3c}', Extend), ('\u{b3e}', '\u{b3f}', Extend),
+        ('\u{b41}', '\u{b44}', Extend), ('\u{b4d}', '\u{b4d}', Linker),
+        ('\u{b55}', '\u{b57}', Extend), ('\u{b5c}', '\u{b5d}', Consonant),
+        ('\u{b5f}', '\u{b5f}', Consonant), ('\u{b62}', '\u{b63}', Extend),
+        ('\u{b71}', '\u{b71}', Consonant), ('\u{b82}', '\u{b82}', Extend),
+        ('\u{bbe}', '\u{bbe}', Extend), ('\u{bc0}', '\u{bc0}', Extend),
+        ('\u{bcd}', '\u{bcd}', Extend), ('\u{bd7}', '\u{bd7}', Extend),
+        ('\u{c00}', '\u{c00}', Extend), ('\u{c04}', '\u{c04}', Extend),
+        ('\u{c15}', '\u{c28}', Consonant), ('\u{c2a}', '\u{c39}', Consonant),
+        ('\u{c3c}', '\u{c3c}', Extend), ('\u{c3e}', '\u{c40}', Extend),
+        ('\u{c46}', '\u{c48}', Extend), ('\u{c4a}', '\u{c4c}', Extend),
+        ('\u{c4d}', '\u{c4d}', Linker), ('\u{c55}', '\u{c56}', Extend),
+        ('\u{c58}', '\u{c5a}', Consonant), ('\u{c62}', '\u{c63}', Extend),
+        ('\u{c81}', '\u{c81}', Extend), ('\u{cbc}', '\u{cbc}', Extend),
+        ('\u{cbf}', '\u{cc0}', Extend), ('\u{cc2}', '\u{cc2}', Extend),
+        ('\u{cc6}', '\u{cc8}', Extend), ('\u{cca}', '\u{ccd}', Extend),
+        ('\u{cd5}', '\u{cd6}', Extend), ('\u{ce2}', '\u{ce3}', Extend),
+        ('\u{d00}', '\u{d01}', Extend), ('\u{d15}', '\u{d3a}', Consonant),
+        ('\u{d3b}', '\u{d3c}', Extend), ('\u{d3e}', '\u{d3e}', Extend),
+        ('\u{d41}', '\u{d44}', Extend), ('\u{d4d}', '\u{d4d}', Linker),
+        ('\u{d57}', '\u{d57}', Extend), ('\u{d62}', '\u{d63}', Extend),
+        ('\u{d81}', '\u{d81}', Extend), ('\u{dca}', '\u{dca}', Extend),
+        ('\u{dcf}', '\u{dcf}', Extend), ('\u{dd2}', '\u{dd4}', Extend),
+        ('\u{dd6}', '\u{dd6}', Extend), ('\u{ddf}', '\u{ddf}', Extend),
+        ('\u{e31}', '\u{e31}', Extend), ('\u{e34}', '\u{e3a}', Extend),
+        ('\u{e47}', '\u{e4e}', Extend), ('\u{eb1}', '\u{eb1}', Extend),
+        ('\u{eb4}', '\u{ebc}', Extend), ('\u{ec8}', '\u{ece}', Extend),
+        ('\u{f18}', '\u{f19}', Extend), ('\u{f35}', '\u{f35}', Extend),
+        ('\u{f37}', '\u{f37}', Extend), ('\u{f39}', '\u{f39}', Extend),
+        ('\u{f71}', '\u{f7e}', Extend), ('\u{f80}', '\u{f84}', Extend),
+        ('\u{f86}', '\u{f87}', Extend), ('\u{f8d}', '\u{f97}', Extend),
+        ('\u{f99}', '\u{fbc}', Extend), ('\u{fc6}', '\u{fc6}', Extend),
+        ('\u{102d}', '\u{1030}', Extend), ('\u{1032}', '\u{1037}', Extend),
+        ('\u{1039}', '\u{103a}', Extend), ('\u{103d}', '\u{103e}', Extend),
+        ('\u{1058}', '\u{1059}', Extend), ('\u{105e}', '\u{1060}', Extend),
+        ('\u{1071}', '\u{1074}', Extend), ('\u{1082}', '\u{1082}', Extend),
+        ('\u{1085}', '\u{1086}', Extend), ('\u{108d}', '\u{108d}', Extend),
+        ('\u{109d}', '\u{109d}', Extend), ('\u{135d}', '\u{135f}', Extend),
+        ('\u{1712}', '\u{1715}', Extend), ('\u{1732}', '\u{1734}', Extend),
+        ('\u{1752}', '\u{1753}', Extend), ('\u{1772}', '\u{1773}', Extend),
+        ('\u{17b4}', '\u{17b5}', Extend), ('\u{17b7}', '\u{17bd}', Extend),
+        ('\u{17c6}', '\u{17c6}', Extend), ('\u{17c9}', '\u{17d3}', Extend),
+        ('\u{17dd}', '\u{17dd}', Extend), ('\u{180b}', '\u{180d}', Extend),
+        ('\u{180f}', '\u{180f}', Extend), ('\u{1885}', '\u{1886}', Extend),
+        ('\u{18a9}', '\u{18a9}', Extend), ('\u{1920}', '\u{1922}', Extend),
+        ('\u{1927}', '\u{1928}', Extend), ('\u{1932}', '\u{1932}', Extend),
+        ('\u{1939}', '\u{193b}', Extend), ('\u{1a17}', '\u{1a18}', Extend),
+        ('\u{1a1b}', '\u{1a1b}', Extend), ('\u{1a56}', '\u{1a56}', Extend),
+        ('\u{1a58}', '\u{1a5e}', Extend), ('\u{1a60}', '\u{1a60}', Extend),
+        ('\u{1a62}', '\u{1a62}', Extend), ('\u{1a65}', '\u{1a6c}', Extend),
+        ('\u{1a73}', '\u{1a7c}', Extend), ('\u{1a7f}', '\u{1a7f}', Extend),
+        ('\u{1ab0}', '\u{1ace}', Extend), ('\u{1b00}', '\u{1b03}', Extend),
+        ('\u{1b34}', '\u{1b3d}', Extend), ('\u{1b42}', '\u{1b44}', Extend),
+        ('\u{1b6b}', '\u{1b73}', Extend), ('\u{1b80}', '\u{1b81}', Extend),
+        ('\u{1ba2}', '\u{1ba5}', Extend), ('\u{1ba8}', '\u{1bad}', Extend),
+        ('\u{1be6}', '\u{1be6}', Extend), ('\u{1be8}', '\u{1be9}', Extend),
+        ('\u{1bed}', '\u{1bed}', Extend), ('\u{1bef}', '\u{1bf3}', Extend),
+        ('\u{1c2c}', '\u{1c33}', Extend), ('\u{1c36}', '\u{1c37}', Extend),
+        ('\u{1cd0}', '\u{1cd2}', Extend), ('\u{1cd4}', '\u{1ce0}', Extend),
+        ('\u{1ce2}', '\u{1ce8}', Extend), ('\u{1ced}', '\u{1ced}', Extend),
+        ('\u{1cf4}', '\u{1cf4}', Extend), ('\u{1cf8}', '\u{1cf9}', Extend),
+        ('\u{1dc0}', '\u{1dff}', Extend), ('\u{200d}', '\u{200d}', Extend),
+        ('\u{20d0}', '\u{20f0}', Extend), ('\u{2cef}', '\u{2cf1}', Extend),
+        ('\u{2d7f}', '\u{2d7f}', Extend), ('\u{2de0}', '\u{2dff}', Extend),
+        ('\u{302a}', '\u{302f}', Extend), ('\u{3099}', '\u{309a}', Extend),
+        ('\u{a66f}', '\u{a672}', Extend), ('\u{a674}', '\u{a67d}', Extend),
+        ('\u{a69e}', '\u{a69f}', Extend), ('\u{a6f0}', '\u{a6f1}', Extend),
+        ('\u{a802}', '\u{a802}', Extend), ('\u{a806}', '\u{a806}', Extend),
+        ('\u{a80b}', '\u{a80b}', Extend), ('\u{a825}', '\u{a826}', Extend),
+        ('\u{a82c}', '\u{a82c}', Extend), ('\u{a8c4}', '\u{a8c5}', Extend),
+        ('\u{a8e0}', '\u{a8f1}', Extend), ('\u{a8ff}', '\u{a8ff}', Extend),
+        ('\u{a926}', '\u{a92d}', Extend), ('\u{a947}', '\u{a951}', Extend),
+        ('\u{a953}', '\u{a953}', Extend), ('\u{a980}', '\u{a982}', Extend),
+        ('\u{a9b3}', '\u{a9b3}', Extend), ('\u{a9b6}', '\u{a9b9}', Extend),
+        ('\u{a9bc}', '\u{a9bd}', Extend), ('\u{a9c0}', '\u{a9c0}', Extend),
+        ('\u{a9e5}', '\u{a9e5}', Extend), ('\u{aa29}', '\u{aa2e}', Extend),
+        ('\u{aa31}', '\u{aa32}', Extend), ('\u{aa35}', '\u{aa36}', Extend),
+        ('\u{aa43}', '\u{aa43}', Extend), ('\u{aa4c}', '\u{aa4c}', Extend),
+        ('\u{aa7c}', '\u{aa7c}', Extend), ('\u{aab0}', '\u{aab0}', Extend),
+        ('\u{aab2}', '\u{aab4}', Extend), ('\u{aab7}', '\u{aab8}', Extend),
+        ('\u{aabe}', '\u{aabf}', Extend), ('\u{aac1}', '\u{aac1}', Extend),
+        ('\u{aaec}', '\u{aaed}', Extend), ('\u{aaf6}', '\u{aaf6}', Extend),
+        ('\u{abe5}', '\u{abe5}', Extend), ('\u{abe8}', '\u{abe8}', Extend),
+        ('\u{abed}', '\u{abed}', Extend), ('\u{fb1e}', '\u{fb1e}', Extend),
+        ('\u{fe00}', '\u{fe0f}', Extend), ('\u{fe20}', '\u{fe2f}', Extend),
+        ('\u{ff9e}', '\u{ff9f}', Extend), ('\u{101fd}', '\u{101fd}', Extend),
+        ('\u{102e0}', '\u{102e0}', Extend), ('\u{10376}', '\u{1037a}', Extend),
+        ('\u{10a01}', '\u{10a03}', Extend), ('\u{10a05}', '\u{10a06}', Extend),
+        ('\u{10a0c}', '\u{10a0f}', Extend), ('\u{10a38}', '\u{10a3a}', Extend),
+        ('\u{10a3f}', '\u{10a3f}', Extend), ('\u{10ae5}', '\u{10ae6}', Extend),
+        ('\u{10d24}', '\u{10d27}', Extend), ('\u{10d69}', '\u{10d6d}', Extend),
+        ('\u{10eab}', '\u{10eac}', Extend), ('\u{10efc}', '\u{10eff}', Extend),
+        ('\u{10f46}', '\u{10f50}', Extend), ('\u{10f82}', '\u{10f85}', Extend),
+        ('\u{11001}', '\u{11001}', Extend), ('\u{11038}', '\u{11046}', Extend),
+        ('\u{11070}', '\u{11070}', Extend), ('\u{11073}', '\u{11074}', Extend),
+        ('\u{1107f}', '\u{11081}', Extend), ('\u{110b3}', '\u{110b6}', Extend),
+        ('\u{110b9}', '\u{110ba}', Extend), ('\u{110c2}', '\u{110c2}', Extend),
+        ('\u{11100}', '\u{11102}', Extend), ('\u{11127}', '\u{1112b}', Extend),
+        ('\u{1112d}', '\u{11134}', Extend), ('\u{11173}', '\u{11173}', Extend),
+        ('\u{11180}', '\u{11181}', Extend), ('\u{111b6}', '\u{111be}', Extend),
+        ('\u{111c0}', '\u{111c0}', Extend), ('\u{111c9}', '\u{111cc}', Extend),
+        ('\u{111cf}', '\u{111cf}', Extend), ('\u{1122f}', '\u{11231}', Extend),
+        ('\u{11234}', '\u{11237}', Extend), ('\u{1123e}', '\u{1123e}', Extend),
+        ('\u{11241}', '\u{11241}', Extend), ('\u{112df}', '\u{112df}', Extend),
+        ('\u{112e3}', '\u{112ea}', Extend), ('\u{11300}', '\u{11301}', Extend),
+        ('\u{1133b}', '\u{1133c}', Extend), ('\u{1133e}', '\u{1133e}', Extend),
+        ('\u{11340}', '\u{11340}', Extend), ('\u{1134d}', '\u{1134d}', Extend),
+        ('\u{11357}', '\u{11357}', Extend), ('\u{11366}', '\u{1136c}', Extend),
+        ('\u{11370}', '\u{11374}', Extend), ('\u{113b8}', '\u{113b8}', Extend),
+        ('\u{113bb}', '\u{113c0}', Extend), ('\u{113c2}', '\u{113c2}', Extend),
+        ('\u{113c5}', '\u{113c5}', Extend), ('\u{113c7}', '\u{113c9}', Extend),
+        ('\u{113ce}', '\u{113d0}', Extend), ('\u{113d2}', '\u{113d2}', Extend),
+        ('\u{113e1}', '\u{113e2}', Extend), ('\u{11438}', '\u{1143f}', Extend),
+        ('\u{11442}', '\u{11444}', Extend), ('\u{11446}', '\u{11446}', Extend),
+        ('\u{1145e}', '\u{1145e}', Extend), ('\u{114b0}', '\u{114b0}', Extend),
+        ('\u{114b3}', '\u{114b8}', Extend), ('\u{114ba}', '\u{114ba}', Extend),
+        ('\u{114bd}', '\u{114bd}', Extend), ('\u{114bf}', '\u{114c0}', Extend),
+        ('\u{114c2}', '\u{114c3}', Extend), ('\u{115af}', '\u{115af}', Extend),
+        ('\u{115b2}', '\u{115b5}', Extend), ('\u{115bc}', '\u{115bd}', Extend),
+        ('\u{115bf}', '\u{115c0}', Extend), ('\u{115dc}', '\u{115dd}', Extend),
+        ('\u{11633}', '\u{1163a}', Extend), ('\u{1163d}', '\u{1163d}', Extend),
+        ('\u{1163f}', '\u{11640}', Extend), ('\u{116ab}', '\u{116ab}', Extend),
+        ('\u{116ad}', '\u{116ad}', Extend), ('\u{116b0}', '\u{116b7}', Extend),
+        ('\u{1171d}', '\u{1171d}', Extend), ('\u{1171f}', '\u{1171f}', Extend),
+        ('\u{11722}', '\u{11725}', Extend), ('\u{11727}', '\u{1172b}', Extend),
+        ('\u{1182f}', '\u{11837}', Extend), ('\u{11839}', '\u{1183a}', Extend),
+        ('\u{11930}', '\u{11930}', Extend), ('\u{1193b}', '\u{1193e}', Extend),
+        ('\u{11943}', '\u{11943}', Extend), ('\u{119d4}', '\u{119d7}', Extend),
+        ('\u{119da}', '\u{119db}', Extend), ('\u{119e0}', '\u{119e0}', Extend),
+        ('\u{11a01}', '\u{11a0a}', Extend), ('\u{11a33}', '\u{11a38}', Extend),
+        ('\u{11a3b}', '\u{11a3e}', Extend), ('\u{11a47}', '\u{11a47}', Extend),
+        ('\u{11a51}', '\u{11a56}', Extend), ('\u{11a59}', '\u{11a5b}', Extend),
+        ('\u{11a8a}', '\u{11a96}', Extend), ('\u{11a98}', '\u{11a99}', Extend),
+        ('\u{11c30}', '\u{11c36}', Extend), ('\u{11c38}', '\u{11c3d}', Extend),
+        ('\u{11c3f}', '\u{11c3f}', Extend), ('\u{11c92}', '\u{11ca7}', Extend),
+        ('\u{11caa}', '\u{11cb0}', Extend), ('\u{11cb2}', '\u{11cb3}', Extend),
+        ('\u{11cb5}', '\u{11cb6}', Extend), ('\u{11d31}', '\u{11d36}', Extend),
+        ('\u{11d3a}', '\u{11d3a}', Extend), ('\u{11d3c}', '\u{11d3d}', Extend),
+        ('\u{11d3f}', '\u{11d45}', Extend), ('\u{11d47}', '\u{11d47}', Extend),
+        ('\u{11d90}', '\u{11d91}', Extend), ('\u{11d95}', '\u{11d95}', Extend),
+        ('\u{11d97}', '\u{11d97}', Extend), ('\u{11ef3}', '\u{11ef4}', Extend),
+        ('\u{11f00}', '\u{11f01}', Extend), ('\u{11f36}', '\u{11f3a}', Extend),
+        ('\u{11f40}', '\u{11f42}', Extend), ('\u{11f5a}', '\u{11f5a}', Extend),
+        ('\u{13440}', '\u{13440}', Extend), ('\u{13447}', '\u{13455}', Extend),
+        ('\u{1611e}', '\u{16129}', Extend), ('\u{1612d}', '\u{1612f}', Extend),
+        ('\u{16af0}', '\u{16af4}', Extend), ('\u{16b30}', '\u{16b36}', Extend),
+        ('\u{16f4f}', '\u{16f4f}', Extend), ('\u{16f8f}', '\u{16f92}', Extend),
+        ('\u{16fe4}', '\u{16fe4}', Extend), ('\u{16ff0}', '\u{16ff1}', Extend),
+        ('\u{1bc9d}', '\u{1bc9e}', Extend), ('\u{1cf00}', '\u{1cf2d}', Extend),
+        ('\u{1cf30}', '\u{1cf46}', Extend), ('\u{1d165}', '\u{1d169}', Extend),
+        ('\u{1d16d}', '\u{1d172}', Extend), ('\u{1d17b}', '\u{1d182}', Extend),
+        ('\u{1d185}', '\u{1d18b}', Extend), ('\u{1d1aa}', '\u{1d1ad}', Extend),
+        ('\u{1d242}', '\u{1d244}', Extend), ('\u{1da00}', '\u{1da36}', Extend),
+        ('\u{1da3b}', '\u{1da6c}', Extend), ('\u{1da75}', '\u{1da75}', Extend),
+        ('\u{1da84}', '\u{1da84}', Extend), ('\u{1da9b}', '\u{1da9f}', Extend),
+        ('\u{1daa1}', '\u{1daaf}', Extend), ('\u{1e000}', '\u{1e006}', Extend),
+        ('\u{1e008}', '\u{1e018}', Extend), ('\u{1e01b}', '\u{1e021}', Extend),
+        ('\u{1e023}', '\u{1e024}', Extend), ('\u{1e026}', '\u{1e02a}', Extend),
+        ('\u{1e08f}', '\u{1e08f}', Extend), ('\u{1e130}', '\u{1e136}', Extend),
+        ('\u{1e2ae}', '\u{1e2ae}', Extend), ('\u{1e2ec}', '\u{1e2ef}', Extend),
+        ('\u{1e4ec}', '\u{1e4ef}', Extend), ('\u{1e5ee}', '\u{1e5ef}', Extend),
+        ('\u{1e8d0}', '\u{1e8d6}', Extend), ('\u{1e944}', '\u{1e94a}', Extend),
+        ('\u{1f3fb}', '\u{1f3ff}', Extend), ('\u{e0020}', '\u{e007f}', Extend),
+        ('\u{e0100}', '\u{e01ef}', Extend),
+    ];
+}
+
+#[rustfmt::skip]
+const EXTENDED_PICTOGRAPHIC: &[(char, char)] = &[
+    ('\u{a9}', '\u{a9}'), ('\u{ae}', '\u{ae}'), ('\u{203c}', '\u{203c}'), ('\u{2049}', '\u{2049}'),
+    ('\u{2122}', '\u{2122}'), ('\u{2139}', '\u{2139}'), ('\u{2194}', '\u{2199}'),
+    ('\u{21a9}', '\u{21aa}'), ('\u{231a}', '\u{231b}'), ('\u{2328}', '\u{2328}'),
+    ('\u{2388}', '\u{2388}'), ('\u{23cf}', '\u{23cf}'), ('\u{23e9}', '\u{23f3}'),
+    ('\u{23f8}', '\u{23fa}'), ('\u{24c2}', '\u{24c2}'), ('\u{25aa}', '\u{25ab}'),
+    ('\u{25b6}', '\u{25b6}'), ('\u{25c0}', '\u{25c0}'), ('\u{25fb}', '\u{25fe}'),
+    ('\u{2600}', '\u{2605}'), ('\u{2607}', '\u{2612}'), ('\u{2614}', '\u{2685}'),
+    ('\u{2690}', '\u{2705}'), ('\u{2708}', '\u{2712}'), ('\u{2714}', '\u{2714}'),
+    ('\u{2716}', '\u{2716}'), ('\u{271d}', '\u{271d}'), ('\u{2721}', '\u{2721}'),
+    ('\u{2728}', '\u{2728}'), ('\u{2733}', '\u{2734}'), ('\u{2744}', '\u{2744}'),
+    ('\u{2747}', '\u{2747}'), ('\u{274c}', '\u{274c}'), ('\u{274e}', '\u{274e}'),
+    ('\u{2753}', '\u{2755}'), ('\u{2757}', '\u{2757}'), ('\u{2763}', '\u{2767}'),
+    ('\u{2795}', '\u{2797}'), ('\u{27a1}', '\u{27a1}'), ('\u{27b0}', '\u{27b0}'),
+    ('\u{27bf}', '\u{27bf}'), ('\u{2934}', '\u{2935}'), ('\u{2b05}', '\u{2b07}'),
+    ('\u{2b1b}', '\u{2b1c}'), ('\u{2b50}', '\u{2b50}'), ('\u{2b55}', '\u{2b55}'),
+    ('\u{3030}', '\u{3030}'), ('\u{303d}', '\u{303d}'), ('\u{3297}', '\u{3297}'),
+    ('\u{3299}', '\u{3299}'), ('\u{1f000}', '\u{1f0ff}'), ('\u{1f10d}', '\u{1f10f}'),
+    ('\u{1f12f}', '\u{1f12f}'), ('\u{1f16c}', '\u{1f171}'), ('\u{1f17e}', '\u{1f17f}'),
+    ('\u{1f18e}', '\u{1f18e}'), ('\u{1f191}', '\u{1f19a}'), ('\u{1f1ad}', '\u{1f1e5}'),
+    ('\u{1f201}', '\u{1f20f}'), ('\u{1f21a}', '\u{1f21a}'), ('\u{1f22f}', '\u{1f22f}'),
+    ('\u{1f232}', '\u{1f23a}'), ('\u{1f23c}', '\u{1f23f}'), ('\u{1f249}', '\u{1f3fa}'),
+    ('\u{1f400}', '\u{1f53d}'), ('\u{1f546}', '\u{1f64f}'), ('\u{1f680}', '\u{1f6ff}'),
+    ('\u{1f774}', '\u{1f77f}'), ('\u{1f7d5}', '\u{1f7ff}'), ('\u{1f80c}', '\u{1f80f}'),
+    ('\u{1f848}', '\u{1f84f}'), ('\u{1f85a}', '\u{1f85f}'), ('\u{1f888}', '\u{1f88f}'),
+    ('\u{1f8ae}', '\u{1f8ff}'), ('\u{1f90c}', '\u{1f93a}'), ('\u{1f93c}', '\u{1f945}'),
+    ('\u{1f947}', '\u{1faff}'), ('\u{1fc00}', '\u{1fffd}'),
+];
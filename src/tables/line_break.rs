@@ -0,0 +1,816 @@
+//! Line breaking related properties, see [UAX #14](https://www.unicode.org/reports/tr14/).
+//!
+//! Generated from the `Line_Break`, `General_Category` and `East_Asian_Width`
+//! properties of the Unicode Character Database 16.0.0 by
+//! `scripts/gen_tables.py`, do not edit by hand.
+//!
+//! The classes are already resolved as described by LB1: `AI`, `SG` and `XX`
+//! are [`Al`](LineBreak::Al), `CJ` is [`Ns`](LineBreak::Ns), and `SA` is
+//! [`Cm`](LineBreak::Cm) for marks and [`Al`](LineBreak::Al) otherwise.
+
+use super::{contains, lookup};
+use LineBreak::*;
+
+/// The `Line_Break` property of a code point, named by the short alias of
+/// each class.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LineBreak {
+    Bk,
+    Cr,
+    Lf,
+    Cm,
+    Nl,
+    Wj,
+    Zw,
+    Gl,
+    Sp,
+    Zwj,
+    B2,
+    Ba,
+    Bb,
+    Hy,
+    Cb,
+    Cl,
+    Cp,
+    Ex,
+    In,
+    Ns,
+    Op,
+    Qu,
+    Is,
+    Nu,
+    Po,
+    Pr,
+    Sy,
+    Al,
+    Eb,
+    Em,
+    H2,
+    H3,
+    Hl,
+    Id,
+    Jl,
+    Jv,
+    Jt,
+    Ri,
+    Ak,
+    Ap,
+    As,
+    Vf,
+    Vi,
+}
+
+pub(crate) fn line_break(c: char) -> LineBreak {
+    lookup(c, LINE_BREAK).unwrap_or(LineBreak::Al)
+}
+
+/// Check if the code point is a quotation mark, which opens a quote (`QU` and
+/// `Pi`).
+pub(crate) fn is_initial_quote(c: char) -> bool {
+    contains(c, INITIAL_QUOTE)
+}
+
+/// Check if the code point is a quotation mark, which closes a quote (`QU` and
+/// `Pf`).
+pub(crate) fn is_final_quote(c: char) -> bool {
+    contains(c, FINAL_QUOTE)
+}
+
+/// Check if the code point has an east asian width of fullwidth, wide or
+/// halfwidth.
+pub(crate) fn is_east_asian(c: char) -> bool {
+    contains(c, EAST_ASIAN)
+}
+
+#[rustfmt::skip]
+const LINE_BREAK: &[(char, char, LineBreak)] = &[
+    ('\u{0}', '\u{8}', Cm), ('\u{9}', '\u{9}', Ba), ('\u{a}', '\u{a}', Lf), ('\u{b}', '\u{c}', Bk),
+    ('\u{d}', '\u{d}', Cr), ('\u{e}', '\u{1f}', Cm), ('\u{20}', '\u{20}', Sp),
+    ('\u{21}', '\u{21}', Ex), ('\u{22}', '\u{22}', Qu), ('\u{24}', '\u{24}', Pr),
+    ('\u{25}', '\u{25}', Po), ('\u{27}', '\u{27}', Qu), ('\u{28}', '\u{28}', Op),
+    ('\u{29}', '\u{29}', Cp), ('\u{2b}', '\u{2b}', Pr), ('\u{2c}', '\u{2c}', Is),
+    ('\u{2d}', '\u{2d}', Hy), ('\u{2e}', '\u{2e}', Is), ('\u{2f}', '\u{2f}', Sy),
+    ('\u{30}', '\u{39}', Nu), ('\u{3a}', '\u{3b}', Is), ('\u{3f}', '\u{3f}', Ex),
+    ('\u{5b}', '\u{5b}', Op), ('\u{5c}', '\u{5c}', Pr), ('\u{5d}', '\u{5d}', Cp),
+    ('\u{7b}', '\u{7b}', Op), ('\u{7c}', '\u{7c}', Ba), ('\u{7d}', '\u{7d}', Cl),
+    ('\u{7f}', '\u{84}', Cm), ('\u{85}', '\u{85}', Nl), ('\u{86}', '\u{9f}', Cm),
+    ('\u{a0}', '\u{a0}', Gl), ('\u{a1}', '\u{a1}', Op), ('\u{a2}', '\u{a2}', Po),
+    ('\u{a3}', '\u{a5}', Pr), ('\u{ab}', '\u{ab}', Qu), ('\u{ad}', '\u{ad}', Ba),
+    ('\u{b0}', '\u{b0}', Po), ('\u{b1}', '\u{b1}', Pr), ('\u{b4}', '\u{b4}', Bb),
+    ('\u{bb}', '\u{bb}', Qu), ('\u{bf}', '\u{bf}', Op), ('\u{2c8}', '\u{2c8}', Bb),
+    ('\u{2cc}', '\u{2cc}', Bb), ('\u{2df}', '\u{2df}', Bb), ('\u{300}', '\u{34e}', Cm),
+    ('\u{34f}', '\u{34f}', Gl), ('\u{350}', '\u{35b}', Cm), ('\u{35c}', '\u{362}', Gl),
+    ('\u{363}', '\u{36f}', Cm), ('\u{37e}', '\u{37e}', Is), ('\u{483}', '\u{489}', Cm),
+    ('\u{589}', '\u{589}', Is), ('\u{58a}', '\u{58a}', Ba), ('\u{58f}', '\u{58f}', Pr),
+    ('\u{591}', '\u{5bd}', Cm), ('\u{5be}', '\u{5be}', Ba), ('\u{5bf}', '\u{5bf}', Cm),
+    ('\u{5c1}', '\u{5c2}', Cm), ('\u{5c4}', '\u{5c5}', Cm), ('\u{5c6}', '\u{5c6}', Ex),
+    ('\u{5c7}', '\u{5c7}', Cm), ('\u{5d0}', '\u{5ea}', Hl), ('\u{5ef}', '\u{5f2}', Hl),
+    ('\u{600}', '\u{605}', Nu), ('\u{609}', '\u{60b}', Po), ('\u{60c}', '\u{60d}', Is),
+    ('\u{610}', '\u{61a}', Cm), ('\u{61b}', '\u{61b}', Ex), ('\u{61c}', '\u{61c}', Cm),
+    ('\u{61d}', '\u{61f}', Ex), ('\u{64b}', '\u{65f}', Cm), ('\u{660}', '\u{669}', Nu),
+    ('\u{66a}', '\u{66a}', Po), ('\u{66b}', '\u{66c}', Nu), ('\u{670}', '\u{670}', Cm),
+    ('\u{6d4}', '\u{6d4}', Ex), ('\u{6d6}', '\u{6dc}', Cm), ('\u{6dd}', '\u{6dd}', Nu),
+    ('\u{6df}', '\u{6e4}', Cm), ('\u{6e7}', '\u{6e8}', Cm), ('\u{6ea}', '\u{6ed}', Cm),
+    ('\u{6f0}', '\u{6f9}', Nu), ('\u{711}', '\u{711}', Cm), ('\u{730}', '\u{74a}', Cm),
+    ('\u{7a6}', '\u{7b0}', Cm), ('\u{7c0}', '\u{7c9}', Nu), ('\u{7eb}', '\u{7f3}', Cm),
+    ('\u{7f8}', '\u{7f8}', Is), ('\u{7f9}', '\u{7f9}', Ex), ('\u{7fd}', '\u{7fd}', Cm),
+    ('\u{7fe}', '\u{7ff}', Pr), ('\u{816}', '\u{819}', Cm), ('\u{81b}', '\u{823}', Cm),
+    ('\u{825}', '\u{827}', Cm), ('\u{829}', '\u{82d}', Cm), ('\u{859}', '\u{85b}', Cm),
+    ('\u{890}', '\u{891}', Nu), ('\u{897}', '\u{89f}', Cm), ('\u{8ca}', '\u{8e1}', Cm),
+    ('\u{8e2}', '\u{8e2}', Nu), ('\u{8e3}', '\u{903}', Cm), ('\u{93a}', '\u{93c}', Cm),
+    ('\u{93e}', '\u{94f}', Cm), ('\u{951}', '\u{957}', Cm), ('\u{962}', '\u{963}', Cm),
+    ('\u{964}', '\u{965}', Ba), ('\u{966}', '\u{96f}', Nu), ('\u{981}', '\u{983}', Cm),
+    ('\u{9bc}', '\u{9bc}', Cm), ('\u{9be}', '\u{9c4}', Cm), ('\u{9c7}', '\u{9c8}', Cm),
+    ('\u{9cb}', '\u{9cd}', Cm), ('\u{9d7}', '\u{9d7}', Cm), ('\u{9e2}', '\u{9e3}', Cm),
+    ('\u{9e6}', '\u{9ef}', Nu), ('\u{9f2}', '\u{9f3}', Po), ('\u{9f9}', '\u{9f9}', Po),
+    ('\u{9fb}', '\u{9fb}', Pr), ('\u{9fe}', '\u{9fe}', Cm), ('\u{a01}', '\u{a03}', Cm),
+    ('\u{a3c}', '\u{a3c}', Cm), ('\u{a3e}', '\u{a42}', Cm), ('\u{a47}', '\u{a48}', Cm),
+    ('\u{a4b}', '\u{a4d}', Cm), ('\u{a51}', '\u{a51}', Cm), ('\u{a66}', '\u{a6f}', Nu),
+    ('\u{a70}', '\u{a71}', Cm), ('\u{a75}', '\u{a75}', Cm), ('\u{a81}', '\u{a83}', Cm),
+    ('\u{abc}', '\u{abc}', Cm), ('\u{abe}', '\u{ac5}', Cm), ('\u{ac7}', '\u{ac9}', Cm),
+    ('\u{acb}', '\u{acd}', Cm), ('\u{ae2}', '\u{ae3}', Cm), ('\u{ae6}', '\u{aef}', Nu),
+    ('\u{af1}', '\u{af1}', Pr), ('\u{afa}', '\u{aff}', Cm), ('\u{b01}', '\u{b03}', Cm),
+    ('\u{b3c}', '\u{b3c}', Cm), ('\u{b3e}', '\u{b44}', Cm), ('\u{b47}', '\u{b48}', Cm),
+    ('\u{b4b}', '\u{b4d}', Cm), ('\u{b55}', '\u{b57}', Cm), ('\u{b62}', '\u{b63}', Cm),
+    ('\u{b66}', '\u{b6f}', Nu), ('\u{b82}', '\u{b82}', Cm), ('\u{bbe}', '\u{bc2}', Cm),
+    ('\u{bc6}', '\u{bc8}', Cm), ('\u{bca}', '\u{bcd}', Cm), ('\u{bd7}', '\u{bd7}', Cm),
+    ('\u{be6}', '\u{bef}', Nu), ('\u{bf9}', '\u{bf9}', Pr), ('\u{c00}', '\u{c04}', Cm),
+    ('\u{c3c}', '\u{c3c}', Cm), ('\u{c3e}', '\u{c44}', Cm), ('\u{c46}', '\u{c48}', Cm),
+    ('\u{c4a}', '\u{c4d}', Cm), ('\u{c55}', '\u{c56}', Cm), ('\u{c62}', '\u{c63}', Cm),
+    ('\u{c66}', '\u{c6f}', Nu), ('\u{c77}', '\u{c77}', Bb), ('\u{c81}', '\u{c83}', Cm),
+    ('\u{c84}', '\u{c84}', Bb), ('\u{cbc}', '\u{cbc}', Cm), ('\u{cbe}', '\u{cc4}', Cm),
+    ('\u{cc6}', '\u{cc8}', Cm), ('\u{cca}', '\u{ccd}', Cm), ('\u{cd5}', '\u{cd6}', Cm),
+    ('\u{ce2}', '\u{ce3}', Cm), ('\u{ce6}', '\u{cef}', Nu), ('\u{cf3}', '\u{cf3}', Cm),
+    ('\u{d00}', '\u{d03}', Cm), ('\u{d3b}', '\u{d3c}', Cm), ('\u{d3e}', '\u{d44}', Cm),
+    ('\u{d46}', '\u{d48}', Cm), ('\u{d4a}', '\u{d4d}', Cm), ('\u{d57}', '\u{d57}', Cm),
+    ('\u{d62}', '\u{d63}', Cm), ('\u{d66}', '\u{d6f}', Nu), ('\u{d79}', '\u{d79}', Po),
+    ('\u{d81}', '\u{d83}', Cm), ('\u{dca}', '\u{dca}', Cm), ('\u{dcf}', '\u{dd4}', Cm),
+    ('\u{dd6}', '\u{dd6}', Cm), ('\u{dd8}', '\u{ddf}', Cm), ('\u{de6}', '\u{def}', Nu),
+    ('\u{df2}', '\u{df3}', Cm), ('\u{e31}', '\u{e31}', Cm), ('\u{e34}', '\u{e3a}', Cm),
+    ('\u{e3f}', '\u{e3f}', Pr), ('\u{e47}', '\u{e4e}', Cm), ('\u{e50}', '\u{e59}', Nu),
+    ('\u{e5a}', '\u{e5b}', Ba), ('\u{eb1}', '\u{eb1}', Cm), ('\u{eb4}', '\u{ebc}', Cm),
+    ('\u{ec8}', '\u{ece}', Cm), ('\u{ed0}', '\u{ed9}', Nu), ('\u{f01}', '\u{f04}', Bb),
+    ('\u{f06}', '\u{f07}', Bb), ('\u{f08}', '\u{f08}', Gl), ('\u{f09}', '\u{f0a}', Bb),
+    ('\u{f0b}', '\u{f0b}', Ba), ('\u{f0c}', '\u{f0c}', Gl), ('\u{f0d}', '\u{f11}', Ex),
+    ('\u{f12}', '\u{f12}', Gl), ('\u{f14}', '\u{f14}', Ex), ('\u{f18}', '\u{f19}', Cm),
+    ('\u{f20}', '\u{f29}', Nu), ('\u{f34}', '\u{f34}', Ba), ('\u{f35}', '\u{f35}', Cm),
+    ('\u{f37}', '\u{f37}', Cm), ('\u{f39}', '\u{f39}', Cm), ('\u{f3a}', '\u{f3a}', Op),
+    ('\u{f3b}', '\u{f3b}', Cl), ('\u{f3c}', '\u{f3c}', Op), ('\u{f3d}', '\u{f3d}', Cl),
+    ('\u{f3e}', '\u{f3f}', Cm), ('\u{f71}', '\u{f7e}', Cm), ('\u{f7f}', '\u{f7f}', Ba),
+    ('\u{f80}', '\u{f84}', Cm), ('\u{f85}', '\u{f85}', Ba), ('\u{f86}', '\u{f87}', Cm),
+    ('\u{f8d}', '\u{f97}', Cm), ('\u{f99}', '\u{fbc}', Cm), ('\u{fbe}', '\u{fbf}', Ba),
+    ('\u{fc6}', '\u{fc6}', Cm), ('\u{fd0}', '\u{fd1}', Bb), ('\u{fd2}', '\u{fd2}', Ba),
+    ('\u{fd3}', '\u{fd3}', Bb), ('\u{fd9}', '\u{fda}', Gl), ('\u{102b}', '\u{103e}', Cm),
+    ('\u{1040}', '\u{1049}', Nu), ('\u{104a}', '\u{104b}', Ba), ('\u{1056}', '\u{1059}', Cm),
+    ('\u{105e}', '\u{1060}', Cm), ('\u{1062}', '\u{1064}', Cm), ('\u{1067}', '\u{106d}', Cm),
+    ('\u{1071}', '\u{1074}', Cm), ('\u{1082}', '\u{108d}', Cm), ('\u{108f}', '\u{108f}', Cm),
+    ('\u{1090}', '\u{1099}', Nu), ('\u{109a}', '\u{109d}', Cm), ('\u{1100}', '\u{115f}', Jl),
+    ('\u{1160}', '\u{11a7}', Jv), ('\u{11a8}', '\u{11ff}', Jt), ('\u{135d}', '\u{135f}', Cm),
+    ('\u{1361}', '\u{1361}', Ba), ('\u{1400}', '\u{1400}', Ba), ('\u{1680}', '\u{1680}', Ba),
+    ('\u{169b}', '\u{169b}', Op), ('\u{169c}', '\u{169c}', Cl), ('\u{16eb}', '\u{16ed}', Ba),
+    ('\u{1712}', '\u{1715}', Cm), ('\u{1732}', '\u{1734}', Cm), ('\u{1735}', '\u{1736}', Ba),
+    ('\u{1752}', '\u{1753}', Cm), ('\u{1772}', '\u{1773}', Cm), ('\u{17b4}', '\u{17d3}', Cm),
+    ('\u{17d4}', '\u{17d5}', Ba), ('\u{17d6}', '\u{17d6}', Ns), ('\u{17d8}', '\u{17d8}', Ba),
+    ('\u{17da}', '\u{17da}', Ba), ('\u{17db}', '\u{17db}', Pr), ('\u{17dd}', '\u{17dd}', Cm),
+    ('\u{17e0}', '\u{17e9}', Nu), ('\u{1802}', '\u{1803}', Ex), ('\u{1804}', '\u{1805}', Ba),
+    ('\u{1806}', '\u{1806}', Bb), ('\u{1808}', '\u{1809}', Ex), ('\u{180b}', '\u{180d}', Cm),
+    ('\u{180e}', '\u{180e}', Gl), ('\u{180f}', '\u{180f}', Cm), ('\u{1810}', '\u{1819}', Nu),
+    ('\u{1885}', '\u{1886}', Cm), ('\u{18a9}', '\u{18a9}', Cm), ('\u{1920}', '\u{192b}', Cm),
+    ('\u{1930}', '\u{193b}', Cm), ('\u{1944}', '\u{1945}', Ex), ('\u{1946}', '\u{194f}', Nu),
+    ('\u{19d0}', '\u{19da}', Nu), ('\u{1a17}', '\u{1a1b}', Cm), ('\u{1a55}', '\u{1a5e}', Cm),
+    ('\u{1a60}', '\u{1a7c}', Cm), ('\u{1a7f}', '\u{1a7f}', Cm), ('\u{1a80}', '\u{1a89}', Nu),
+    ('\u{1a90}', '\u{1a99}', Nu), ('\u{1ab0}', '\u{1ace}', Cm), ('\u{1b00}', '\u{1b04}', Cm),
+    ('\u{1b05}', '\u{1b33}', Ak), ('\u{1b34}', '\u{1b43}', Cm), ('\u{1b44}', '\u{1b44}', Vi),
+    ('\u{1b45}', '\u{1b4c}', Ak), ('\u{1b4e}', '\u{1b4f}', Ba), ('\u{1b50}', '\u{1b59}', As),
+    ('\u{1b5a}', '\u{1b5b}', Ba), ('\u{1b5c}', '\u{1b5c}', Id), ('\u{1b5d}', '\u{1b60}', Ba),
+    ('\u{1b61}', '\u{1b6a}', Id), ('\u{1b6b}', '\u{1b73}', Cm), ('\u{1b74}', '\u{1b7c}', Id),
+    ('\u{1b7d}', '\u{1b7f}', Ba), ('\u{1b80}', '\u{1b82}', Cm), ('\u{1ba1}', '\u{1bad}', Cm),
+    ('\u{1bb0}', '\u{1bb9}', Nu), ('\u{1bc0}', '\u{1be5}', As), ('\u{1be6}', '\u{1bf1}', Cm),
+    ('\u{1bf2}', '\u{1bf3}', Vf), ('\u{1c24}', '\u{1c37}', Cm), ('\u{1c3b}', '\u{1c3f}', Ba),
+    ('\u{1c40}', '\u{1c49}', Nu), ('\u{1c50}', '\u{1c59}', Nu), ('\u{1c7e}', '\u{1c7f}', Ba),
+    ('\u{1cd0}', '\u{1cd2}', Cm), ('\u{1cd4}', '\u{1ce8}', Cm), ('\u{1ced}', '\u{1ced}', Cm),
+    ('\u{1cf4}', '\u{1cf4}', Cm), ('\u{1cf7}', '\u{1cf9}', Cm), ('\u{1dc0}', '\u{1dcc}', Cm),
+    ('\u{1dcd}', '\u{1dcd}', Gl), ('\u{1dce}', '\u{1dfb}', Cm), ('\u{1dfc}', '\u{1dfc}', Gl),
+    ('\u{1dfd}', '\u{1dff}', Cm), ('\u{1ffd}', '\u{1ffd}', Bb), ('\u{2000}', '\u{2006}', Ba),
+    ('\u{2007}', '\u{2007}', Gl), ('\u{2008}', '\u{200a}', Ba), ('\u{200b}', '\u{200b}', Zw),
+    ('\u{200c}', '\u{200c}', Cm), ('\u{200d}', '\u{200d}', Zwj), ('\u{200e}', '\u{200f}', Cm),
+    ('\u{2010}', '\u{2010}', Ba), ('\u{2011}', '\u{2011}', Gl), ('\u{2012}', '\u{2013}', Ba),
+    ('\u{2014}', '\u{2014}', B2), ('\u{2018}', '\u{2019}', Qu), ('\u{201a}', '\u{201a}', Op),
+    ('\u{201b}', '\u{201d}', Qu), ('\u{201e}', '\u{201e}', Op), ('\u{201f}', '\u{201f}', Qu),
+    ('\u{2024}', '\u{2026}', In), ('\u{2027}', '\u{2027}', Ba), ('\u{2028}', '\u{2029}', Bk),
+    ('\u{202a}', '\u{202e}', Cm), ('\u{202f}', '\u{202f}', Gl), ('\u{2030}', '\u{2037}', Po),
+    ('\u{2039}', '\u{203a}', Qu), ('\u{203c}', '\u{203d}', Ns), ('\u{2044}', '\u{2044}', Is),
+    ('\u{2045}', '\u{2045}', Op), ('\u{2046}', '\u{2046}', Cl), ('\u{2047}', '\u{2049}', Ns),
+    ('\u{2056}', '\u{2056}', Ba), ('\u{2057}', '\u{2057}', Po), ('\u{2058}', '\u{205b}', Ba),
+    ('\u{205d}', '\u{205f}', Ba), ('\u{2060}', '\u{2060}', Wj), ('\u{2066}', '\u{206f}', Cm),
+    ('\u{207d}', '\u{207d}', Op), ('\u{207e}', '\u{207e}', Cl), ('\u{208d}', '\u{208d}', Op),
+    ('\u{208e}', '\u{208e}', Cl), ('\u{20a0}', '\u{20a6}', Pr), ('\u{20a7}', '\u{20a7}', Po),
+    ('\u{20a8}', '\u{20b5}', Pr), ('\u{20b6}', '\u{20b6}', Po), ('\u{20b7}', '\u{20ba}', Pr),
+    ('\u{20bb}', '\u{20bb}', Po), ('\u{20bc}', '\u{20bd}', Pr), ('\u{20be}', '\u{20be}', Po),
+    ('\u{20bf}', '\u{20bf}', Pr), ('\u{20c0}', '\u{20c0}', Po), ('\u{20c1}', '\u{20cf}', Pr),
+    ('\u{20d0}', '\u{20f0}', Cm), ('\u{2103}', '\u{2103}', Po), ('\u{2109}', '\u{2109}', Po),
+    ('\u{2116}', '\u{2116}', Pr), ('\u{2212}', '\u{2213}', Pr), ('\u{22ef}', '\u{22ef}', In),
+    ('\u{2308}', '\u{2308}', Op), ('\u{2309}', '\u{2309}', Cl), ('\u{230a}', '\u{230a}', Op),
+    ('\u{230b}', '\u{230b}', Cl), ('\u{231a}', '\u{231b}', Id), ('\u{2329}', '\u{2329}', Op),
+    ('\u{232a}', '\u{232a}', Cl), ('\u{23f0}', '\u{23f3}', Id), ('\u{2600}', '\u{2603}', Id),
+    ('\u{2614}', '\u{2615}', Id), ('\u{2618}', '\u{2618}', Id), ('\u{261a}', '\u{261c}', Id),
+    ('\u{261d}', '\u{261d}', Eb), ('\u{261e}', '\u{261f}', Id), ('\u{2639}', '\u{263b}', Id),
+    ('\u{2668}', '\u{2668}', Id), ('\u{267f}', '\u{267f}', Id), ('\u{26bd}', '\u{26c8}', Id),
+    ('\u{26cd}', '\u{26cd}', Id), ('\u{26cf}', '\u{26d1}', Id), ('\u{26d3}', '\u{26d4}', Id),
+    ('\u{26d8}', '\u{26d9}', Id), ('\u{26dc}', '\u{26dc}', Id), ('\u{26df}', '\u{26e1}', Id),
+    ('\u{26ea}', '\u{26ea}', Id), ('\u{26f1}', '\u{26f5}', Id), ('\u{26f7}', '\u{26f8}', Id),
+    ('\u{26f9}', '\u{26f9}', Eb), ('\u{26fa}', '\u{26fa}', Id), ('\u{26fd}', '\u{2704}', Id),
+    ('\u{2708}', '\u{2709}', Id), ('\u{270a}', '\u{270d}', Eb), ('\u{275b}', '\u{2760}', Qu),
+    ('\u{2762}', '\u{2763}', Ex), ('\u{2764}', '\u{2764}', Id), ('\u{2768}', '\u{2768}', Op),
+    ('\u{2769}', '\u{2769}', Cl), ('\u{276a}', '\u{276a}', Op), ('\u{276b}', '\u{276b}', Cl),
+    ('\u{276c}', '\u{276c}', Op), ('\u{276d}', '\u{276d}', Cl), ('\u{276e}', '\u{276e}', Op),
+    ('\u{276f}', '\u{276f}', Cl), ('\u{2770}', '\u{2770}', Op), ('\u{2771}', '\u{2771}', Cl),
+    ('\u{2772}', '\u{2772}', Op), ('\u{2773}', '\u{2773}', Cl), ('\u{2774}', '\u{2774}', Op),
+    ('\u{2775}', '\u{2775}', Cl), ('\u{27c5}', '\u{27c5}', Op), ('\u{27c6}', '\u{27c6}', Cl),
+    ('\u{27e6}', '\u{27e6}', Op), ('\u{27e7}', '\u{27e7}', Cl), ('\u{27e8}', '\u{27e8}', Op),
+    ('\u{27e9}', '\u{27e9}', Cl), ('\u{27ea}', '\u{27ea}', Op), ('\u{27eb}', '\u{27eb}', Cl),
+    ('\u{27ec}', '\u{27ec}', Op), ('\u{27ed}', '\u{27ed}', Cl), ('\u{27ee}', '\u{27ee}', Op),
+    ('\u{27ef}', '\u{27ef}', Cl), ('\u{2983}', '\u{2983}', Op), ('\u{2984}', '\u{2984}', Cl),
+    ('\u{2985}', '\u{2985}', Op), ('\u{2986}', '\u{2986}', Cl), ('\u{2987}', '\u{2987}', Op),
+    ('\u{2988}', '\u{2988}', Cl), ('\u{2989}', '\u{2989}', Op), ('\u{298a}', '\u{298a}', Cl),
+    ('\u{298b}', '\u{298b}', Op), ('\u{298c}', '\u{298c}', Cl), ('\u{298d}', '\u{298d}', Op),
+    ('\u{298e}', '\u{298e}', Cl), ('\u{298f}', '\u{298f}', Op), ('\u{2990}', '\u{2990}', Cl),
+    ('\u{2991}', '\u{2991}', Op), ('\u{2992}', '\u{2992}', Cl), ('\u{2993}', '\u{2993}', Op),
+    ('\u{2994}', '\u{2994}', Cl), ('\u{2995}', '\u{2995}', Op), ('\u{2996}', '\u{2996}', Cl),
+    ('\u{2997}', '\u{2997}', Op), ('\u{2998}', '\u{2998}', Cl), ('\u{29d8}', '\u{29d8}', Op),
+    ('\u{29d9}', '\u{29d9}', Cl), ('\u{29da}', '\u{29da}', Op), ('\u{29db}', '\u{29db}', Cl),
+    ('\u{29fc}', '\u{29fc}', Op), ('\u{29fd}', '\u{29fd}', Cl), ('\u{2cef}', '\u{2cf1}', Cm),
+    ('\u{2cf9}', '\u{2cf9}', Ex), ('\u{2cfa}', '\u{2cfc}', Ba), ('\u{2cfe}', '\u{2cfe}', Ex),
+    ('\u{2cff}', '\u{2cff}', Ba), ('\u{2d70}', '\u{2d70}', Ba), ('\u{2d7f}', '\u{2d7f}', Cm),
+    ('\u{2de0}', '\u{2dff}', Cm), ('\u{2e00}', '\u{2e0d}', Qu), ('\u{2e0e}', '\u{2e15}', Ba),
+    ('\u{2e17}', '\u{2e17}', Ba), ('\u{2e18}', '\u{2e18}', Op), ('\u{2e19}', '\u{2e19}', Ba),
+    ('\u{2e1c}', '\u{2e1d}', Qu), ('\u{2e20}', '\u{2e21}', Qu), ('\u{2e22}', '\u{2e22}', Op),
+    ('\u{2e23}', '\u{2e23}', Cl), ('\u{2e24}', '\u{2e24}', Op), ('\u{2e25}', '\u{2e25}', Cl),
+    ('\u{2e26}', '\u{2e26}', Op), ('\u{2e27}', '\u{2e27}', Cl), ('\u{2e28}', '\u{2e28}', Op),
+    ('\u{2e29}', '\u{2e29}', Cl), ('\u{2e2a}', '\u{2e2d}', Ba), ('\u{2e2e}', '\u{2e2e}', Ex),
+    ('\u{2e30}', '\u{2e31}', Ba), ('\u{2e33}', '\u{2e34}', Ba), ('\u{2e3a}', '\u{2e3b}', B2),
+    ('\u{2e3c}', '\u{2e3e}', Ba), ('\u{2e40}', '\u{2e41}', Ba), ('\u{2e42}', '\u{2e42}', Op),
+    ('\u{2e43}', '\u{2e4a}', Ba), ('\u{2e4c}', '\u{2e4c}', Ba), ('\u{2e4e}', '\u{2e4f}', Ba),
+    ('\u{2e53}', '\u{2e54}', Ex), ('\u{2e55}', '\u{2e55}', Op), ('\u{2e56}', '\u{2e56}', Cp),
+    ('\u{2e57}', '\u{2e57}', Op), ('\u{2e58}', '\u{2e58}', Cp), ('\u{2e59}', '\u{2e59}', Op),
+    ('\u{2e5a}', '\u{2e5a}', Cp), ('\u{2e5b}', '\u{2e5b}', Op), ('\u{2e5c}', '\u{2e5c}', Cp),
+    ('\u{2e5d}', '\u{2e5d}', Ba), ('\u{2e80}', '\u{2e99}', Id), ('\u{2e9b}', '\u{2ef3}', Id),
+    ('\u{2f00}', '\u{2fd5}', Id), ('\u{2ff0}', '\u{2fff}', Id), ('\u{3000}', '\u{3000}', Ba),
+    ('\u{3001}', '\u{3002}', Cl), ('\u{3003}', '\u{3004}', Id), ('\u{3005}', '\u{3005}', Ns),
+    ('\u{3006}', '\u{3007}', Id), ('\u{3008}', '\u{3008}', Op), ('\u{3009}', '\u{3009}', Cl),
+    ('\u{300a}', '\u{300a}', Op), ('\u{300b}', '\u{300b}', Cl), ('\u{300c}', '\u{300c}', Op),
+    ('\u{300d}', '\u{300d}', Cl), ('\u{300e}', '\u{300e}', Op), ('\u{300f}', '\u{300f}', Cl),
+    ('\u{3010}', '\u{3010}', Op), ('\u{3011}', '\u{3011}', Cl), ('\u{3012}', '\u{3013}', Id),
+    ('\u{3014}', '\u{3014}', Op), ('\u{3015}', '\u{3015}', Cl), ('\u{3016}', '\u{3016}', Op),
+    ('\u{3017}', '\u{3017}', Cl), ('\u{3018}', '\u{3018}', Op), ('\u{3019}', '\u{3019}', Cl),
+    ('\u{301a}', '\u{301a}', Op), ('\u{301b}', '\u{301b}', Cl), ('\u{301c}', '\u{301c}', Ns),
+    ('\u{301d}', '\u{301d}', Op), ('\u{301e}', '\u{301f}', Cl), ('\u{3020}', '\u{3029}', Id),
+    ('\u{302a}', '\u{302f}', Cm), ('\u{3030}', '\u{3034}', Id), ('\u{3035}', '\u{3035}', Cm),
+    ('\u{3036}', '\u{303a}', Id), ('\u{303b}', '\u{303c}', Ns), ('\u{303d}', '\u{303f}', Id),
+    ('\u{3041}', '\u{3041}', Ns), ('\u{3042}', '\u{3042}', Id), ('\u{3043}', '\u{3043}', Ns),
+    ('\u{3044}', '\u{3044}', Id), ('\u{3045}', '\u{3045}', Ns), ('\u{3046}', '\u{3046}', Id),
+    ('\u{3047}', '\u{3047}', Ns), ('\u{3048}', '\u{3048}', Id), ('\u{3049}', '\u{3049}', Ns),
+    ('\u{304a}', '\u{3062}', Id), ('\u{3063}', '\u{3063}', Ns), ('\u{3064}', '\u{3082}', Id),
+    ('\u{3083}', '\u{3083}', Ns), ('\u{3084}', '\u{3084}', Id), ('\u{3085}', '\u{3085}', Ns),
+    ('\u{3086}', '\u{3086}', Id), ('\u{3087}', '\u{3087}', Ns), ('\u{3088}', '\u{308d}', Id),
+    ('\u{308e}', '\u{308e}', Ns), ('\u{308f}', '\u{3094}', Id), ('\u{3095}', '\u{3096}', Ns),
+    ('\u{3099}', '\u{309a}', Cm), ('\u{309b}', '\u{309e}', Ns), ('\u{309f}', '\u{309f}', Id),
+    ('\u{30a0}', '\u{30a1}', Ns), ('\u{30a2}', '\u{30a2}', Id), ('\u{30a3}', '\u{30a3}', Ns),
+    ('\u{30a4}', '\u{30a4}', Id), ('\u{30a5}', '\u{30a5}', Ns), ('\u{30a6}', '\u{30a6}', Id),
+    ('\u{30a7}', '\u{30a7}', Ns), ('\u{30a8}', '\u{30a8}', Id), ('\u{30a9}', '\u{30a9}', Ns),
+    ('\u{30aa}', '\u{30c2}', Id), ('\u{30c3}', '\u{30c3}', Ns), ('\u{30c4}', '\u{30e2}', Id),
+    ('\u{30e3}', '\u{30e3}', Ns), ('\u{30e4}', '\u{30e4}', Id), ('\u{30e5}', '\u{30e5}', Ns),
+    ('\u{30e6}', '\u{30e6}', Id), ('\u{30e7}', '\u{30e7}', Ns), ('\u{30e8}', '\u{30ed}', Id),
+    ('\u{30ee}', '\u{30ee}', Ns), ('\u{30ef}', '\u{30f4}', Id), ('\u{30f5}', '\u{30f6}', Ns),
+    ('\u{30f7}', '\u{30fa}', Id), ('\u{30fb}', '\u{30fe}', Ns), ('\u{30ff}', '\u{30ff}', Id),
+    ('\u{3105}', '\u{312f}', Id), ('\u{3131}', '\u{318e}', Id), ('\u{3190}', '\u{31e5}', Id),
+    ('\u{31ef}', '\u{31ef}', Id), ('\u{31f0}', '\u{31ff}', Ns), ('\u{3200}', '\u{321e}', Id),
+    ('\u{3220}', '\u{3247}', Id), ('\u{3250}', '\u{4dbf}', Id), ('\u{4e00}', '\u{a014}', Id),
+    ('\u{a015}', '\u{a015}', Ns), ('\u{a016}', '\u{a48c}', Id), ('\u{a490}', '\u{a4c6}', Id),
+    ('\u{a4fe}', '\u{a4ff}', Ba), ('\u{a60d}', '\u{a60d}', Ba), ('\u{a60e}', '\u{a60e}', Ex),
+    ('\u{a60f}', '\u{a60f}', Ba), ('\u{a620}', '\u{a629}', Nu), ('\u{a66f}', '\u{a672}', Cm),
+    ('\u{a674}', '\u{a67d}', Cm), ('\u{a69e}', '\u{a69f}', Cm), ('\u{a6f0}', '\u{a6f1}', Cm),
+    ('\u{a6f3}', '\u{a6f7}', Ba), ('\u{a802}', '\u{a802}', Cm), ('\u{a806}', '\u{a806}', Cm),
+    ('\u{a80b}', '\u{a80b}', Cm), ('\u{a823}', '\u{a827}', Cm), ('\u{a82c}', '\u{a82c}', Cm),
+    ('\u{a838}', '\u{a838}', Po), ('\u{a874}', '\u{a875}', Bb), ('\u{a876}', '\u{a877}', Ex),
+    ('\u{a880}', '\u{a881}', Cm), ('\u{a8b4}', '\u{a8c5}', Cm), ('\u{a8ce}', '\u{a8cf}', Ba),
+    ('\u{a8d0}', '\u{a8d9}', Nu), ('\u{a8e0}', '\u{a8f1}', Cm), ('\u{a8fc}', '\u{a8fc}', Bb),
+    ('\u{a8ff}', '\u{a8ff}', Cm), ('\u{a900}', '\u{a909}', Nu), ('\u{a926}', '\u{a92d}', Cm),
+    ('\u{a92e}', '\u{a92f}', Ba), ('\u{a947}', '\u{a953}', Cm), ('\u{a960}', '\u{a97c}', Jl),
+    ('\u{a980}', '\u{a983}', Cm), ('\u{a984}', '\u{a9b2}', Ak), ('\u{a9b3}', '\u{a9bf}', Cm),
+    ('\u{a9c0}', '\u{a9c0}', Vi), ('\u{a9c1}', '\u{a9c6}', Id), ('\u{a9c7}', '\u{a9c9}', Ba),
+    ('\u{a9ca}', '\u{a9cd}', Id), ('\u{a9cf}', '\u{a9cf}', Ba), ('\u{a9d0}', '\u{a9d9}', As),
+    ('\u{a9de}', '\u{a9df}', Id), ('\u{a9e5}', '\u{a9e5}', Cm), ('\u{a9f0}', '\u{a9f9}', Nu),
+    ('\u{aa00}', '\u{aa28}', As), ('\u{aa29}', '\u{aa36}', Cm), ('\u{aa40}', '\u{aa42}', Ba),
+    ('\u{aa43}', '\u{aa43}', Cm), ('\u{aa44}', '\u{aa4b}', Ba), ('\u{aa4c}', '\u{aa4d}', Cm),
+    ('\u{aa50}', '\u{aa59}', As), ('\u{aa5c}', '\u{aa5c}', Id), ('\u{aa5d}', '\u{aa5f}', Ba),
+    ('\u{aa7b}', '\u{aa7d}', Cm), ('\u{aab0}', '\u{aab0}', Cm), ('\u{aab2}', '\u{aab4}', Cm),
+    ('\u{aab7}', '\u{aab8}', Cm), ('\u{aabe}', '\u{aabf}', Cm), ('\u{aac1}', '\u{aac1}', Cm),
+    ('\u{aaeb}', '\u{aaef}', Cm), ('\u{aaf0}', '\u{aaf1}', Ba), ('\u{aaf5}', '\u{aaf6}', Cm),
+    ('\u{abe3}', '\u{abea}', Cm), ('\u{abeb}', '\u{abeb}', Ba), ('\u{abec}', '\u{abed}', Cm),
+    ('\u{abf0}', '\u{abf9}', Nu), ('\u{ac00}', '\u{ac00}', H2), ('\u{ac01}', '\u{ac1b}', H3),
+    ('\u{ac1c}', '\u{ac1c}', H2), ('\u{ac1d}', '\u{ac37}', H3), ('\u{ac38}', '\u{ac38}', H2),
+    ('\u{ac39}', '\u{ac53}', H3), ('\u{ac54}', '\u{ac54}', H2), ('\u{ac55}', '\u{ac6f}', H3),
+    ('\u{ac70}', '\u{ac70}', H2), ('\u{ac71}', '\u{ac8b}', H3), ('\u{ac8c}', '\u{ac8c}', H2),
+    ('\u{ac8d}', '\u{aca7}', H3), ('\u{aca8}', '\u{aca8}', H2), ('\u{aca9}', '\u{acc3}', H3),
+    ('\u{acc4}', '\u{acc4}', H2), ('\u{acc5}', '\u{acdf}', H3), ('\u{ace0}', '\u{ace0}', H2),
+    ('\u{ace1}', '\u{acfb}', H3), ('\u{acfc}', '\u{acfc}', H2), ('\u{acfd}', '\u{ad17}', H3),
+    ('\u{ad18}', '\u{ad18}', H2), ('\u{ad19}', '\u{ad33}', H3), ('\u{ad34}', '\u{ad34}', H2),
+    ('\u{ad35}', '\u{ad4f}', H3), ('\u{ad50}', '\u{ad50}', H2), ('\u{ad51}', '\u{ad6b}', H3),
+    ('\u{ad6c}', '\u{ad6c}', H2), ('\u{ad6d}', '\u{ad87}', H3), ('\u{ad88}', '\u{ad88}', H2),
+    ('\u{ad89}', '\u{ada3}', H3), ('\u{ada4}', '\u{ada4}', H2), ('\u{ada5}', '\u{adbf}', H3),
+    ('\u{adc0}', '\u{adc0}', H2), ('\u{adc1}', '\u{addb}', H3), ('\u{addc}', '\u{addc}', H2),
+    ('\u{addd}', '\u{adf7}', H3), ('\u{adf8}', '\u{adf8}', H2), ('\u{adf9}', '\u{ae13}', H3),
+    ('\u{ae14}', '\u{ae14}', H2), ('\u{ae15}', '\u{ae2f}', H3), ('\u{ae30}', '\u{ae30}', H2),
+    ('\u{ae31}', '\u{ae4b}', H3), ('\u{ae4c}', '\u{ae4c}', H2), ('\u{ae4d}', '\u{ae67}', H3),
+    ('\u{ae68}', '\u{ae68}', H2), ('\u{ae69}', '\u{ae83}', H3), ('\u{ae84}', '\u{ae84}', H2),
+    ('\u{ae85}', '\u{ae9f}', H3), ('\u{aea0}', '\u{aea0}', H2), ('\u{aea1}', '\u{aebb}', H3),
+    ('\u{aebc}', '\u{aebc}', H2), ('\u{aebd}', '\u{aed7}', H3), ('\u{aed8}', '\u{aed8}', H2),
+    ('\u{aed9}', '\u{aef3}', H3), ('\u{aef4}', '\u{aef4}', H2), ('\u{aef5}', '\u{af0f}', H3),
+    ('\u{af10}', '\u{af10}', H2), ('\u{af11}', '\u{af2b}', H3), ('\u{af2c}', '\u{af2c}', H2),
+    ('\u{af2d}', '\u{af47}', H3), ('\u{af48}', '\u{af48}', H2), ('\u{af49}', '\u{af63}', H3),
+    ('\u{af64}', '\u{af64}', H2), ('\u{af65}', '\u{af7f}', H3), ('\u{af80}', '\u{af80}', H2),
+    ('\u{af81}', '\u{af9b}', H3), ('\u{af9c}', '\u{af9c}', H2), ('\u{af9d}', '\u{afb7}', H3),
+    ('\u{afb8}', '\u{afb8}', H2), ('\u{afb9}', '\u{afd3}', H3), ('\u{afd4}', '\u{afd4}', H2),
+    ('\u{afd5}', '\u{afef}', H3), ('\u{aff0}', '\u{aff0}', H2), ('\u{aff1}', '\u{b00b}', H3),
+    ('\u{b00c}', '\u{b00c}', H2), ('\u{b00d}', '\u{b027}', H3), ('\u{b028}', '\u{b028}', H2),
+    ('\u{b029}', '\u{b043}', H3), ('\u{b044}', '\u{b044}', H2), ('\u{b045}', '\u{b05f}', H3),
+    ('\u{b060}', '\u{b060}', H2), ('\u{b061}', '\u{b07b}', H3), ('\u{b07c}', '\u{b07c}', H2),
+    ('\u{b07d}', '\u{b097}', H3), ('\u{b098}', '\u{b098}', H2), ('\u{b099}', '\u{b0b3}', H3),
+    ('\u{b0b4}', '\u{b0b4}', H2), ('\u{b0b5}', '\u{b0cf}', H3), ('\u{b0d0}', '\u{b0d0}', H2),
+    ('\u{b0d1}', '\u{b0eb}', H3), ('\u{b0ec}', '\u{b0ec}', H2), ('\u{b0ed}', '\u{b107}', H3),
+    ('\u{b108}', '\u{b108}', H2), ('\u{b109}', '\u{b123}', H3), ('\u{b124}', '\u{b124}', H2),
+    ('\u{b125}', '\u{b13f}', H3), ('\u{b140}', '\u{b140}', H2), ('\u{b141}', '\u{b15b}', H3),
+    ('\u{b15c}', '\u{b15c}', H2), ('\u{b15d}', '\u{b177}', H3), ('\u{b178}', '\u{b178}', H2),
+    ('\u{b179}', '\u{b193}', H3), ('\u{b194}', '\u{b194}', H2), ('\u{b195}', '\u{b1af}', H3),
+    ('\u{b1b0}', '\u{b1b0}', H2), ('\u{b1b1}', '\u{b1cb}', H3), ('\u{b1cc}', '\u{b1cc}', H2),
+    ('\u{b1cd}', '\u{b1e7}', H3), ('\u{b1e8}', '\u{b1e8}', H2), ('\u{b1e9}', '\u{b203}', H3),
+    ('\u{b204}', '\u{b204}', H2), ('\u{b205}', '\u{b21f}', H3), ('\u{b220}', '\u{b220}', H2),
+    ('\u{b221}', '\u{b23b}', H3), ('\u{b23c}', '\u{b23c}', H2), ('\u{b23d}', '\u{b257}', H3),
+    ('\u{b258}', '\u{b258}', H2), ('\u{b259}', '\u{b273}', H3), ('\u{b274}', '\u{b274}', H2),
+    ('\u{b275}', '\u{b28f}', H3), ('\u{b290}', '\u{b290}', H2), ('\u{b291}', '\u{b2ab}', H3),
+    ('\u{b2ac}', '\u{b2ac}', H2), ('\u{b2ad}', '\u{b2c7}', H3), ('\u{b2c8}', '\u{b2c8}', H2),
+    ('\u{b2c9}', '\u{b2e3}', H3), ('\u{b2e4}', '\u{b2e4}', H2), ('\u{b2e5}', '\u{b2ff}', H3),
+    ('\u{b300}', '\u{b300}', H2), ('\u{b301}', '\u{b31b}', H3), ('\u{b31c}', '\u{b31c}', H2),
+    ('\u{b31d}', '\u{b337}', H3), ('\u{b338}', '\u{b338}', H2), ('\u{b339}', '\u{b353}', H3),
+    ('\u{b354}', '\u{b354}', H2), ('\u{b355}', '\u{b36f}', H3), ('\u{b370}', '\u{b370}', H2),
+    ('\u{b371}', '\u{b38b}', H3), ('\u{b38c}', '\u{b38c}', H2), ('\u{b38d}', '\u{b3a7}', H3),
+    ('\u{b3a8}', '\u{b3a8}', H2), ('\u{b3a9}', '\u{b3c3}', H3), ('\u{b3c4}', '\u{b3c4}', H2),
+    ('\u{b3c5}', '\u{b3df}', H3), ('\u{b3e0}', '\u{b3e0}', H2), ('\u{b3e1}', '\u{b3fb}', H3),
+    ('\u{b3fc}', '\u{b3fc}', H2), ('\u{b3fd}', '\u{b417}', H3), ('\u{b418}', '\u{b418}', H2),
+    ('\u{b419}', '\u{b433}', H3), ('\u{b434}', '\u{b434}', H2), ('\u{b435}', '\u{b44f}', H3),
+    ('\u{b450}', '\u{b450}', H2), ('\u{b451}', '\u{b46b}', H3), ('\u{b46c}', '\u{b46c}', H2),
+    ('\u{b46d}', '\u{b487}', H3), ('\u{b488}', '\u{b488}', H2), ('\u{b489}', '\u{b4a3}', H3),
+    ('\u{b4a4}', '\u{b4a4}', H2), ('\u{b4a5}', '\u{b4bf}', H3), ('\u{b4c0}', '\u{b4c0}', H2),
+    ('\u{b4c1}', '\u{b4db}', H3), ('\u{b4dc}', '\u{b4dc}', H2), ('\u{b4dd}', '\u{b4f7}', H3),
+    ('\u{b4f8}', '\u{b4f8}', H2), ('\u{b4f9}', '\u{b513}', H3), ('\u{b514}', '\u{b514}', H2),
+    ('\u{b515}', '\u{b52f}', H3), ('\u{b530}', '\u{b530}', H2), ('\u{b531}', '\u{b54b}', H3),
+    ('\u{b54c}', '\u{b54c}', H2), ('\u{b54d}', '\u{b567}', H3), ('\u{b568}', '\u{b568}', H2),
+    ('\u{b569}', '\u{b583}', H3), ('\u{b584}', '\u{b584}', H2), ('\u{b585}', '\u{b59f}', H3),
+    ('\u{b5a0}', '\u{b5a0}', H2), ('\u{b5a1}', '\u{b5bb}', H3), ('\u{b5bc}', '\u{b5bc}', H2),
+    ('\u{b5bd}', '\u{b5d7}', H3), ('\u{b5d8}', '\u{b5d8}', H2), ('\u{b5d9}', '\u{b5f3}', H3),
+    ('\u{b5f4}', '\u{b5f4}', H2), ('\u{b5f5}', '\u{b60f}', H3), ('\u{b610}', '\u{b610}', H2),
+    ('\u{b611}', '\u{b62b}', H3), ('\u{b62c}', '\u{b62c}', H2), ('\u{b62d}', '\u{b647}', H3),
+    ('\u{b648}', '\u{b648}', H2), ('\u{b649}', '\u{b663}', H3), ('\u{b664}', '\u{b664}', H2),
+    ('\u{b665}', '\u{b67f}', H3), ('\u{b680}', '\u{b680}', H2), ('\u{b681}', '\u{b69b}', H3),
+    ('\u{b69c}', '\u{b69c}', H2), ('\u{b69d}', '\u{b6b7}', H3), ('\u{b6b8}', '\u{b6b8}', H2),
+    ('\u{b6b9}', '\u{b6d3}', H3), ('\u{b6d4}', '\u{b6d4}', H2), ('\u{b6d5}', '\u{b6ef}', H3),
+    ('\u{b6f0}', '\u{b6f0}', H2), ('\u{b6f1}', '\u{b70b}', H3), ('\u{b70c}', '\u{b70c}', H2),
+    ('\u{b70d}', '\u{b727}', H3), ('\u{b728}', '\u{b728}', H2), ('\u{b729}', '\u{b743}', H3),
+    ('\u{b744}', '\u{b744}', H2), ('\u{b745}', '\u{b75f}', H3), ('\u{b760}', '\u{b760}', H2),
+    ('\u{b761}', '\u{b77b}', H3), ('\u{b77c}', '\u{b77c}', H2), ('\u{b77d}', '\u{b797}', H3),
+    ('\u{b798}', '\u{b798}', H2), ('\u{b799}', '\u{b7b3}', H3), ('\u{b7b4}', '\u{b7b4}', H2),
+    ('\u{b7b5}', '\u{b7cf}', H3), ('\u{b7d0}', '\u{b7d0}', H2), ('\u{b7d1}', '\u{b7eb}', H3),
+    ('\u{b7ec}', '\u{b7ec}', H2), ('\u{b7ed}', '\u{b807}', H3), ('\u{b808}', '\u{b808}', H2),
+    ('\u{b809}', '\u{b823}', H3), ('\u{b824}', '\u{b824}', H2), ('\u{b825}', '\u{b83f}', H3),
+    ('\u{b840}', '\u{b840}', H2), ('\u{b841}', '\u{b85b}', H3), ('\u{b85c}', '\u{b85c}', H2),
+    ('\u{b85d}', '\u{b877}', H3), ('\u{b878}', '\u{b878}', H2), ('\u{b879}', '\u{b893}', H3),
+    ('\u{b894}', '\u{b894}', H2), ('\u{b895}', '\u{b8af}', H3), ('\u{b8b0}', '\u{b8b0}', H2),
+    ('\u{b8b1}', '\u{b8cb}', H3), ('\u{b8cc}', '\u{b8cc}', H2), ('\u{b8cd}', '\u{b8e7}', H3),
+    ('\u{b8e8}', '\u{b8e8}', H2), ('\u{b8e9}', '\u{b903}', H3), ('\u{b904}', '\u{b904}', H2),
+    ('\u{b905}', '\u{b91f}', H3), ('\u{b920}', '\u{b920}', H2), ('\u{b921}', '\u{b93b}', H3),
+    ('\u{b93c}', '\u{b93c}', H2), ('\u{b93d}', '\u{b957}', H3), ('\u{b958}', '\u{b958}', H2),
+    ('\u{b959}', '\u{b973}', H3), ('\u{b974}', '\u{b974}', H2), ('\u{b975}', '\u{b98f}', H3),
+    ('\u{b990}', '\u{b990}', H2), ('\u{b991}', '\u{b9ab}', H3), ('\u{b9ac}', '\u{b9ac}', H2),
+    ('\u{b9ad}', '\u{b9c7}', H3), ('\u{b9c8}', '\u{b9c8}', H2), ('\u{b9c9}', '\u{b9e3}', H3),
+    ('\u{b9e4}', '\u{b9e4}', H2), ('\u{b9e5}', '\u{b9ff}', H3), ('\u{ba00}', '\u{ba00}', H2),
+    ('\u{ba01}', '\u{ba1b}', H3), ('\u{ba1c}', '\u{ba1c}', H2), ('\u{ba1d}', '\u{ba37}', H3),
+    ('\u{ba38}', '\u{ba38}', H2), ('\u{ba39}', '\u{ba53}', H3), ('\u{ba54}', '\u{ba54}', H2),
+    ('\u{ba55}', '\u{ba6f}', H3), ('\u{ba70}', '\u{ba70}', H2), ('\u{ba71}', '\u{ba8b}', H3),
+    ('\u{ba8c}', '\u{ba8c}', H2), ('\u{ba8d}', '\u{baa7}', H3), ('\u{baa8}', '\u{baa8}', H2),
+    ('\u{baa9}', '\u{bac3}', H3), ('\u{bac4}', '\u{bac4}', H2), ('\u{bac5}', '\u{badf}', H3),
+    ('\u{bae0}', '\u{bae0}', H2), ('\u{bae1}', '\u{bafb}', H3), ('\u{bafc}', '\u{bafc}', H2),
+    ('\u{bafd}', '\u{bb17}', H3), ('\u{bb18}', '\u{bb18}', H2), ('\u{bb19}', '\u{bb33}', H3),
+    ('\u{bb34}', '\u{bb34}', H2), ('\u{bb35}', '\u{bb4f}', H3), ('\u{bb50}', '\u{bb50}', H2),
+    ('\u{bb51}', '\u{bb6b}', H3), ('\u{bb6c}', '\u{bb6c}', H2), ('\u{bb6d}', '\u{bb87}', H3),
+    ('\u{bb88}', '\u{bb88}', H2), ('\u{bb89}', '\u{bba3}', H3), ('\u{bba4}', '\u{bba4}', H2),
+    ('\u{bba5}', '\u{bbbf}', H3), ('\u{bbc0}', '\u{bbc0}', H2), ('\u{bbc1}', '\u{bbdb}', H3),
+    ('\u{bbdc}', '\u{bbdc}', H2), ('\u{bbdd}', '\u{bbf7}', H3), ('\u{bbf8}', '\u{bbf8}', H2),
+    ('\u{bbf9}', '\u{bc13}', H3), ('\u{bc14}', '\u{bc14}', H2), ('\u{bc15}', '\u{bc2f}', H3),
+    ('\u{bc30}', '\u{bc30}', H2), ('\u{bc31}', '\u{bc4b}', H3), ('\u{bc4c}', '\u{bc4c}', H2),
+    ('\u{bc4d}', '\u{bc67}', H3), ('\u{bc68}', '\u{bc68}', H2), ('\u{bc69}', '\u{bc83}', H3),
+    ('\u{bc84}', '\u{bc84}', H2), ('\u{bc85}', '\u{bc9f}', H3), ('\u{bca0}', '\u{bca0}', H2),
+    ('\u{bca1}', '\u{bcbb}', H3), ('\u{bcbc}', '\u{bcbc}', H2), ('\u{bcbd}', '\u{bcd7}', H3),
+    ('\u{bcd8}', '\u{bcd8}', H2), ('\u{bcd9}', '\u{bcf3}', H3), ('\u{bcf4}', '\u{bcf4}', H2),
+    ('\u{bcf5}', '\u{bd0f}', H3), ('\u{bd10}', '\u{bd10}', H2), ('\u{bd11}', '\u{bd2b}', H3),
+    ('\u{bd2c}', '\u{bd2c}', H2), ('\u{bd2d}', '\u{bd47}', H3), ('\u{bd48}', '\u{bd48}', H2),
+    ('\u{bd49}', '\u{bd63}', H3), ('\u{bd64}', '\u{bd64}', H2), ('\u{bd65}', '\u{bd7f}', H3),
+    ('\u{bd80}', '\u{bd80}', H2), ('\u{bd81}', '\u{bd9b}', H3), ('\u{bd9c}', '\u{bd9c}', H2),
+    ('\u{bd9d}', '\u{bdb7}', H3), ('\u{bdb8}', '\u{bdb8}', H2), ('\u{bdb9}', '\u{bdd3}', H3),
+    ('\u{bdd4}', '\u{bdd4}', H2), ('\u{bdd5}', '\u{bdef}', H3), ('\u{bdf0}', '\u{bdf0}', H2),
+    ('\u{bdf1}', '\u{be0b}', H3), ('\u{be0c}', '\u{be0c}', H2), ('\u{be0d}', '\u{be27}', H3),
+    ('\u{be28}', '\u{be28}', H2), ('\u{be29}', '\u{be43}', H3), ('\u{be44}', '\u{be44}', H2),
+    ('\u{be45}', '\u{be5f}', H3), ('\u{be60}', '\u{be60}', H2), ('\u{be61}', '\u{be7b}', H3),
+    ('\u{be7c}', '\u{be7c}', H2), ('\u{be7d}', '\u{be97}', H3), ('\u{be98}', '\u{be98}', H2),
+    ('\u{be99}', '\u{beb3}', H3), ('\u{beb4}', '\u{beb4}', H2), ('\u{beb5}', '\u{becf}', H3),
+    ('\u{bed0}', '\u{bed0}', H2), ('\u{bed1}', '\u{beeb}', H3), ('\u{beec}', '\u{beec}', H2),
+    ('\u{beed}', '\u{bf07}', H3), ('\u{bf08}', '\u{bf08}', H2), ('\u{bf09}', '\u{bf23}', H3),
+    ('\u{bf24}', '\u{bf24}', H2), ('\u{bf25}', '\u{bf3f}', H3), ('\u{bf40}', '\u{bf40}', H2),
+    ('\u{bf41}', '\u{bf5b}', H3), ('\u{bf5c}', '\u{bf5c}', H2), ('\u{bf5d}', '\u{bf77}', H3),
+    ('\u{bf78}', '\u{bf78}', H2), ('\u{bf79}', '\u{bf93}', H3), ('\u{bf94}', '\u{bf94}', H2),
+    ('\u{bf95}', '\u{bfaf}', H3), ('\u{bfb0}', '\u{bfb0}', H2), ('\u{bfb1}', '\u{bfcb}', H3),
+    ('\u{bfcc}', '\u{bfcc}', H2), ('\u{bfcd}', '\u{bfe7}', H3), ('\u{bfe8}', '\u{bfe8}', H2),
+    ('\u{bfe9}', '\u{c003}', H3), ('\u{c004}', '\u{c004}', H2), ('\u{c005}', '\u{c01f}', H3),
+    ('\u{c020}', '\u{c020}', H2), ('\u{c021}', '\u{c03b}', H3), ('\u{c03c}', '\u{c03c}', H2),
+    ('\u{c03d}', '\u{c057}', H3), ('\u{c058}', '\u{c058}', H2), ('\u{c059}', '\u{c073}', H3),
+    ('\u{c074}', '\u{c074}', H2), ('\u{c075}', '\u{c08f}', H3), ('\u{c090}', '\u{c090}', H2),
+    ('\u{c091}', '\u{c0ab}', H3), ('\u{c0ac}', '\u{c0ac}', H2), ('\u{c0ad}', '\u{c0c7}', H3),
+    ('\u{c0c8}', '\u{c0c8}', H2), ('\u{c0c9}', '\u{c0e3}', H3), ('\u{c0e4}', '\u{c0e4}', H2),
+    ('\u{c0e5}', '\u{c0ff}', H3), ('\u{c100}', '\u{c100}', H2), ('\u{c101}', '\u{c11b}', H3),
+    ('\u{c11c}', '\u{c11c}', H2), ('\u{c11d}', '\u{c137}', H3), ('\u{c138}', '\u{c138}', H2),
+    ('\u{c139}', '\u{c153}', H3), ('\u{c154}', '\u{c154}', H2), ('\u{c155}', '\u{c16f}', H3),
+    ('\u{c170}', '\u{c170}', H2), ('\u{c171}', '\u{c18b}', H3), ('\u{c18c}', '\u{c18c}', H2),
+    ('\u{c18d}', '\u{c1a7}', H3), ('\u{c1a8}', '\u{c1a8}', H2), ('\u{c1a9}', '\u{c1c3}', H3),
+    ('\u{c1c4}', '\u{c1c4}', H2), ('\u{c1c5}', '\u{c1df}', H3), ('\u{c1e0}', '\u{c1e0}', H2),
+    ('\u{c1e1}', '\u{c1fb}', H3), ('\u{c1fc}', '\u{c1fc}', H2), ('\u{c1fd}', '\u{c217}', H3),
+    ('\u{c218}', '\u{c218}', H2), ('\u{c219}', '\u{c233}', H3), ('\u{c234}', '\u{c234}', H2),
+    ('\u{c235}', '\u{c24f}', H3), ('\u{c250}', '\u{c250}', H2), ('\u{c251}', '\u{c26b}', H3),
+    ('\u{c26c}', '\u{c26c}', H2), ('\u{c26d}', '\u{c287}', H3), ('\u{c288}', '\u{c288}', H2),
+    ('\u{c289}', '\u{c2a3}', H3), ('\u{c2a4}', '\u{c2a4}', H2), ('\u{c2a5}', '\u{c2bf}', H3),
+    ('\u{c2c0}', '\u{c2c0}', H2), ('\u{c2c1}', '\u{c2db}', H3), ('\u{c2dc}', '\u{c2dc}', H2),
+    ('\u{c2dd}', '\u{c2f7}', H3), ('\u{c2f8}', '\u{c2f8}', H2), ('\u{c2f9}', '\u{c313}', H3),
+    ('\u{c314}', '\u{c314}', H2), ('\u{c315}', '\u{c32f}', H3), ('\u{c330}', '\u{c330}', H2),
+    ('\u{c331}', '\u{c34b}', H3), ('\u{c34c}', '\u{c34c}', H2), ('\u{c34d}', '\u{c367}', H3),
+    ('\u{c368}', '\u{c368}', H2), ('\u{c369}', '\u{c383}', H3), ('\u{c384}', '\u{c384}', H2),
+    ('\u{c385}', '\u{c39f}', H3), ('\u{c3a0}', '\u{c3a0}', H2), ('\u{c3a1}', '\u{c3bb}', H3),
+    ('\u{c3bc}', '\u{c3bc}', H2), ('\u{c3bd}', '\u{c3d7}', H3), ('\u{c3d8}', '\u{c3d8}', H2),
+    ('\u{c3d9}', '\u{c3f3}', H3), ('\u{c3f4}', '\u{c3f4}', H2), ('\u{c3f5}', '\u{c40f}', H3),
+    ('\u{c410}', '\u{c410}', H2), ('\u{c411}', '\u{c42b}', H3), ('\u{c42c}', '\u{c42c}', H2),
+    ('\u{c42d}', '\u{c447}', H3), ('\u{c448}', '\u{c448}', H2), ('\u{c449}', '\u{c463}', H3),
+    ('\u{c464}', '\u{c464}', H2), ('\u{c465}', '\u{c47f}', H3), ('\u{c480}', '\u{c480}', H2),
+    ('\u{c481}', '\u{c49b}', H3), ('\u{c49c}', '\u{c49c}', H2), ('\u{c49d}', '\u{c4b7}', H3),
+    ('\u{c4b8}', '\u{c4b8}', H2), ('\u{c4b9}', '\u{c4d3}', H3), ('\u{c4d4}', '\u{c4d4}', H2),
+    ('\u{c4d5}', '\u{c4ef}', H3), ('\u{c4f0}', '\u{c4f0}', H2), ('\u{c4f1}', '\u{c50b}', H3),
+    ('\u{c50c}', '\u{c50c}', H2), ('\u{c50d}', '\u{c527}', H3), ('\u{c528}', '\u{c528}', H2),
+    ('\u{c529}', '\u{c543}', H3), ('\u{c544}', '\u{c544}', H2), ('\u{c545}', '\u{c55f}', H3),
+    ('\u{c560}', '\u{c560}', H2), ('\u{c561}', '\u{c57b}', H3), ('\u{c57c}', '\u{c57c}', H2),
+    ('\u{c57d}', '\u{c597}', H3), ('\u{c598}', '\u{c598}', H2), ('\u{c599}', '\u{c5b3}', H3),
+    ('\u{c5b4}', '\u{c5b4}', H2), ('\u{c5b5}', '\u{c5cf}', H3), ('\u{c5d0}', '\u{c5d0}', H2),
+    ('\u{c5d1}', '\u{c5eb}', H3), ('\u{c5ec}', '\u{c5ec}', H2), ('\u{c5ed}', '\u{c607}', H3),
+    ('\u{c608}', '\u{c608}', H2), ('\u{c609}', '\u{c623}', H3), ('\u{c624}', '\u{c624}', H2),
+    ('\u{c625}', '\u{c63f}', H3), ('\u{c640}', '\u{c640}', H2), ('\u{c641}', '\u{c65b}', H3),
+    ('\u{c65c}', '\u{c65c}', H2), ('\u{c65d}', '\u{c677}', H3), ('\u{c678}', '\u{c678}', H2),
+    ('\u{c679}', '\u{c693}', H3), ('\u{c694}', '\u{c694}', H2), ('\u{c695}', '\u{c6af}', H3),
+    ('\u{c6b0}', '\u{c6b0}', H2), ('\u{c6b1}', '\u{c6cb}', H3), ('\u{c6cc}', '\u{c6cc}', H2),
+    ('\u{c6cd}', '\u{c6e7}', H3), ('\u{c6e8}', '\u{c6e8}', H2), ('\u{c6e9}', '\u{c703}', H3),
+    ('\u{c704}', '\u{c704}', H2), ('\u{c705}', '\u{c71f}', H3), ('\u{c720}', '\u{c720}', H2),
+    ('\u{c721}', '\u{c73b}', H3), ('\u{c73c}', '\u{c73c}', H2), ('\u{c73d}', '\u{c757}', H3),
+    ('\u{c758}', '\u{c758}', H2), ('\u{c759}', '\u{c773}', H3), ('\u{c774}', '\u{c774}', H2),
+    ('\u{c775}', '\u{c78f}', H3), ('\u{c790}', '\u{c790}', H2), ('\u{c791}', '\u{c7ab}', H3),
+    ('\u{c7ac}', '\u{c7ac}', H2), ('\u{c7ad}', '\u{c7c7}', H3), ('\u{c7c8}', '\u{c7c8}', H2),
+    ('\u{c7c9}', '\u{c7e3}', H3), ('\u{c7e4}', '\u{c7e4}', H2), ('\u{c7e5}', '\u{c7ff}', H3),
+    ('\u{c800}', '\u{c800}', H2), ('\u{c801}', '\u{c81b}', H3), ('\u{c81c}', '\u{c81c}', H2),
+    ('\u{c81d}', '\u{c837}', H3), ('\u{c838}', '\u{c838}', H2), ('\u{c839}', '\u{c853}', H3),
+    ('\u{c854}', '\u{c854}', H2), ('\u{c855}', '\u{c86f}', H3), ('\u{c870}', '\u{c870}', H2),
+    ('\u{c871}', '\u{c88b}', H3), ('\u{c88c}', '\u{c88c}', H2), ('\u{c88d}', '\u{c8a7}', H3),
+    ('\u{c8a8}', '\u{c8a8}', H2), ('\u{c8a9}', '\u{c8c3}', H3), ('\u{c8c4}', '\u{c8c4}', H2),
+    ('\u{c8c5}', '\u{c8df}', H3), ('\u{c8e0}', '\u{c8e0}', H2), ('\u{c8e1}', '\u{c8fb}', H3),
+    ('\u{c8fc}', '\u{c8fc}', H2), ('\u{c8fd}', '\u{c917}', H3), ('\u{c918}', '\u{c918}', H2),
+    ('\u{c919}', '\u{c933}', H3), ('\u{c934}', '\u{c934}', H2), ('\u{c935}', '\u{c94f}', H3),
+    ('\u{c950}', '\u{c950}', H2), ('\u{c951}', '\u{c96b}', H3), ('\u{c96c}', '\u{c96c}', H2),
+    ('\u{c96d}', '\u{c987}', H3), ('\u{c988}', '\u{c988}', H2), ('\u{c989}', '\u{c9a3}', H3),
+    ('\u{c9a4}', '\u{c9a4}', H2), ('\u{c9a5}', '\u{c9bf}', H3), ('\u{c9c0}', '\u{c9c0}', H2),
+    ('\u{c9c1}', '\u{c9db}', H3), ('\u{c9dc}', '\u{c9dc}', H2), ('\u{c9dd}', '\u{c9f7}', H3),
+    ('\u{c9f8}', '\u{c9f8}', H2), ('\u{c9f9}', '\u{ca13}', H3), ('\u{ca14}', '\u{ca14}', H2),
+    ('\u{ca15}', '\u{ca2f}', H3), ('\u{ca30}', '\u{ca30}', H2), ('\u{ca31}', '\u{ca4b}', H3),
+    ('\u{ca4c}', '\u{ca4c}', H2), ('\u{ca4d}', '\u{ca67}', H3), ('\u{ca68}', '\u{ca68}', H2),
+    ('\u{ca69}', '\u{ca83}', H3), ('\u{ca84}', '\u{ca84}', H2), ('\u{ca85}', '\u{ca9f}', H3),
+    ('\u{caa0}', '\u{caa0}', H2), ('\u{caa1}', '\u{cabb}', H3), ('\u{cabc}', '\u{cabc}', H2),
+    ('\u{cabd}', '\u{cad7}', H3), ('\u{cad8}', '\u{cad8}', H2), ('\u{cad9}', '\u{caf3}', H3),
+    ('\u{caf4}', '\u{caf4}', H2), ('\u{caf5}', '\u{cb0f}', H3), ('\u{cb10}', '\u{cb10}', H2),
+    ('\u{cb11}', '\u{cb2b}', H3), ('\u{cb2c}', '\u{cb2c}', H2), ('\u{cb2d}', '\u{cb47}', H3),
+    ('\u{cb48}', '\u{cb48}', H2), ('\u{cb49}', '\u{cb63}', H3), ('\u{cb64}', '\u{cb64}', H2),
+    ('\u{cb65}', '\u{cb7f}', H3), ('\u{cb80}', '\u{cb80}', H2), ('\u{cb81}', '\u{cb9b}', H3),
+    ('\u{cb9c}', '\u{cb9c}', H2), ('\u{cb9d}', '\u{cbb7}', H3), ('\u{cbb8}', '\u{cbb8}', H2),
+    ('\u{cbb9}', '\u{cbd3}', H3), ('\u{cbd4}', '\u{cbd4}', H2), ('\u{cbd5}', '\u{cbef}', H3),
+    ('\u{cbf0}', '\u{cbf0}', H2), ('\u{cbf1}', '\u{cc0b}', H3), ('\u{cc0c}', '\u{cc0c}', H2),
+    ('\u{cc0d}', '\u{cc27}', H3), ('\u{cc28}', '\u{cc28}', H2), ('\u{cc29}', '\u{cc43}', H3),
+    ('\u{cc44}', '\u{cc44}', H2), ('\u{cc45}', '\u{cc5f}', H3), ('\u{cc60}', '\u{cc60}', H2),
+    ('\u{cc61}', '\u{cc7b}', H3), ('\u{cc7c}', '\u{cc7c}', H2), ('\u{cc7d}', '\u{cc97}', H3),
+    ('\u{cc98}', '\u{cc98}', H2), ('\u{cc99}', '\u{ccb3}', H3), ('\u{ccb4}', '\u{ccb4}', H2),
+    ('\u{ccb5}', '\u{cccf}', H3), ('\u{ccd0}', '\u{ccd0}', H2), ('\u{ccd1}', '\u{cceb}', H3),
+    ('\u{ccec}', '\u{ccec}', H2), ('\u{cced}', '\u{cd07}', H3), ('\u{cd08}', '\u{cd08}', H2),
+    ('\u{cd09}', '\u{cd23}', H3), ('\u{cd24}', '\u{cd24}', H2), ('\u{cd25}', '\u{cd3f}', H3),
+    ('\u{cd40}', '\u{cd40}', H2), ('\u{cd41}', '\u{cd5b}', H3), ('\u{cd5c}', '\u{cd5c}', H2),
+    ('\u{cd5d}', '\u{cd77}', H3), ('\u{cd78}', '\u{cd78}', H2), ('\u{cd79}', '\u{cd93}', H3),
+    ('\u{cd94}', '\u{cd94}', H2), ('\u{cd95}', '\u{cdaf}', H3), ('\u{cdb0}', '\u{cdb0}', H2),
+    ('\u{cdb1}', '\u{cdcb}', H3), ('\u{cdcc}', '\u{cdcc}', H2), ('\u{cdcd}', '\u{cde7}', H3),
+    ('\u{cde8}', '\u{cde8}', H2), ('\u{cde9}', '\u{ce03}', H3), ('\u{ce04}', '\u{ce04}', H2),
+    ('\u{ce05}', '\u{ce1f}', H3), ('\u{ce20}', '\u{ce20}', H2), ('\u{ce21}', '\u{ce3b}', H3),
+    ('\u{ce3c}', '\u{ce3c}', H2), ('\u{ce3d}', '\u{ce57}', H3), ('\u{ce58}', '\u{ce58}', H2),
+    ('\u{ce59}', '\u{ce73}', H3), ('\u{ce74}', '\u{ce74}', H2), ('\u{ce75}', '\u{ce8f}', H3),
+    ('\u{ce90}', '\u{ce90}', H2), ('\u{ce91}', '\u{ceab}', H3), ('\u{ceac}', '\u{ceac}', H2),
+    ('\u{cead}', '\u{cec7}', H3), ('\u{cec8}', '\u{cec8}', H2), ('\u{cec9}', '\u{cee3}', H3),
+    ('\u{cee4}', '\u{cee4}', H2), ('\u{cee5}', '\u{ceff}', H3), ('\u{cf00}', '\u{cf00}', H2),
+    ('\u{cf01}', '\u{cf1b}', H3), ('\u{cf1c}', '\u{cf1c}', H2), ('\u{cf1d}', '\u{cf37}', H3),
+    ('\u{cf38}', '\u{cf38}', H2), ('\u{cf39}', '\u{cf53}', H3), ('\u{cf54}', '\u{cf54}', H2),
+    ('\u{cf55}', '\u{cf6f}', H3), ('\u{cf70}', '\u{cf70}', H2), ('\u{cf71}', '\u{cf8b}', H3),
+    ('\u{cf8c}', '\u{cf8c}', H2), ('\u{cf8d}', '\u{cfa7}', H3), ('\u{cfa8}', '\u{cfa8}', H2),
+    ('\u{cfa9}', '\u{cfc3}', H3), ('\u{cfc4}', '\u{cfc4}', H2), ('\u{cfc5}', '\u{cfdf}', H3),
+    ('\u{cfe0}', '\u{cfe0}', H2), ('\u{cfe1}', '\u{cffb}', H3), ('\u{cffc}', '\u{cffc}', H2),
+    ('\u{cffd}', '\u{d017}', H3), ('\u{d018}', '\u{d018}', H2), ('\u{d019}', '\u{d033}', H3),
+    ('\u{d034}', '\u{d034}', H2), ('\u{d035}', '\u{d04f}', H3), ('\u{d050}', '\u{d050}', H2),
+    ('\u{d051}', '\u{d06b}', H3), ('\u{d06c}', '\u{d06c}', H2), ('\u{d06d}', '\u{d087}', H3),
+    ('\u{d088}', '\u{d088}', H2), ('\u{d089}', '\u{d0a3}', H3), ('\u{d0a4}', '\u{d0a4}', H2),
+    ('\u{d0a5}', '\u{d0bf}', H3), ('\u{d0c0}', '\u{d0c0}', H2), ('\u{d0c1}', '\u{d0db}', H3),
+    ('\u{d0dc}', '\u{d0dc}', H2), ('\u{d0dd}', '\u{d0f7}', H3), ('\u{d0f8}', '\u{d0f8}', H2),
+    ('\u{d0f9}', '\u{d113}', H3), ('\u{d114}', '\u{d114}', H2), ('\u{d115}', '\u{d12f}', H3),
+    ('\u{d130}', '\u{d130}', H2), ('\u{d131}', '\u{d14b}', H3), ('\u{d14c}', '\u{d14c}', H2),
+    ('\u{d14d}', '\u{d167}', H3), ('\u{d168}', '\u{d168}', H2), ('\u{d169}', '\u{d183}', H3),
+    ('\u{d184}', '\u{d184}', H2), ('\u{d185}', '\u{d19f}', H3), ('\u{d1a0}', '\u{d1a0}', H2),
+    ('\u{d1a1}', '\u{d1bb}', H3), ('\u{d1bc}', '\u{d1bc}', H2), ('\u{d1bd}', '\u{d1d7}', H3),
+    ('\u{d1d8}', '\u{d1d8}', H2), ('\u{d1d9}', '\u{d1f3}', H3), ('\u{d1f4}', '\u{d1f4}', H2),
+    ('\u{d1f5}', '\u{d20f}', H3), ('\u{d210}', '\u{d210}', H2), ('\u{d211}', '\u{d22b}', H3),
+    ('\u{d22c}', '\u{d22c}', H2), ('\u{d22d}', '\u{d247}', H3), ('\u{d248}', '\u{d248}', H2),
+    ('\u{d249}', '\u{d263}', H3), ('\u{d264}', '\u{d264}', H2), ('\u{d265}', '\u{d27f}', H3),
+    ('\u{d280}', '\u{d280}', H2), ('\u{d281}', '\u{d29b}', H3), ('\u{d29c}', '\u{d29c}', H2),
+    ('\u{d29d}', '\u{d2b7}', H3), ('\u{d2b8}', '\u{d2b8}', H2), ('\u{d2b9}', '\u{d2d3}', H3),
+    ('\u{d2d4}', '\u{d2d4}', H2), ('\u{d2d5}', '\u{d2ef}', H3), ('\u{d2f0}', '\u{d2f0}', H2),
+    ('\u{d2f1}', '\u{d30b}', H3), ('\u{d30c}', '\u{d30c}', H2), ('\u{d30d}', '\u{d327}', H3),
+    ('\u{d328}', '\u{d328}', H2), ('\u{d329}', '\u{d343}', H3), ('\u{d344}', '\u{d344}', H2),
+    ('\u{d345}', '\u{d35f}', H3), ('\u{d360}', '\u{d360}', H2), ('\u{d361}', '\u{d37b}', H3),
+    ('\u{d37c}', '\u{d37c}', H2), ('\u{d37d}', '\u{d397}', H3), ('\u{d398}', '\u{d398}', H2),
+    ('\u{d399}', '\u{d3b3}', H3), ('\u{d3b4}', '\u{d3b4}', H2), ('\u{d3b5}', '\u{d3cf}', H3),
+    ('\u{d3d0}', '\u{d3d0}', H2), ('\u{d3d1}', '\u{d3eb}', H3), ('\u{d3ec}', '\u{d3ec}', H2),
+    ('\u{d3ed}', '\u{d407}', H3), ('\u{d408}', '\u{d408}', H2), ('\u{d409}', '\u{d423}', H3),
+    ('\u{d424}', '\u{d424}', H2), ('\u{d425}', '\u{d43f}', H3), ('\u{d440}', '\u{d440}', H2),
+    ('\u{d441}', '\u{d45b}', H3), ('\u{d45c}', '\u{d45c}', H2), ('\u{d45d}', '\u{d477}', H3),
+    ('\u{d478}', '\u{d478}', H2), ('\u{d479}', '\u{d493}', H3), ('\u{d494}', '\u{d494}', H2),
+    ('\u{d495}', '\u{d4af}', H3), ('\u{d4b0}', '\u{d4b0}', H2), ('\u{d4b1}', '\u{d4cb}', H3),
+    ('\u{d4cc}', '\u{d4cc}', H2), ('\u{d4cd}', '\u{d4e7}', H3), ('\u{d4e8}', '\u{d4e8}', H2),
+    ('\u{d4e9}', '\u{d503}', H3), ('\u{d504}', '\u{d504}', H2), ('\u{d505}', '\u{d51f}', H3),
+    ('\u{d520}', '\u{d520}', H2), ('\u{d521}', '\u{d53b}', H3), ('\u{d53c}', '\u{d53c}', H2),
+    ('\u{d53d}', '\u{d557}', H3), ('\u{d558}', '\u{d558}', H2), ('\u{d559}', '\u{d573}', H3),
+    ('\u{d574}', '\u{d574}', H2), ('\u{d575}', '\u{d58f}', H3), ('\u{d590}', '\u{d590}', H2),
+    ('\u{d591}', '\u{d5ab}', H3), ('\u{d5ac}', '\u{d5ac}', H2), ('\u{d5ad}', '\u{d5c7}', H3),
+    ('\u{d5c8}', '\u{d5c8}', H2), ('\u{d5c9}', '\u{d5e3}', H3), ('\u{d5e4}', '\u{d5e4}', H2),
+    ('\u{d5e5}', '\u{d5ff}', H3), ('\u{d600}', '\u{d600}', H2), ('\u{d601}', '\u{d61b}', H3),
+    ('\u{d61c}', '\u{d61c}', H2), ('\u{d61d}', '\u{d637}', H3), ('\u{d638}', '\u{d638}', H2),
+    ('\u{d639}', '\u{d653}', H3), ('\u{d654}', '\u{d654}', H2), ('\u{d655}', '\u{d66f}', H3),
+    ('\u{d670}', '\u{d670}', H2), ('\u{d671}', '\u{d68b}', H3), ('\u{d68c}', '\u{d68c}', H2),
+    ('\u{d68d}', '\u{d6a7}', H3), ('\u{d6a8}', '\u{d6a8}', H2), ('\u{d6a9}', '\u{d6c3}', H3),
+    ('\u{d6c4}', '\u{d6c4}', H2), ('\u{d6c5}', '\u{d6df}', H3), ('\u{d6e0}', '\u{d6e0}', H2),
+    ('\u{d6e1}', '\u{d6fb}', H3), ('\u{d6fc}', '\u{d6fc}', H2), ('\u{d6fd}', '\u{d717}', H3),
+    ('\u{d718}', '\u{d718}', H2), ('\u{d719}', '\u{d733}', H3), ('\u{d734}', '\u{d734}', H2),
+    ('\u{d735}', '\u{d74f}', H3), ('\u{d750}', '\u{d750}', H2), ('\u{d751}', '\u{d76b}', H3),
+    ('\u{d76c}', '\u{d76c}', H2), ('\u{d76d}', '\u{d787}', H3), ('\u{d788}', '\u{d788}', H2),
+    ('\u{d789}', '\u{d7a3}', H3), ('\u{d7b0}', '\u{d7c6}', Jv), ('\u{d7cb}', '\u{d7fb}', Jt),
+    ('\u{f900}', '\u{faff}', Id), ('\u{fb1d}', '\u{fb1d}', Hl), ('\u{fb1e}', '\u{fb1e}', Cm),
+    ('\u{fb1f}', '\u{fb28}', Hl), ('\u{fb2a}', '\u{fb36}', Hl), ('\u{fb38}', '\u{fb3c}', Hl),
+    ('\u{fb3e}', '\u{fb3e}', Hl), ('\u{fb40}', '\u{fb41}', Hl), ('\u{fb43}', '\u{fb44}', Hl),
+    ('\u{fb46}', '\u{fb4f}', Hl), ('\u{fd3e}', '\u{fd3e}', Cl), ('\u{fd3f}', '\u{fd3f}', Op),
+    ('\u{fdfc}', '\u{fdfc}', Po), ('\u{fe00}', '\u{fe0f}', Cm), ('\u{fe10}', '\u{fe12}', Cl),
+    ('\u{fe13}', '\u{fe14}', Ns), ('\u{fe15}', '\u{fe16}', Ex), ('\u{fe17}', '\u{fe17}', Op),
+    ('\u{fe18}', '\u{fe18}', Cl), ('\u{fe19}', '\u{fe19}', In), ('\u{fe20}', '\u{fe20}', Gl),
+    ('\u{fe21}', '\u{fe21}', Cm), ('\u{fe22}', '\u{fe22}', Gl), ('\u{fe23}', '\u{fe23}', Cm),
+    ('\u{fe24}', '\u{fe24}', Gl), ('\u{fe25}', '\u{fe25}', Cm), ('\u{fe26}', '\u{fe27}', Gl),
+    ('\u{fe28}', '\u{fe28}', Cm), ('\u{fe29}', '\u{fe29}', Gl), ('\u{fe2a}', '\u{fe2a}', Cm),
+    ('\u{fe2b}', '\u{fe2b}', Gl), ('\u{fe2c}', '\u{fe2c}', Cm), ('\u{fe2d}', '\u{fe2e}', Gl),
+    ('\u{fe2f}', '\u{fe2f}', Cm), ('\u{fe30}', '\u{fe34}', Id), ('\u{fe35}', '\u{fe35}', Op),
+    ('\u{fe36}', '\u{fe36}', Cl), ('\u{fe37}', '\u{fe37}', Op), ('\u{fe38}', '\u{fe38}', Cl),
+    ('\u{fe39}', '\u{fe39}', Op), ('\u{fe3a}', '\u{fe3a}', Cl), ('\u{fe3b}', '\u{fe3b}', Op),
+    ('\u{fe3c}', '\u{fe3c}', Cl), ('\u{fe3d}', '\u{fe3d}', Op), ('\u{fe3e}', '\u{fe3e}', Cl),
+    ('\u{fe3f}', '\u{fe3f}', Op), ('\u{fe40}', '\u{fe40}', Cl), ('\u{fe41}', '\u{fe41}', Op),
+    ('\u{fe42}', '\u{fe42}', Cl), ('\u{fe43}', '\u{fe43}', Op), ('\u{fe44}', '\u{fe44}', Cl),
+    ('\u{fe45}', '\u{fe46}', Id), ('\u{fe47}', '\u{fe47}', Op), ('\u{fe48}', '\u{fe48}', Cl),
+    ('\u{fe49}', '\u{fe4f}', Id), ('\u{fe50}', '\u{fe50}', Cl), ('\u{fe51}', '\u{fe51}', Id),
+    ('\u{fe52}', '\u{fe52}', Cl), ('\u{fe54}', '\u{fe55}', Ns), ('\u{fe56}', '\u{fe57}', Ex),
+    ('\u{fe58}', '\u{fe58}', Id), ('\u{fe59}', '\u{fe59}', Op), ('\u{fe5a}', '\u{fe5a}', Cl),
+    ('\u{fe5b}', '\u{fe5b}', Op), ('\u{fe5c}', '\u{fe5c}', Cl), ('\u{fe5d}', '\u{fe5d}', Op),
+    ('\u{fe5e}', '\u{fe5e}', Cl), ('\u{fe5f}', '\u{fe66}', Id), ('\u{fe68}', '\u{fe68}', Id),
+    ('\u{fe69}', '\u{fe69}', Pr), ('\u{fe6a}', '\u{fe6a}', Po), ('\u{fe6b}', '\u{fe6b}', Id),
+    ('\u{feff}', '\u{feff}', Wj), ('\u{ff01}', '\u{ff01}', Ex), ('\u{ff02}', '\u{ff03}', Id),
+    ('\u{ff04}', '\u{ff04}', Pr), ('\u{ff05}', '\u{ff05}', Po), ('\u{ff06}', '\u{ff07}', Id),
+    ('\u{ff08}', '\u{ff08}', Op), ('\u{ff09}', '\u{ff09}', Cl), ('\u{ff0a}', '\u{ff0b}', Id),
+    ('\u{ff0c}', '\u{ff0c}', Cl), ('\u{ff0d}', '\u{ff0d}', Id), ('\u{ff0e}', '\u{ff0e}', Cl),
+    ('\u{ff0f}', '\u{ff19}', Id), ('\u{ff1a}', '\u{ff1b}', Ns), ('\u{ff1c}', '\u{ff1e}', Id),
+    ('\u{ff1f}', '\u{ff1f}', Ex), ('\u{ff20}', '\u{ff3a}', Id), ('\u{ff3b}', '\u{ff3b}', Op),
+    ('\u{ff3c}', '\u{ff3c}', Id), ('\u{ff3d}', '\u{ff3d}', Cl), ('\u{ff3e}', '\u{ff5a}', Id),
+    ('\u{ff5b}', '\u{ff5b}', Op), ('\u{ff5c}', '\u{ff5c}', Id), ('\u{ff5d}', '\u{ff5d}', Cl),
+    ('\u{ff5e}', '\u{ff5e}', Id), ('\u{ff5f}', '\u{ff5f}', Op), ('\u{ff60}', '\u{ff61}', Cl),
+    ('\u{ff62}', '\u{ff62}', Op), ('\u{ff63}', '\u{ff64}', Cl), ('\u{ff65}', '\u{ff65}', Ns),
+    ('\u{ff66}', '\u{ff66}', Id), ('\u{ff67}', '\u{ff70}', Ns), ('\u{ff71}', '\u{ff9d}', Id),
+    ('\u{ff9e}', '\u{ff9f}', Ns), ('\u{ffa0}', '\u{ffbe}', Id), ('\u{ffc2}', '\u{ffc7}', Id),
+    ('\u{ffca}', '\u{ffcf}', Id), ('\u{ffd2}', '\u{ffd7}', Id), ('\u{ffda}', '\u{ffdc}', Id),
+    ('\u{ffe0}', '\u{ffe0}', Po), ('\u{ffe1}', '\u{ffe1}', Pr), ('\u{ffe2}', '\u{ffe4}', Id),
+    ('\u{ffe5}', '\u{ffe6}', Pr), ('\u{fff9}', '\u{fffb}', Cm), ('\u{fffc}', '\u{fffc}', Cb),
+    ('\u{10100}', '\u{10102}', Ba), ('\u{101fd}', '\u{101fd}', Cm), ('\u{102e0}', '\u{102e0}', Cm),
+    ('\u{10376}', '\u{1037a}', Cm), ('\u{1039f}', '\u{1039f}', Ba), ('\u{103d0}', '\u{103d0}', Ba),
+    ('\u{104a0}', '\u{104a9}', Nu), ('\u{10857}', '\u{10857}', Ba), ('\u{1091f}', '\u{1091f}', Ba),
+    ('\u{10a01}', '\u{10a03}', Cm), ('\u{10a05}', '\u{10a06}', Cm), ('\u{10a0c}', '\u{10a0f}', Cm),
+    ('\u{10a38}', '\u{10a3a}', Cm), ('\u{10a3f}', '\u{10a3f}', Cm), ('\u{10a50}', '\u{10a57}', Ba),
+    ('\u{10ae5}', '\u{10ae6}', Cm), ('\u{10af0}', '\u{10af5}', Ba), ('\u{10af6}', '\u{10af6}', In),
+    ('\u{10b39}', '\u{10b3f}', Ba), ('\u{10d24}', '\u{10d27}', Cm), ('\u{10d30}', '\u{10d39}', Nu),
+    ('\u{10d40}', '\u{10d49}', Nu), ('\u{10d69}', '\u{10d6d}', Cm), ('\u{10d6e}', '\u{10d6e}', Ba),
+    ('\u{10eab}', '\u{10eac}', Cm), ('\u{10ead}', '\u{10ead}', Ba), ('\u{10efc}', '\u{10eff}', Cm),
+    ('\u{10f46}', '\u{10f50}', Cm), ('\u{10f82}', '\u{10f85}', Cm), ('\u{11000}', '\u{11002}', Cm),
+    ('\u{11003}', '\u{11004}', Ap), ('\u{11005}', '\u{11037}', Ak), ('\u{11038}', '\u{11045}', Cm),
+    ('\u{11046}', '\u{11046}', Vi), ('\u{11047}', '\u{11048}', Ba), ('\u{11049}', '\u{1104d}', Id),
+    ('\u{11052}', '\u{11065}', Id), ('\u{11066}', '\u{1106f}', As), ('\u{11070}', '\u{11070}', Cm),
+    ('\u{11071}', '\u{11072}', Ak), ('\u{11073}', '\u{11074}', Cm), ('\u{11075}', '\u{11075}', Ak),
+    ('\u{1107f}', '\u{1107f}', Gl), ('\u{11080}', '\u{11082}', Cm), ('\u{110b0}', '\u{110ba}', Cm),
+    ('\u{110bd}', '\u{110bd}', Nu), ('\u{110be}', '\u{110c1}', Ba), ('\u{110c2}', '\u{110c2}', Cm),
+    ('\u{110cd}', '\u{110cd}', Nu), ('\u{110f0}', '\u{110f9}', Nu), ('\u{11100}', '\u{11102}', Cm),
+    ('\u{11127}', '\u{11134}', Cm), ('\u{11136}', '\u{1113f}', Nu), ('\u{11140}', '\u{11143}', Ba),
+    ('\u{11145}', '\u{11146}', Cm), ('\u{11173}', '\u{11173}', Cm), ('\u{11175}', '\u{11175}', Bb),
+    ('\u{11180}', '\u{11182}', Cm), ('\u{111b3}', '\u{111c0}', Cm), ('\u{111c5}', '\u{111c6}', Ba),
+    ('\u{111c8}', '\u{111c8}', Ba), ('\u{111c9}', '\u{111cc}', Cm), ('\u{111ce}', '\u{111cf}', Cm),
+    ('\u{111d0}', '\u{111d9}', Nu), ('\u{111db}', '\u{111db}', Bb), ('\u{111dd}', '\u{111df}', Ba),
+    ('\u{1122c}', '\u{11237}', Cm), ('\u{11238}', '\u{11239}', Ba), ('\u{1123b}', '\u{1123c}', Ba),
+    ('\u{1123e}', '\u{1123e}', Cm), ('\u{11241}', '\u{11241}', Cm), ('\u{112a9}', '\u{112a9}', Ba),
+    ('\u{112df}', '\u{112ea}', Cm), ('\u{112f0}', '\u{112f9}', Nu), ('\u{11300}', '\u{11303}', Cm),
+    ('\u{11305}', '\u{1130c}', Ak), ('\u{1130f}', '\u{11310}', Ak), ('\u{11313}', '\u{11328}', Ak),
+    ('\u{1132a}', '\u{11330}', Ak), ('\u{11332}', '\u{11333}', Ak), ('\u{11335}', '\u{11339}', Ak),
+    ('\u{1133b}', '\u{1133c}', Cm), ('\u{1133d}', '\u{1133d}', Ba), ('\u{1133e}', '\u{11344}', Cm),
+    ('\u{11347}', '\u{11348}', Cm), ('\u{1134b}', '\u{1134c}', Cm), ('\u{1134d}', '\u{1134d}', Vi),
+    ('\u{11350}', '\u{11350}', As), ('\u{11357}', '\u{11357}', Cm), ('\u{1135d}', '\u{1135d}', Ba),
+    ('\u{1135e}', '\u{1135f}', As), ('\u{11360}', '\u{11361}', Ak), ('\u{11362}', '\u{11363}', Cm),
+    ('\u{11366}', '\u{1136c}', Cm), ('\u{11370}', '\u{11374}', Cm), ('\u{11380}', '\u{11389}', As),
+    ('\u{1138b}', '\u{1138b}', As), ('\u{1138e}', '\u{1138e}', As), ('\u{11390}', '\u{11391}', As),
+    ('\u{11392}', '\u{113b5}', Ak), ('\u{113b7}', '\u{113b7}', Id), ('\u{113b8}', '\u{113c0}', Cm),
+    ('\u{113c2}', '\u{113c2}', Cm), ('\u{113c5}', '\u{113c5}', Cm), ('\u{113c7}', '\u{113ca}', Cm),
+    ('\u{113cc}', '\u{113cf}', Cm), ('\u{113d0}', '\u{113d0}', Vi), ('\u{113d1}', '\u{113d1}', Ap),
+    ('\u{113d2}', '\u{113d2}', Cm), ('\u{113d3}', '\u{113d5}', Id), ('\u{113d7}', '\u{113d8}', Id),
+    ('\u{113e1}', '\u{113e2}', Cm), ('\u{11435}', '\u{11446}', Cm), ('\u{1144b}', '\u{1144e}', Ba),
+    ('\u{11450}', '\u{11459}', Nu), ('\u{1145a}', '\u{1145b}', Ba), ('\u{1145e}', '\u{1145e}', Cm),
+    ('\u{114b0}', '\u{114c3}', Cm), ('\u{114d0}', '\u{114d9}', Nu), ('\u{115af}', '\u{115b5}', Cm),
+    ('\u{115b8}', '\u{115c0}', Cm), ('\u{115c1}', '\u{115c1}', Bb), ('\u{115c2}', '\u{115c3}', Ba),
+    ('\u{115c4}', '\u{115c5}', Ex), ('\u{115c9}', '\u{115d7}', Ba), ('\u{115dc}', '\u{115dd}', Cm),
+    ('\u{11630}', '\u{11640}', Cm), ('\u{11641}', '\u{11642}', Ba), ('\u{11650}', '\u{11659}', Nu),
+    ('\u{11660}', '\u{1166c}', Bb), ('\u{116ab}', '\u{116b7}', Cm), ('\u{116c0}', '\u{116c9}', Nu),
+    ('\u{116d0}', '\u{116e3}', Nu), ('\u{1171d}', '\u{1172b}', Cm), ('\u{11730}', '\u{11739}', Nu),
+    ('\u{1173c}', '\u{1173e}', Ba), ('\u{1182c}', '\u{1183a}', Cm), ('\u{118e0}', '\u{118e9}', Nu),
+    ('\u{11900}', '\u{11906}', Ak), ('\u{11909}', '\u{11909}', Ak), ('\u{1190c}', '\u{11913}', Ak),
+    ('\u{11915}', '\u{11916}', Ak), ('\u{11918}', '\u{1192f}', Ak), ('\u{11930}', '\u{11935}', Cm),
+    ('\u{11937}', '\u{11938}', Cm), ('\u{1193b}', '\u{1193d}', Cm), ('\u{1193e}', '\u{1193e}', Vi),
+    ('\u{1193f}', '\u{1193f}', Ap), ('\u{11940}', '\u{11940}', Cm), ('\u{11941}', '\u{11941}', Ap),
+    ('\u{11942}', '\u{11943}', Cm), ('\u{11944}', '\u{11946}', Ba), ('\u{11950}', '\u{11959}', As),
+    ('\u{119d1}', '\u{119d7}', Cm), ('\u{119da}', '\u{119e0}', Cm), ('\u{119e2}', '\u{119e2}', Bb),
+    ('\u{119e4}', '\u{119e4}', Cm), ('\u{11a01}', '\u{11a0a}', Cm), ('\u{11a33}', '\u{11a39}', Cm),
+    ('\u{11a3b}', '\u{11a3e}', Cm), ('\u{11a3f}', '\u{11a3f}', Bb), ('\u{11a41}', '\u{11a44}', Ba),
+    ('\u{11a45}', '\u{11a45}', Bb), ('\u{11a47}', '\u{11a47}', Cm), ('\u{11a51}', '\u{11a5b}', Cm),
+    ('\u{11a8a}', '\u{11a99}', Cm), ('\u{11a9a}', '\u{11a9c}', Ba), ('\u{11a9e}', '\u{11aa0}', Bb),
+    ('\u{11aa1}', '\u{11aa2}', Ba), ('\u{11b00}', '\u{11b09}', Bb), ('\u{11bf0}', '\u{11bf9}', Nu),
+    ('\u{11c2f}', '\u{11c36}', Cm), ('\u{11c38}', '\u{11c3f}', Cm), ('\u{11c41}', '\u{11c45}', Ba),
+    ('\u{11c50}', '\u{11c59}', Nu), ('\u{11c70}', '\u{11c70}', Bb), ('\u{11c71}', '\u{11c71}', Ex),
+    ('\u{11c92}', '\u{11ca7}', Cm), ('\u{11ca9}', '\u{11cb6}', Cm), ('\u{11d31}', '\u{11d36}', Cm),
+    ('\u{11d3a}', '\u{11d3a}', Cm), ('\u{11d3c}', '\u{11d3d}', Cm), ('\u{11d3f}', '\u{11d45}', Cm),
+    ('\u{11d47}', '\u{11d47}', Cm), ('\u{11d50}', '\u{11d59}', Nu), ('\u{11d8a}', '\u{11d8e}', Cm),
+    ('\u{11d90}', '\u{11d91}', Cm), ('\u{11d93}', '\u{11d97}', Cm), ('\u{11da0}', '\u{11da9}', Nu),
+    ('\u{11ee0}', '\u{11ef1}', As), ('\u{11ef2}', '\u{11ef2}', Ba), ('\u{11ef3}', '\u{11ef6}', Cm),
+    ('\u{11ef7}', '\u{11ef8}', Ba), ('\u{11f00}', '\u{11f01}', Cm), ('\u{11f02}', '\u{11f02}', Ap),
+    ('\u{11f03}', '\u{11f03}', Cm), ('\u{11f04}', '\u{11f10}', Ak), ('\u{11f12}', '\u{11f33}', Ak),
+    ('\u{11f34}', '\u{11f3a}', Cm), ('\u{11f3e}', '\u{11f41}', Cm), ('\u{11f42}', '\u{11f42}', Vi),
+    ('\u{11f43}', '\u{11f44}', Ba), ('\u{11f45}', '\u{11f4f}', Id), ('\u{11f50}', '\u{11f59}', As),
+    ('\u{11f5a}', '\u{11f5a}', Cm), ('\u{11fdd}', '\u{11fe0}', Po), ('\u{11fff}', '\u{11fff}', Ba),
+    ('\u{12470}', '\u{12474}', Ba), ('\u{13258}', '\u{1325a}', Op), ('\u{1325b}', '\u{1325d}', Cl),
+    ('\u{13282}', '\u{13282}', Cl), ('\u{13286}', '\u{13286}', Op), ('\u{13287}', '\u{13287}', Cl),
+    ('\u{13288}', '\u{13288}', Op), ('\u{13289}', '\u{13289}', Cl), ('\u{13379}', '\u{13379}', Op),
+    ('\u{1337a}', '\u{1337b}', Cl), ('\u{1342f}', '\u{1342f}', Op), ('\u{13430}', '\u{13436}', Gl),
+    ('\u{13437}', '\u{13437}', Op), ('\u{13438}', '\u{13438}', Cl), ('\u{13439}', '\u{1343b}', Gl),
+    ('\u{1343c}', '\u{1343c}', Op), ('\u{1343d}', '\u{1343d}', Cl), ('\u{1343e}', '\u{1343e}', Op),
+    ('\u{1343f}', '\u{1343f}', Cl), ('\u{13440}', '\u{13440}', Cm), ('\u{13447}', '\u{13455}', Cm),
+    ('\u{145ce}', '\u{145ce}', Op), ('\u{145cf}', '\u{145cf}', Cl), ('\u{16100}', '\u{1611d}', As),
+    ('\u{1611e}', '\u{1612f}', Cm), ('\u{16130}', '\u{16139}', As), ('\u{16a60}', '\u{16a69}', Nu),
+    ('\u{16a6e}', '\u{16a6f}', Ba), ('\u{16ac0}', '\u{16ac9}', Nu), ('\u{16af0}', '\u{16af4}', Cm),
+    ('\u{16af5}', '\u{16af5}', Ba), ('\u{16b30}', '\u{16b36}', Cm), ('\u{16b37}', '\u{16b39}', Ba),
+    ('\u{16b44}', '\u{16b44}', Ba), ('\u{16b50}', '\u{16b59}', Nu), ('\u{16d6e}', '\u{16d6f}', Ba),
+    ('\u{16d70}', '\u{16d79}', Nu), ('\u{16e97}', '\u{16e98}', Ba), ('\u{16f4f}', '\u{16f4f}', Cm),
+    ('\u{16f51}', '\u{16f87}', Cm), ('\u{16f8f}', '\u{16f92}', Cm), ('\u{16fe0}', '\u{16fe3}', Ns),
+    ('\u{16fe4}', '\u{16fe4}', Gl), ('\u{16ff0}', '\u{16ff1}', Cm), ('\u{17000}', '\u{187f7}', Id),
+    ('\u{18800}', '\u{18aff}', Id), ('\u{18d00}', '\u{18d08}', Id), ('\u{1b000}', '\u{1b122}', Id),
+    ('\u{1b132}', '\u{1b132}', Ns), ('\u{1b150}', '\u{1b152}', Ns), ('\u{1b155}', '\u{1b155}', Ns),
+    ('\u{1b164}', '\u{1b167}', Ns), ('\u{1b170}', '\u{1b2fb}', Id), ('\u{1bc9d}', '\u{1bc9e}', Cm),
+    ('\u{1bc9f}', '\u{1bc9f}', Ba), ('\u{1bca0}', '\u{1bca3}', Cm), ('\u{1ccf0}', '\u{1ccf9}', Nu),
+    ('\u{1cf00}', '\u{1cf2d}', Cm), ('\u{1cf30}', '\u{1cf46}', Cm), ('\u{1d165}', '\u{1d169}', Cm),
+    ('\u{1d16d}', '\u{1d182}', Cm), ('\u{1d185}', '\u{1d18b}', Cm), ('\u{1d1aa}', '\u{1d1ad}', Cm),
+    ('\u{1d242}', '\u{1d244}', Cm), ('\u{1d7ce}', '\u{1d7ff}', Nu), ('\u{1da00}', '\u{1da36}', Cm),
+    ('\u{1da3b}', '\u{1da6c}', Cm), ('\u{1da75}', '\u{1da75}', Cm), ('\u{1da84}', '\u{1da84}', Cm),
+    ('\u{1da87}', '\u{1da8a}', Ba), ('\u{1da9b}', '\u{1da9f}', Cm), ('\u{1daa1}', '\u{1daaf}', Cm),
+    ('\u{1e000}', '\u{1e006}', Cm), ('\u{1e008}', '\u{1e018}', Cm), ('\u{1e01b}', '\u{1e021}', Cm),
+    ('\u{1e023}', '\u{1e024}', Cm), ('\u{1e026}', '\u{1e02a}', Cm), ('\u{1e08f}', '\u{1e08f}', Cm),
+    ('\u{1e130}', '\u{1e136}', Cm), ('\u{1e140}', '\u{1e149}', Nu), ('\u{1e2ae}', '\u{1e2ae}', Cm),
+    ('\u{1e2ec}', '\u{1e2ef}', Cm), ('\u{1e2f0}', '\u{1e2f9}', Nu), ('\u{1e2ff}', '\u{1e2ff}', Pr),
+    ('\u{1e4ec}', '\u{1e4ef}', Cm), ('\u{1e4f0}', '\u{1e4f9}', Nu), ('\u{1e5ee}', '\u{1e5ef}', Cm),
+    ('\u{1e5f1}', '\u{1e5fa}', Nu), ('\u{1e8d0}', '\u{1e8d6}', Cm), ('\u{1e944}', '\u{1e94a}', Cm),
+    ('\u{1e950}', '\u{1e959}', Nu), ('\u{1e95e}', '\u{1e95f}', Op), ('\u{1ecac}', '\u{1ecac}', Po),
+    ('\u{1ecb0}', '\u{1ecb0}', Po), ('\u{1f000}', '\u{1f0ff}', Id), ('\u{1f1ae}', '\u{1f1e5}', Id),
+    ('\u{1f1e6}', '\u{1f1ff}', Ri), ('\u{1f200}', '\u{1f384}', Id), ('\u{1f385}', '\u{1f385}', Eb),
+    ('\u{1f386}', '\u{1f39b}', Id), ('\u{1f39e}', '\u{1f3b4}', Id), ('\u{1f3b7}', '\u{1f3bb}', Id),
+    ('\u{1f3bd}', '\u{1f3c1}', Id), ('\u{1f3c2}', '\u{1f3c4}', Eb), ('\u{1f3c5}', '\u{1f3c6}', Id),
+    ('\u{1f3c7}', '\u{1f3c7}', Eb), ('\u{1f3c8}', '\u{1f3c9}', Id), ('\u{1f3ca}', '\u{1f3cc}', Eb),
+    ('\u{1f3cd}', '\u{1f3fa}', Id), ('\u{1f3fb}', '\u{1f3ff}', Em), ('\u{1f400}', '\u{1f441}', Id),
+    ('\u{1f442}', '\u{1f443}', Eb), ('\u{1f444}', '\u{1f445}', Id), ('\u{1f446}', '\u{1f450}', Eb),
+    ('\u{1f451}', '\u{1f465}', Id), ('\u{1f466}', '\u{1f478}', Eb), ('\u{1f479}', '\u{1f47b}', Id),
+    ('\u{1f47c}', '\u{1f47c}', Eb), ('\u{1f47d}', '\u{1f480}', Id), ('\u{1f481}', '\u{1f483}', Eb),
+    ('\u{1f484}', '\u{1f484}', Id), ('\u{1f485}', '\u{1f487}', Eb), ('\u{1f488}', '\u{1f48e}', Id),
+    ('\u{1f48f}', '\u{1f48f}', Eb), ('\u{1f490}', '\u{1f490}', Id), ('\u{1f491}', '\u{1f491}', Eb),
+    ('\u{1f492}', '\u{1f49f}', Id), ('\u{1f4a1}', '\u{1f4a1}', Id), ('\u{1f4a3}', '\u{1f4a3}', Id),
+    ('\u{1f4a5}', '\u{1f4a9}', Id), ('\u{1f4aa}', '\u{1f4aa}', Eb), ('\u{1f4ab}', '\u{1f4ae}', Id),
+    ('\u{1f4b0}', '\u{1f4b0}', Id), ('\u{1f4b3}', '\u{1f4ff}', Id), ('\u{1f507}', '\u{1f516}', Id),
+    ('\u{1f525}', '\u{1f531}', Id), ('\u{1f54a}', '\u{1f573}', Id), ('\u{1f574}', '\u{1f575}', Eb),
+    ('\u{1f576}', '\u{1f579}', Id), ('\u{1f57a}', '\u{1f57a}', Eb), ('\u{1f57b}', '\u{1f58f}', Id),
+    ('\u{1f590}', '\u{1f590}', Eb), ('\u{1f591}', '\u{1f594}', Id), ('\u{1f595}', '\u{1f596}', Eb),
+    ('\u{1f597}', '\u{1f5d3}', Id), ('\u{1f5dc}', '\u{1f5f3}', Id), ('\u{1f5fa}', '\u{1f644}', Id),
+    ('\u{1f645}', '\u{1f647}', Eb), ('\u{1f648}', '\u{1f64a}', Id), ('\u{1f64b}', '\u{1f64f}', Eb),
+    ('\u{1f676}', '\u{1f678}', Qu), ('\u{1f679}', '\u{1f67b}', Ns), ('\u{1f680}', '\u{1f6a2}', Id),
+    ('\u{1f6a3}', '\u{1f6a3}', Eb), ('\u{1f6a4}', '\u{1f6b3}', Id), ('\u{1f6b4}', '\u{1f6b6}', Eb),
+    ('\u{1f6b7}', '\u{1f6bf}', Id), ('\u{1f6c0}', '\u{1f6c0}', Eb), ('\u{1f6c1}', '\u{1f6cb}', Id),
+    ('\u{1f6cc}', '\u{1f6cc}', Eb), ('\u{1f6cd}', '\u{1f6ff}', Id), ('\u{1f774}', '\u{1f77f}', Id),
+    ('\u{1f7d5}', '\u{1f7ff}', Id), ('\u{1f90c}', '\u{1f90c}', Eb), ('\u{1f90d}', '\u{1f90e}', Id),
+    ('\u{1f90f}', '\u{1f90f}', Eb), ('\u{1f910}', '\u{1f917}', Id), ('\u{1f918}', '\u{1f91f}', Eb),
+    ('\u{1f920}', '\u{1f925}', Id), ('\u{1f926}', '\u{1f926}', Eb), ('\u{1f927}', '\u{1f92f}', Id),
+    ('\u{1f930}', '\u{1f939}', Eb), ('\u{1f93a}', '\u{1f93b}', Id), ('\u{1f93c}', '\u{1f93e}', Eb),
+    ('\u{1f93f}', '\u{1f976}', Id), ('\u{1f977}', '\u{1f977}', Eb), ('\u{1f978}', '\u{1f9b4}', Id),
+    ('\u{1f9b5}', '\u{1f9b6}', Eb), ('\u{1f9b7}', '\u{1f9b7}', Id), ('\u{1f9b8}', '\u{1f9b9}', Eb),
+    ('\u{1f9ba}', '\u{1f9ba}', Id), ('\u{1f9bb}', '\u{1f9bb}', Eb), ('\u{1f9bc}', '\u{1f9cc}', Id),
+    ('\u{1f9cd}', '\u{1f9cf}', Eb), ('\u{1f9d0}', '\u{1f9d0}', Id), ('\u{1f9d1}', '\u{1f9dd}', Eb),
+    ('\u{1f9de}', '\u{1f9ff}', Id), ('\u{1fa54}', '\u{1fac2}', Id), ('\u{1fac3}', '\u{1fac5}', Eb),
+    ('\u{1fac6}', '\u{1faef}', Id), ('\u{1faf0}', '\u{1faf8}', Eb), ('\u{1faf9}', '\u{1faff}', Id),
+    ('\u{1fbf0}', '\u{1fbf9}', Nu), ('\u{1fc00}', '\u{1fffd}', Id), ('\u{20000}', '\u{2fffd}', Id),
+    ('\u{30000}', '\u{3fffd}', Id), ('\u{e0001}', '\u{e0001}', Cm), ('\u{e0020}', '\u{e007f}', Cm),
+    ('\u{e0100}', '\u{e01ef}', Cm),
+];
+
+#[rustfmt::skip]
+const INITIAL_QUOTE: &[(char, char)] = &[
+    ('\u{ab}', '\u{ab}'), ('\u{2018}', '\u{2018}'), ('\u{201b}', '\u{201c}'),
+    ('\u{201f}', '\u{201f}'), ('\u{2039}', '\u{2039}'), ('\u{2e02}', '\u{2e02}'),
+    ('\u{2e04}', '\u{2e04}'), ('\u{2e09}', '\u{2e09}'), ('\u{2e0c}', '\u{2e0c}'),
+    ('\u{2e1c}', '\u{2e1c}'), ('\u{2e20}', '\u{2e20}'),
+];
+
+#[rustfmt::skip]
+const FINAL_QUOTE: &[(char, char)] = &[
+    ('\u{bb}', '\u{bb}'), ('\u{2019}', '\u{2019}'), ('\u{201d}', '\u{201d}'),
+    ('\u{203a}', '\u{203a}'), ('\u{2e03}', '\u{2e03}'), ('\u{2e05}', '\u{2e05}'),
+    ('\u{2e0a}', '\u{2e0a}'), ('\u{2e0d}', '\u{2e0d}'), ('\u{2e1d}', '\u{2e1d}'),
+    ('\u{2e21}', '\u{2e21}'),
+];
+
+#[rustfmt::skip]
+const EAST_ASIAN: &[(char, char)] = &[
+    ('\u{1100}', '\u{115f}'), ('\u{20a9}', '\u{20a9}'), ('\u{231a}', '\u{231b}'),
+    ('\u{2329}', '\u{232a}'), ('\u{23e9}', '\u{23ec}'), ('\u{23f0}', '\u{23f0}'),
+    ('\u{23f3}', '\u{23f3}'), ('\u{25fd}', '\u{25fe}'), ('\u{2614}', '\u{2615}'),
+    ('\u{2630}', '\u{2637}'), ('\u{2648}', '\u{2653}'), ('\u{267f}', '\u{267f}'),
+    ('\u{268a}', '\u{268f}'), ('\u{2693}', '\u{2693}'), ('\u{26a1}', '\u{26a1}'),
+    ('\u{26aa}', '\u{26ab}'), ('\u{26bd}', '\u{26be}'), ('\u{26c4}', '\u{26c5}'),
+    ('\u{26ce}', '\u{26ce}'), ('\u{26d4}', '\u{26d4}'), ('\u{26ea}', '\u{26ea}'),
+    ('\u{26f2}', '\u{26f3}'), ('\u{26f5}', '\u{26f5}'), ('\u{26fa}', '\u{26fa}'),
+    ('\u{26fd}', '\u{26fd}'), ('\u{2705}', '\u{2705}'), ('\u{270a}', '\u{270b}'),
+    ('\u{2728}', '\u{2728}'), ('\u{274c}', '\u{274c}'), ('\u{274e}', '\u{274e}'),
+    ('\u{2753}', '\u{2755}'), ('\u{2757}', '\u{2757}'), ('\u{2795}', '\u{2797}'),
+    ('\u{27b0}', '\u{27b0}'), ('\u{27bf}', '\u{27bf}'), ('\u{2b1b}', '\u{2b1c}'),
+    ('\u{2b50}', '\u{2b50}'), ('\u{2b55}', '\u{2b55}'), ('\u{2e80}', '\u{2e99}'),
+    ('\u{2e9b}', '\u{2ef3}'), ('\u{2f00}', '\u{2fd5}'), ('\u{2ff0}', '\u{303e}'),
+    ('\u{3041}', '\u{3096}'), ('\u{3099}', '\u{30ff}'), ('\u{3105}', '\u{312f}'),
+    ('\u{3131}', '\u{318e}'), ('\u{3190}', '\u{31e5}'), ('\u{31ef}', '\u{321e}'),
+    ('\u{3220}', '\u{3247}'), ('\u{3250}', '\u{a48c}'), ('\u{a490}', '\u{a4c6}'),
+    ('\u{a960}', '\u{a97c}'), ('\u{ac00}', '\u{d7a3}'), ('\u{f900}', '\u{faff}'),
+    ('\u{fe10}', '\u{fe19}'), ('\u{fe30}', '\u{fe52}'), ('\u{fe54}', '\u{fe66}'),
+    ('\u{fe68}', '\u{fe6b}'), ('\u{ff01}', '\u{ffbe}'), ('\u{ffc2}', '\u{ffc7}'),
+    ('\u{ffca}', '\u{ffcf}'), ('\u{ffd2}', '\u{ffd7}'), ('\u{ffda}', '\u{ffdc}'),
+    ('\u{ffe0}', '\u{ffe6}'), ('\u{ffe8}', '\u{ffee}'), ('\u{16fe0}', '\u{16fe4}'),
+    ('\u{16ff0}', '\u{16ff1}'), ('\u{17000}', '\u{187f7}'), ('\u{18800}', '\u{18cd5}'),
+    ('\u{18cff}', '\u{18d08}'), ('\u{1aff0}', '\u{1aff3}'), ('\u{1aff5}', '\u{1affb}'),
+    ('\u{1affd}', '\u{1affe}'), ('\u{1b000}', '\u{1b122}'), ('\u{1b132}', '\u{1b132}'),
+    ('\u{1b150}', '\u{1b152}'), ('\u{1b155}', '\u{1b155}'), ('\u{1b164}', '\u{1b167}'),
+    ('\u{1b170}', '\u{1b2fb}'), ('\u{1d300}', '\u{1d356}'), ('\u{1d360}', '\u{1d376}'),
+    ('\u{1f004}', '\u{1f004}'), ('\u{1f0cf}', '\u{1f0cf}'), ('\u{1f18e}', '\u{1f18e}'),
+    ('\u{1f191}', '\u{1f19a}'), ('\u{1f200}', '\u{1f202}'), ('\u{1f210}', '\u{1f23b}'),
+    ('\u{1f240}', '\u{1f248}'), ('\u{1f250}', '\u{1f251}'), ('\u{1f260}', '\u{1f265}'),
+    ('\u{1f300}', '\u{1f320}'), ('\u{1f32d}', '\u{1f335}'), ('\u{1f337}', '\u{1f37c}'),
+    ('\u{1f37e}', '\u{1f393}'), ('\u{1f3a0}', '\u{1f3ca}'), ('\u{1f3cf}', '\u{1f3d3}'),
+    ('\u{1f3e0}', '\u{1f3f0}'), ('\u{1f3f4}', '\u{1f3f4}'), ('\u{1f3f8}', '\u{1f43e}'),
+    ('\u{1f440}', '\u{1f440}'), ('\u{1f442}', '\u{1f4fc}'), ('\u{1f4ff}', '\u{1f53d}'),
+    ('\u{1f54b}', '\u{1f54e}'), ('\u{1f550}', '\u{1f567}'), ('\u{1f57a}', '\u{1f57a}'),
+    ('\u{1f595}', '\u{1f596}'), ('\u{1f5a4}', '\u{1f5a4}'), ('\u{1f5fb}', '\u{1f64f}'),
+    ('\u{1f680}', '\u{1f6c5}'), ('\u{1f6cc}', '\u{1f6cc}'), ('\u{1f6d0}', '\u{1f6d2}'),
+    ('\u{1f6d5}', '\u{1f6d7}'), ('\u{1f6dc}', '\u{1f6df}'), ('\u{1f6eb}', '\u{1f6ec}'),
+    ('\u{1f6f4}', '\u{1f6fc}'), ('\u{1f7e0}', '\u{1f7eb}'), ('\u{1f7f0}', '\u{1f7f0}'),
+    ('\u{1f90c}', '\u{1f93a}'), ('\u{1f93c}', '\u{1f945}'), ('\u{1f947}', '\u{1f9ff}'),
+    ('\u{1fa70}', '\u{1fa7c}'), ('\u{1fa80}', '\u{1fa89}'), ('\u{1fa8f}', '\u{1fac6}'),
+    ('\u{1face}', '\u{1fadc}'), ('\u{1fadf}', '\u{1fae9}'), ('\u{1faf0}', '\u{1faf8}'),
+    ('\u{20000}', '\u{2fffd}'), ('\u{30000}', '\u{3fffd}'),
+];
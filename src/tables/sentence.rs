@@ -0,0 +1,1166 @@
+//! Sentence boundary related properties, see [UAX #29](https://www.unicode.org/reports/tr29/).
+//!
+//! Generated from the Unicode Character Database 16.0.0 by
+//! `scripts/gen_tables.py`, do not edit by hand.
+
+use super::lookup;
+use SentenceBreak::*;
+
+/// The `Sentence_Break` property of a code point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SentenceBreak {
+    Other,
+    Cr,
+    Lf,
+    Extend,
+    Sep,
+    Format,
+    Sp,
+    Lower,
+    Upper,
+    OLetter,
+    Numeric,
+    ATerm,
+    SContinue,
+    STerm,
+    Close,
+}
+
+pub(crate) fn sentence_break(c: char) -> SentenceBreak {
+    lookup(c, SENTENCE_BREAK).unwrap_or(SentenceBreak::Other)
+}
+
+#[rustfmt::skip]
+const SENTENCE_BREAK: &[(char, char, SentenceBreak)] = &[
+    ('\u{9}', '\u{9}', Sp), ('\u{a}', '\u{a}', Lf), ('\u{b}', '\u{c}', Sp), ('\u{d}', '\u{d}', Cr),
+    ('\u{20}', '\u{20}', Sp), ('\u{21}', '\u{21}', STerm), ('\u{22}', '\u{22}', Close),
+    ('\u{27}', '\u{29}', Close), ('\u{2c}', '\u{2d}', SContinue), ('\u{2e}', '\u{2e}', ATerm),
+    ('\u{30}', '\u{39}', Numeric), ('\u{3a}', '\u{3b}', SContinue), ('\u{3f}', '\u{3f}', STerm),
+    ('\u{41}', '\u{5a}', Upper), ('\u{5b}', '\u{5b}', Close), ('\u{5d}', '\u{5d}', Close),
+    ('\u{61}', '\u{7a}', Lower), ('\u{7b}', '\u{7b}', Close), ('\u{7d}', '\u{7d}', Close),
+    ('\u{85}', '\u{85}', Sep), ('\u{a0}', '\u{a0}', Sp), ('\u{aa}', '\u{aa}', Lower),
+    ('\u{ab}', '\u{ab}', Close), ('\u{ad}', '\u{ad}', Format), ('\u{b5}', '\u{b5}', Lower),
+    ('\u{ba}', '\u{ba}', Lower), ('\u{bb}', '\u{bb}', Close), ('\u{c0}', '\u{d6}', Upper),
+    ('\u{d8}', '\u{de}', Upper), ('\u{df}', '\u{f6}', Lower), ('\u{f8}', '\u{ff}', Lower),
+    ('\u{100}', '\u{100}', Upper), ('\u{101}', '\u{101}', Lower), ('\u{102}', '\u{102}', Upper),
+    ('\u{103}', '\u{103}', Lower), ('\u{104}', '\u{104}', Upper), ('\u{105}', '\u{105}', Lower),
+    ('\u{106}', '\u{106}', Upper), ('\u{107}', '\u{107}', Lower), ('\u{108}', '\u{108}', Upper),
+    ('\u{109}', '\u{109}', Lower), ('\u{10a}', '\u{10a}', Upper), ('\u{10b}', '\u{10b}', Lower),
+    ('\u{10c}', '\u{10c}', Upper), ('\u{10d}', '\u{10d}', Lower), ('\u{10e}', '\u{10e}', Upper),
+    ('\u{10f}', '\u{10f}', Lower), ('\u{110}', '\u{110}', Upper), ('\u{111}', '\u{111}', Lower),
+    ('\u{112}', '\u{112}', Upper), ('\u{113}', '\u{113}', Lower), ('\u{114}', '\u{114}', Upper),
+    ('\u{115}', '\u{115}', Lower), ('\u{116}', '\u{116}', Upper), ('\u{117}', '\u{117}', Lower),
+    ('\u{118}', '\u{118}', Upper), ('\u{119}', '\u{119}', Lower), ('\u{11a}', '\u{11a}', Upper),
+    ('\u{11b}', '\u{11b}', Lower), ('\u{11c}', '\u{11c}', Upper), ('\u{11d}', '\u{11d}', Lower),
+    ('\u{11e}', '\u{11e}', Upper), ('\u{11f}', '\u{11f}', Lower), ('\u{120}', '\u{120}', Upper),
+    ('\u{121}', '\u{121}', Lower), ('\u{122}', '\u{122}', Upper), ('\u{123}', '\u{123}', Lower),
+    ('\u{124}', '\u{124}', Upper), ('\u{125}', '\u{125}', Lower), ('\u{126}', '\u{126}', Upper),
+    ('\u{127}', '\u{127}', Lower), ('\u{128}', '\u{128}', Upper), ('\u{129}', '\u{129}', Lower),
+    ('\u{12a}', '\u{12a}', Upper), ('\u{12b}', '\u{12b}', Lower), ('\u{12c}', '\u{12c}', Upper),
+    ('\u{12d}', '\u{12d}', Lower), ('\u{12e}', '\u{12e}', Upper), ('\u{12f}', '\u{12f}', Lower),
+    ('\u{130}', '\u{130}', Upper), ('\u{131}', '\u{131}', Lower), ('\u{132}', '\u{132}', Upper),
+    ('\u{133}', '\u{133}', Lower), ('\u{134}', '\u{134}', Upper), ('\u{135}', '\u{135}', Lower),
+    ('\u{136}', '\u{136}', Upper), ('\u{137}', '\u{138}', Lower), ('\u{139}', '\u{139}', Upper),
+    ('\u{13a}', '\u{13a}', Lower), ('\u{13b}', '\u{13b}', Upper), ('\u{13c}', '\u{13c}', Lower),
+    ('\u{13d}', '\u{13d}', Upper), ('\u{13e}', '\u{13e}', Lower), ('\u{13f}', '\u{13f}', Upper),
+    ('\u{140}', '\u{140}', Lower), ('\u{141}', '\u{141}', Upper), ('\u{142}', '\u{142}', Lower),
+    ('\u{143}', '\u{143}', Upper), ('\u{144}', '\u{144}', Lower), ('\u{145}', '\u{145}', Upper),
+    ('\u{146}', '\u{146}', Lower), ('\u{147}', '\u{147}', Upper), ('\u{148}', '\u{149}', Lower),
+    ('\u{14a}', '\u{14a}', Upper), ('\u{14b}', '\u{14b}', Lower), ('\u{14c}', '\u{14c}', Upper),
+    ('\u{14d}', '\u{14d}', Lower), ('\u{14e}', '\u{14e}', Upper), ('\u{14f}', '\u{14f}', Lower),
+    ('\u{150}', '\u{150}', Upper), ('\u{151}', '\u{151}', Lower), ('\u{152}', '\u{152}', Upper),
+    ('\u{153}', '\u{153}', Lower), ('\u{154}', '\u{154}', Upper), ('\u{155}', '\u{155}', Lower),
+    ('\u{156}', '\u{156}', Upper), ('\u{157}', '\u{157}', Lower), ('\u{158}', '\u{158}', Upper),
+    ('\u{159}', '\u{159}', Lower), ('\u{15a}', '\u{15a}', Upper), ('\u{15b}', '\u{15b}', Lower),
+    ('\u{15c}', '\u{15c}', Upper), ('\u{15d}', '\u{15d}', Lower), ('\u{15e}', '\u{15e}', Upper),
+    ('\u{15f}', '\u{15f}', Lower), ('\u{160}', '\u{160}', Upper), ('\u{161}', '\u{161}', Lower),
+    ('\u{162}', '\u{162}', Upper), ('\u{163}', '\u{163}', Lower), ('\u{164}', '\u{164}', Upper),
+    ('\u{165}', '\u{165}', Lower), ('\u{166}', '\u{166}', Upper), ('\u{167}', '\u{167}', Lower),
+    ('\u{168}', '\u{168}', Upper), ('\u{169}', '\u{169}', Lower), ('\u{16a}', '\u{16a}', Upper),
+    ('\u{16b}', '\u{16b}', Lower), ('\u{16c}', '\u{16c}', Upper), ('\u{16d}', '\u{16d}', Lower),
+    ('\u{16e}', '\u{16e}', Upper), ('\u{16f}', '\u{16f}', Lower), ('\u{170}', '\u{170}', Upper),
+    ('\u{171}', '\u{171}', Lower), ('\u{172}', '\u{172}', Upper), ('\u{173}', '\u{173}', Lower),
+    ('\u{174}', '\u{174}', Upper), ('\u{175}', '\u{175}', Lower), ('\u{176}', '\u{176}', Upper),
+    ('\u{177}', '\u{177}', Lower), ('\u{178}', '\u{179}', Upper), ('\u{17a}', '\u{17a}', Lower),
+    ('\u{17b}', '\u{17b}', Upper), ('\u{17c}', '\u{17c}', Lower), ('\u{17d}', '\u{17d}', Upper),
+    ('\u{17e}', '\u{180}', Lower), ('\u{181}', '\u{182}', Upper), ('\u{183}', '\u{183}', Lower),
+    ('\u{184}', '\u{184}', Upper), ('\u{185}', '\u{185}', Lower), ('\u{186}', '\u{187}', Upper),
+    ('\u{188}', '\u{188}', Lower), ('\u{189}', '\u{18b}', Upper), ('\u{18c}', '\u{18d}', Lower),
+    ('\u{18e}', '\u{191}', Upper), ('\u{192}', '\u{192}', Lower), ('\u{193}', '\u{194}', Upper),
+    ('\u{195}', '\u{195}', Lower), ('\u{196}', '\u{198}', Upper), ('\u{199}', '\u{19b}', Lower),
+    ('\u{19c}', '\u{19d}', Upper), ('\u{19e}', '\u{19e}', Lower), ('\u{19f}', '\u{1a0}', Upper),
+    ('\u{1a1}', '\u{1a1}', Lower), ('\u{1a2}', '\u{1a2}', Upper), ('\u{1a3}', '\u{1a3}', Lower),
+    ('\u{1a4}', '\u{1a4}', Upper), ('\u{1a5}', '\u{1a5}', Lower), ('\u{1a6}', '\u{1a7}', Upper),
+    ('\u{1a8}', '\u{1a8}', Lower), ('\u{1a9}', '\u{1a9}', Upper), ('\u{1aa}', '\u{1ab}', Lower),
+    ('\u{1ac}', '\u{1ac}', Upper), ('\u{1ad}', '\u{1ad}', Lower), ('\u{1ae}', '\u{1af}', Upper),
+    ('\u{1b0}', '\u{1b0}', Lower), ('\u{1b1}', '\u{1b3}', Upper), ('\u{1b4}', '\u{1b4}', Lower),
+    ('\u{1b5}', '\u{1b5}', Upper), ('\u{1b6}', '\u{1b6}', Lower), ('\u{1b7}', '\u{1b8}', Upper),
+    ('\u{1b9}', '\u{1ba}', Lower), ('\u{1bb}', '\u{1bb}', OLetter), ('\u{1bc}', '\u{1bc}', Upper),
+    ('\u{1bd}', '\u{1bf}', Lower), ('\u{1c0}', '\u{1c3}', OLetter), ('\u{1c4}', '\u{1c5}', Upper),
+    ('\u{1c6}', '\u{1c6}', Lower), ('\u{1c7}', '\u{1c8}', Upper), ('\u{1c9}', '\u{1c9}', Lower),
+    ('\u{1ca}', '\u{1cb}', Upper), ('\u{1cc}', '\u{1cc}', Lower), ('\u{1cd}', '\u{1cd}', Upper),
+    ('\u{1ce}', '\u{1ce}', Lower), ('\u{1cf}', '\u{1cf}', Upper), ('\u{1d0}', '\u{1d0}', Lower),
+    ('\u{1d1}', '\u{1d1}', Upper), ('\u{1d2}', '\u{1d2}', Lower), ('\u{1d3}', '\u{1d3}', Upper),
+    ('\u{1d4}', '\u{1d4}', Lower), ('\u{1d5}', '\u{1d5}', Upper), ('\u{1d6}', '\u{1d6}', Lower),
+    ('\u{1d7}', '\u{1d7}', Upper), ('\u{1d8}', '\u{1d8}', Lower), ('\u{1d9}', '\u{1d9}', Upper),
+    ('\u{1da}', '\u{1da}', Lower), ('\u{1db}', '\u{1db}', Upper), ('\u{1dc}', '\u{1dd}', Lower),
+    ('\u{1de}', '\u{1de}', Upper), ('\u{1df}', '\u{1df}', Lower), ('\u{1e0}', '\u{1e0}', Upper),
+    ('\u{1e1}', '\u{1e1}', Lower), ('\u{1e2}', '\u{1e2}', Upper), ('\u{1e3}', '\u{1e3}', Lower),
+    ('\u{1e4}', '\u{1e4}', Upper), ('\u{1e5}', '\u{1e5}', Lower), ('\u{1e6}', '\u{1e6}', Upper),
+    ('\u{1e7}', '\u{1e7}', Lower), ('\u{1e8}', '\u{1e8}', Upper), ('\u{1e9}', '\u{1e9}', Lower),
+    ('\u{1ea}', '\u{1ea}', Upper), ('\u{1eb}', '\u{1eb}', Lower), ('\u{1ec}', '\u{1ec}', Upper),
+    ('\u{1ed}', '\u{1ed}', Lower), ('\u{1ee}', '\u{1ee}', Upper), ('\u{1ef}', '\u{1f0}', Lower),
+    ('\u{1f1}', '\u{1f2}', Upper), ('\u{1f3}', '\u{1f3}', Lower), ('\u{1f4}', '\u{1f4}', Upper),
+    ('\u{1f5}', '\u{1f5}', Lower), ('\u{1f6}', '\u{1f8}', Upper), ('\u{1f9}', '\u{1f9}', Lower),
+    ('\u{1fa}', '\u{1fa}', Upper), ('\u{1fb}', '\u{1fb}', Lower), ('\u{1fc}', '\u{1fc}', Upper),
+    ('\u{1fd}', '\u{1fd}', Lower), ('\u{1fe}', '\u{1fe}', Upper), ('\u{1ff}', '\u{1ff}', Lower),
+    ('\u{200}', '\u{200}', Upper), ('\u{201}', '\u{201}', Lower), ('\u{202}', '\u{202}', Upper),
+    ('\u{203}', '\u{203}', Lower), ('\u{204}', '\u{204}', Upper), ('\u{205}', '\u{205}', Lower),
+    ('\u{206}', '\u{206}', Upper), ('\u{207}', '\u{207}', Lower), ('\u{208}', '\u{208}', Upper),
+    ('\u{209}', '\u{209}', Lower), ('\u{20a}', '\u{20a}', Upper), ('\u{20b}', '\u{20b}', Lower),
+    ('\u{20c}', '\u{20c}', Upper), ('\u{20d}', '\u{20d}', Lower), ('\u{20e}', '\u{20e}', Upper),
+    ('\u{20f}', '\u{20f}', Lower), ('\u{210}', '\u{210}', Upper), ('\u{211}', '\u{211}', Lower),
+    ('\u{212}', '\u{212}', Upper), ('\u{213}', '\u{213}', Lower), ('\u{214}', '\u{214}', Upper),
+    ('\u{215}', '\u{215}', Lower), ('\u{216}', '\u{216}', Upper), ('\u{217}', '\u{217}', Lower),
+    ('\u{218}', '\u{218}', Upper), ('\u{219}', '\u{219}', Lower), ('\u{21a}', '\u{21a}', Upper),
+    ('\u{21b}', '\u{21b}', Lower), ('\u{21c}', '\u{21c}', Upper), ('\u{21d}', '\u{21d}', Lower),
+    ('\u{21e}', '\u{21e}', Upper), ('\u{21f}', '\u{21f}', Lower), ('\u{220}', '\u{220}', Upper),
+    ('\u{221}', '\u{221}', Lower), ('\u{222}', '\u{222}', Upper), ('\u{223}', '\u{223}', Lower),
+    ('\u{224}', '\u{224}', Upper), ('\u{225}', '\u{225}', Lower), ('\u{226}', '\u{226}', Upper),
+    ('\u{227}', '\u{227}', Lower), ('\u{228}', '\u{228}', Upper), ('\u{229}', '\u{229}', Lower),
+    ('\u{22a}', '\u{22a}', Upper), ('\u{22b}', '\u{22b}', Lower), ('\u{22c}', '\u{22c}', Upper),
+    ('\u{22d}', '\u{22d}', Lower), ('\u{22e}', '\u{22e}', Upper), ('\u{22f}', '\u{22f}', Lower),
+    ('\u{230}', '\u{230}', Upper), ('\u{231}', '\u{231}', Lower), ('\u{232}', '\u{232}', Upper),
+    ('\u{233}', '\u{239}', Lower), ('\u{23a}', '\u{23b}', Upper), ('\u{23c}', '\u{23c}', Lower),
+    ('\u{23d}', '\u{23e}', Upper), ('\u{23f}', '\u{240}', Lower), ('\u{241}', '\u{241}', Upper),
+    ('\u{242}', '\u{242}', Lower), ('\u{243}', '\u{246}', Upper), ('\u{247}', '\u{247}', Lower),
+    ('\u{248}', '\u{248}', Upper), ('\u{249}', '\u{249}', Lower), ('\u{24a}', '\u{24a}', Upper),
+    ('\u{24b}', '\u{24b}', Lower), ('\u{24c}', '\u{24c}', Upper), ('\u{24d}', '\u{24d}', Lower),
+    ('\u{24e}', '\u{24e}', Upper), ('\u{24f}', '\u{293}', Lower), ('\u{294}', '\u{294}', OLetter),
+    ('\u{295}', '\u{2b8}', Lower), ('\u{2b9}', '\u{2bf}', OLetter), ('\u{2c0}', '\u{2c1}', Lower),
+    ('\u{2c6}', '\u{2d1}', OLetter), ('\u{2e0}', '\u{2e4}', Lower),
+    ('\u{2ec}', '\u{2ec}', OLetter), ('\u{2ee}', '\u{2ee}', OLetter),
+    ('\u{300}', '\u{36f}', Extend), ('\u{370}', '\u{370}', Upper), ('\u{371}', '\u{371}', Lower),
+    ('\u{372}', '\u{372}', Upper), ('\u{373}', '\u{373}', Lower), ('\u{374}', '\u{374}', OLetter),
+    ('\u{376}', '\u{376}', Upper), ('\u{377}', '\u{377}', Lower), ('\u{37a}', '\u{37d}', Lower),
+    ('\u{37e}', '\u{37e}', SContinue), ('\u{37f}', '\u{37f}', Upper),
+    ('\u{386}', '\u{386}', Upper), ('\u{388}', '\u{38a}', Upper), ('\u{38c}', '\u{38c}', Upper),
+    ('\u{38e}', '\u{38f}', Upper), ('\u{390}', '\u{390}', Lower), ('\u{391}', '\u{3a1}', Upper),
+    ('\u{3a3}', '\u{3ab}', Upper), ('\u{3ac}', '\u{3ce}', Lower), ('\u{3cf}', '\u{3cf}', Upper),
+    ('\u{3d0}', '\u{3d1}', Lower), ('\u{3d2}', '\u{3d4}', Upper), ('\u{3d5}', '\u{3d7}', Lower),
+    ('\u{3d8}', '\u{3d8}', Upper), ('\u{3d9}', '\u{3d9}', Lower), ('\u{3da}', '\u{3da}', Upper),
+    ('\u{3db}', '\u{3db}', Lower), ('\u{3dc}', '\u{3dc}', Upper), ('\u{3dd}', '\u{3dd}', Lower),
+    ('\u{3de}', '\u{3de}', Upper), ('\u{3df}', '\u{3df}', Lower), ('\u{3e0}', '\u{3e0}', Upper),
+    ('\u{3e1}', '\u{3e1}', Lower), ('\u{3e2}', '\u{3e2}', Upper), ('\u{3e3}', '\u{3e3}', Lower),
+    ('\u{3e4}', '\u{3e4}', Upper), ('\u{3e5}', '\u{3e5}', Lower), ('\u{3e6}', '\u{3e6}', Upper),
+    ('\u{3e7}', '\u{3e7}', Lower), ('\u{3e8}', '\u{3e8}', Upper), ('\u{3e9}', '\u{3e9}', Lower),
+    ('\u{3ea}', '\u{3ea}', Upper), ('\u{3eb}', '\u{3eb}', Lower), ('\u{3ec}', '\u{3ec}', Upper),
+    ('\u{3ed}', '\u{3ed}', Lower), ('\u{3ee}', '\u{3ee}', Upper), ('\u{3ef}', '\u{3f3}', Lower),
+    ('\u{3f4}', '\u{3f4}', Upper), ('\u{3f5}', '\u{3f5}', Lower), ('\u{3f7}', '\u{3f7}', Upper),
+    ('\u{3f8}', '\u{3f8}', Lower), ('\u{3f9}', '\u{3fa}', Upper), ('\u{3fb}', '\u{3fc}', Lower),
+    ('\u{3fd}', '\u{42f}', Upper), ('\u{430}', '\u{45f}', Lower), ('\u{460}', '\u{460}', Upper),
+    ('\u{461}', '\u{461}', Lower), ('\u{462}', '\u{462}', Upper), ('\u{463}', '\u{463}', Lower),
+    ('\u{464}', '\u{464}', Upper), ('\u{465}', '\u{465}', Lower), ('\u{466}', '\u{466}', Upper),
+    ('\u{467}', '\u{467}', Lower), ('\u{468}', '\u{468}', Upper), ('\u{469}', '\u{469}', Lower),
+    ('\u{46a}', '\u{46a}', Upper), ('\u{46b}', '\u{46b}', Lower), ('\u{46c}', '\u{46c}', Upper),
+    ('\u{46d}', '\u{46d}', Lower), ('\u{46e}', '\u{46e}', Upper), ('\u{46f}', '\u{46f}', Lower),
+    ('\u{470}', '\u{470}', Upper), ('\u{471}', '\u{471}', Lower), ('\u{472}', '\u{472}', Upper),
+    ('\u{473}', '\u{473}', Lower), ('\u{474}', '\u{474}', Upper), ('\u{475}', '\u{475}', Lower),
+    ('\u{476}', '\u{476}', Upper), ('\u{477}', '\u{477}', Lower), ('\u{478}', '\u{478}', Upper),
+    ('\u{479}', '\u{479}', Lower), ('\u{47a}', '\u{47a}', Upper), ('\u{47b}', '\u{47b}', Lower),
+    ('\u{47c}', '\u{47c}', Upper), ('\u{47d}', '\u{47d}', Lower), ('\u{47e}', '\u{47e}', Upper),
+    ('\u{47f}', '\u{47f}', Lower), ('\u{480}', '\u{480}', Upper), ('\u{481}', '\u{481}', Lower),
+    ('\u{483}', '\u{489}', Extend), ('\u{48a}', '\u{48a}', Upper), ('\u{48b}', '\u{48b}', Lower),
+    ('\u{48c}', '\u{48c}', Upper), ('\u{48d}', '\u{48d}', Lower), ('\u{48e}', '\u{48e}', Upper),
+    ('\u{48f}', '\u{48f}', Lower), ('\u{490}', '\u{490}', Upper), ('\u{491}', '\u{491}', Lower),
+    ('\u{492}', '\u{492}', Upper), ('\u{493}', '\u{493}', Lower), ('\u{494}', '\u{494}', Upper),
+    ('\u{495}', '\u{495}', Lower), ('\u{496}', '\u{496}', Upper), ('\u{497}', '\u{497}', Lower),
+    ('\u{498}', '\u{498}', Upper), ('\u{499}', '\u{499}', Lower), ('\u{49a}', '\u{49a}', Upper),
+    ('\u{49b}', '\u{49b}', Lower), ('\u{49c}', '\u{49c}', Upper), ('\u{49d}', '\u{49d}', Lower),
+    ('\u{49e}', '\u{49e}', Upper), ('\u{49f}', '\u{49f}', Lower), ('\u{4a0}', '\u{4a0}', Upper),
+    ('\u{4a1}', '\u{4a1}', Lower), ('\u{4a2}', '\u{4a2}', Upper), ('\u{4a3}', '\u{4a3}', Lower),
+    ('\u{4a4}', '\u{4a4}', Upper), ('\u{4a5}', '\u{4a5}', Lower), ('\u{4a6}', '\u{4a6}', Upper),
+    ('\u{4a7}', '\u{4a7}', Lower), ('\u{4a8}', '\u{4a8}', Upper), ('\u{4a9}', '\u{4a9}', Lower),
+    ('\u{4aa}', '\u{4aa}', Upper), ('\u{4ab}', '\u{4ab}', Lower), ('\u{4ac}', '\u{4ac}', Upper),
+    ('\u{4ad}', '\u{4ad}', Lower), ('\u{4ae}', '\u{4ae}', Upper), ('\u{4af}', '\u{4af}', Lower),
+    ('\u{4b0}', '\u{4b0}', Upper), ('\u{4b1}', '\u{4b1}', Lower), ('\u{4b2}', '\u{4b2}', Upper),
+    ('\u{4b3}', '\u{4b3}', Lower), ('\u{4b4}', '\u{4b4}', Upper), ('\u{4b5}', '\u{4b5}', Lower),
+    ('\u{4b6}', '\u{4b6}', Upper), ('\u{4b7}', '\u{4b7}', Lower), ('\u{4b8}', '\u{4b8}', Upper),
+    ('\u{4b9}', '\u{4b9}', Lower), ('\u{4ba}', '\u{4ba}', Upper), ('\u{4bb}', '\u{4bb}', Lower),
+    ('\u{4bc}', '\u{4bc}', Upper), ('\u{4bd}', '\u{4bd}', Lower), ('\u{4be}', '\u{4be}', Upper),
+    ('\u{4bf}', '\u{4bf}', Lower), ('\u{4c0}', '\u{4c1}', Upper), ('\u{4c2}', '\u{4c2}', Lower),
+    ('\u{4c3}', '\u{4c3}', Upper), ('\u{4c4}', '\u{4c4}', Lower), ('\u{4c5}', '\u{4c5}', Upper),
+    ('\u{4c6}', '\u{4c6}', Lower), ('\u{4c7}', '\u{4c7}', Upper), ('\u{4c8}', '\u{4c8}', Lower),
+    ('\u{4c9}', '\u{4c9}', Upper), ('\u{4ca}', '\u{4ca}', Lower), ('\u{4cb}', '\u{4cb}', Upper),
+    ('\u{4cc}', '\u{4cc}', Lower), ('\u{4cd}', '\u{4cd}', Upper), ('\u{4ce}', '\u{4cf}', Lower),
+    ('\u{4d0}', '\u{4d0}', Upper), ('\u{4d1}', '\u{4d1}', Lower), ('\u{4d2}', '\u{4d2}', Upper),
+    ('\u{4d3}', '\u{4d3}', Lower), ('\u{4d4}', '\u{4d4}', Upper), ('\u{4d5}', '\u{4d5}', Lower),
+    ('\u{4d6}', '\u{4d6}', Upper), ('\u{4d7}', '\u{4d7}', Lower), ('\u{4d8}', '\u{4d8}', Upper),
+    ('\u{4d9}', '\u{4d9}', Lower), ('\u{4da}', '\u{4da}', Upper), ('\u{4db}', '\u{4db}', Lower),
+    ('\u{4dc}', '\u{4dc}', Upper), ('\u{4dd}', '\u{4dd}', Lower), ('\u{4de}', '\u{4de}', Upper),
+    ('\u{4df}', '\u{4df}', Lower), ('\u{4e0}', '\u{4e0}', Upper), ('\u{4e1}', '\u{4e1}', Lower),
+    ('\u{4e2}', '\u{4e2}', Upper), ('\u{4e3}', '\u{4e3}', Lower), ('\u{4e4}', '\u{4e4}', Upper),
+    ('\u{4e5}', '\u{4e5}', Lower), ('\u{4e6}', '\u{4e6}', Upper), ('\u{4e7}', '\u{4e7}', Lower),
+    ('\u{4e8}', '\u{4e8}', Upper), ('\u{4e9}', '\u{4e9}', Lower), ('\u{4ea}', '\u{4ea}', Upper),
+    ('\u{4eb}', '\u{4eb}', Lower), ('\u{4ec}', '\u{4ec}', Upper), ('\u{4ed}', '\u{4ed}', Lower),
+    ('\u{4ee}', '\u{4ee}', Upper), ('\u{4ef}', '\u{4ef}', Lower), ('\u{4f0}', '\u{4f0}', Upper),
+    ('\u{4f1}', '\u{4f1}', Lower), ('\u{4f2}', '\u{4f2}', Upper), ('\u{4f3}', '\u{4f3}', Lower),
+    ('\u{4f4}', '\u{4f4}', Upper), ('\u{4f5}', '\u{4f5}', Lower), ('\u{4f6}', '\u{4f6}', Upper),
+    ('\u{4f7}', '\u{4f7}', Lower), ('\u{4f8}', '\u{4f8}', Upper), ('\u{4f9}', '\u{4f9}', Lower),
+    ('\u{4fa}', '\u{4fa}', Upper), ('\u{4fb}', '\u{4fb}', Lower), ('\u{4fc}', '\u{4fc}', Upper),
+    ('\u{4fd}', '\u{4fd}', Lower), ('\u{4fe}', '\u{4fe}', Upper), ('\u{4ff}', '\u{4ff}', Lower),
+    ('\u{500}', '\u{500}', Upper), ('\u{501}', '\u{501}', Lower), ('\u{502}', '\u{502}', Upper),
+    ('\u{503}', '\u{503}', Lower), ('\u{504}', '\u{504}', Upper), ('\u{505}', '\u{505}', Lower),
+    ('\u{506}', '\u{506}', Upper), ('\u{507}', '\u{507}', Lower), ('\u{508}', '\u{508}', Upper),
+    ('\u{509}', '\u{509}', Lower), ('\u{50a}', '\u{50a}', Upper), ('\u{50b}', '\u{50b}', Lower),
+    ('\u{50c}', '\u{50c}', Upper), ('\u{50d}', '\u{50d}', Lower), ('\u{50e}', '\u{50e}', Upper),
+    ('\u{50f}', '\u{50f}', Lower), ('\u{510}', '\u{510}', Upper), ('\u{511}', '\u{511}', Lower),
+    ('\u{512}', '\u{512}', Upper), ('\u{513}', '\u{513}', Lower), ('\u{514}', '\u{514}', Upper),
+    ('\u{515}', '\u{515}', Lower), ('\u{516}', '\u{516}', Upper), ('\u{517}', '\u{517}', Lower),
+    ('\u{518}', '\u{518}', Upper), ('\u{519}', '\u{519}', Lower), ('\u{51a}', '\u{51a}', Upper),
+    ('\u{51b}', '\u{51b}', Lower), ('\u{51c}', '\u{51c}', Upper), ('\u{51d}', '\u{51d}', Lower),
+    ('\u{51e}', '\u{51e}', Upper), ('\u{51f}', '\u{51f}', Lower), ('\u{520}', '\u{520}', Upper),
+    ('\u{521}', '\u{521}', Lower), ('\u{522}', '\u{522}', Upper), ('\u{523}', '\u{523}', Lower),
+    ('\u{524}', '\u{524}', Upper), ('\u{525}', '\u{525}', Lower), ('\u{526}', '\u{526}', Upper),
+    ('\u{527}', '\u{527}', Lower), ('\u{528}', '\u{528}', Upper), ('\u{529}', '\u{529}', Lower),
+    ('\u{52a}', '\u{52a}', Upper), ('\u{52b}', '\u{52b}', Lower), ('\u{52c}', '\u{52c}', Upper),
+    ('\u{52d}', '\u{52d}', Lower), ('\u{52e}', '\u{52e}', Upper), ('\u{52f}', '\u{52f}', Lower),
+    ('\u{531}', '\u{556}', Upper), ('\u{559}', '\u{559}', OLetter),
+    ('\u{55d}', '\u{55d}', SContinue), ('\u{560}', '\u{588}', Lower),
+    ('\u{589}', '\u{589}', STerm), ('\u{591}', '\u{5bd}', Extend), ('\u{5bf}', '\u{5bf}', Extend),
+    ('\u{5c1}', '\u{5c2}', Extend), ('\u{5c4}', '\u{5c5}', Extend), ('\u{5c7}', '\u{5c7}', Extend),
+    ('\u{5d0}', '\u{5ea}', OLetter), ('\u{5ef}', '\u{5f3}', OLetter),
+    ('\u{600}', '\u{605}', Numeric), ('\u{60c}', '\u{60d}', SContinue),
+    ('\u{610}', '\u{61a}', Extend), ('\u{61c}', '\u{61c}', Format), ('\u{61d}', '\u{61f}', STerm),
+    ('\u{620}', '\u{64a}', OLetter), ('\u{64b}', '\u{65f}', Extend),
+    ('\u{660}', '\u{669}', Numeric), ('\u{66b}', '\u{66c}', Numeric),
+    ('\u{66e}', '\u{66f}', OLetter), ('\u{670}', '\u{670}', Extend),
+    ('\u{671}', '\u{6d3}', OLetter), ('\u{6d4}', '\u{6d4}', STerm),
+    ('\u{6d5}', '\u{6d5}', OLetter), ('\u{6d6}', '\u{6dc}', Extend),
+    ('\u{6dd}', '\u{6dd}', Numeric), ('\u{6df}', '\u{6e4}', Extend),
+    ('\u{6e5}', '\u{6e6}', OLetter), ('\u{6e7}', '\u{6e8}', Extend),
+    ('\u{6ea}', '\u{6ed}', Extend), ('\u{6ee}', '\u{6ef}', OLetter),
+    ('\u{6f0}', '\u{6f9}', Numeric), ('\u{6fa}', '\u{6fc}', OLetter),
+    ('\u{6ff}', '\u{6ff}', OLetter), ('\u{700}', '\u{702}', STerm), ('\u{70f}', '\u{70f}', Format),
+    ('\u{710}', '\u{710}', OLetter), ('\u{711}', '\u{711}', Extend),
+    ('\u{712}', '\u{72f}', OLetter), ('\u{730}', '\u{74a}', Extend),
+    ('\u{74d}', '\u{7a5}', OLetter), ('\u{7a6}', '\u{7b0}', Extend),
+    ('\u{7b1}', '\u{7b1}', OLetter), ('\u{7c0}', '\u{7c9}', Numeric),
+    ('\u{7ca}', '\u{7ea}', OLetter), ('\u{7eb}', '\u{7f3}', Extend),
+    ('\u{7f4}', '\u{7f5}', OLetter), ('\u{7f8}', '\u{7f8}', SContinue),
+    ('\u{7f9}', '\u{7f9}', STerm), ('\u{7fa}', '\u{7fa}', OLetter), ('\u{7fd}', '\u{7fd}', Extend),
+    ('\u{800}', '\u{815}', OLetter), ('\u{816}', '\u{819}', Extend),
+    ('\u{81a}', '\u{81a}', OLetter), ('\u{81b}', '\u{823}', Extend),
+    ('\u{824}', '\u{824}', OLetter), ('\u{825}', '\u{827}', Extend),
+    ('\u{828}', '\u{828}', OLetter), ('\u{829}', '\u{82d}', Extend), ('\u{837}', '\u{837}', STerm),
+    ('\u{839}', '\u{839}', STerm), ('\u{83d}', '\u{83e}', STerm), ('\u{840}', '\u{858}', OLetter),
+    ('\u{859}', '\u{85b}', Extend), ('\u{860}', '\u{86a}', OLetter),
+    ('\u{870}', '\u{887}', OLetter), ('\u{889}', '\u{88e}', OLetter),
+    ('\u{890}', '\u{891}', Numeric), ('\u{897}', '\u{89f}', Extend),
+    ('\u{8a0}', '\u{8c9}', OLetter), ('\u{8ca}', '\u{8e1}', Extend),
+    ('\u{8e2}', '\u{8e2}', Numeric), ('\u{8e3}', '\u{903}', Extend),
+    ('\u{904}', '\u{939}', OLetter), ('\u{93a}', '\u{93c}', Extend),
+    ('\u{93d}', '\u{93d}', OLetter), ('\u{93e}', '\u{94f}', Extend),
+    ('\u{950}', '\u{950}', OLetter), ('\u{951}', '\u{957}', Extend),
+    ('\u{958}', '\u{961}', OLetter), ('\u{962}', '\u{963}', Extend), ('\u{964}', '\u{965}', STerm),
+    ('\u{966}', '\u{96f}', Numeric), ('\u{971}', '\u{980}', OLetter),
+    ('\u{981}', '\u{983}', Extend), ('\u{985}', '\u{98c}', OLetter),
+    ('\u{98f}', '\u{990}', OLetter), ('\u{993}', '\u{9a8}', OLetter),
+    ('\u{9aa}', '\u{9b0}', OLetter), ('\u{9b2}', '\u{9b2}', OLetter),
+    ('\u{9b6}', '\u{9b9}', OLetter), ('\u{9bc}', '\u{9bc}', Extend),
+    ('\u{9bd}', '\u{9bd}', OLetter), ('\u{9be}', '\u{9c4}', Extend),
+    ('\u{9c7}', '\u{9c8}', Extend), ('\u{9cb}', '\u{9cd}', Extend),
+    ('\u{9ce}', '\u{9ce}', OLetter), ('\u{9d7}', '\u{9d7}', Extend),
+    ('\u{9dc}', '\u{9dd}', OLetter), ('\u{9df}', '\u{9e1}', OLetter),
+    ('\u{9e2}', '\u{9e3}', Extend), ('\u{9e6}', '\u{9ef}', Numeric),
+    ('\u{9f0}', '\u{9f1}', OLetter), ('\u{9fc}', '\u{9fc}', OLetter),
+    ('\u{9fe}', '\u{9fe}', Extend), ('\u{a01}', '\u{a03}', Extend),
+    ('\u{a05}', '\u{a0a}', OLetter), ('\u{a0f}', '\u{a10}', OLetter),
+    ('\u{a13}', '\u{a28}', OLetter), ('\u{a2a}', '\u{a30}', OLetter),
+    ('\u{a32}', '\u{a33}', OLetter), ('\u{a35}', '\u{a36}', OLetter),
+    ('\u{a38}', '\u{a39}', OLetter), ('\u{a3c}', '\u{a3c}', Extend),
+    ('\u{a3e}', '\u{a42}', Extend), ('\u{a47}', '\u{a48}', Extend), ('\u{a4b}', '\u{a4d}', Extend),
+    ('\u{a51}', '\u{a51}', Extend), ('\u{a59}', '\u{a5c}', OLetter),
+    ('\u{a5e}', '\u{a5e}', OLetter), ('\u{a66}', '\u{a6f}', Numeric),
+    ('\u{a70}', '\u{a71}', Extend), ('\u{a72}', '\u{a74}', OLetter),
+    ('\u{a75}', '\u{a75}', Extend), ('\u{a81}', '\u{a83}', Extend),
+    ('\u{a85}', '\u{a8d}', OLetter), ('\u{a8f}', '\u{a91}', OLetter),
+    ('\u{a93}', '\u{aa8}', OLetter), ('\u{aaa}', '\u{ab0}', OLetter),
+    ('\u{ab2}', '\u{ab3}', OLetter), ('\u{ab5}', '\u{ab9}', OLetter),
+    ('\u{abc}', '\u{abc}', Extend), ('\u{abd}', '\u{abd}', OLetter),
+    ('\u{abe}', '\u{ac5}', Extend), ('\u{ac7}', '\u{ac9}', Extend), ('\u{acb}', '\u{acd}', Extend),
+    ('\u{ad0}', '\u{ad0}', OLetter), ('\u{ae0}', '\u{ae1}', OLetter),
+    ('\u{ae2}', '\u{ae3}', Extend), ('\u{ae6}', '\u{aef}', Numeric),
+    ('\u{af9}', '\u{af9}', OLetter), ('\u{afa}', '\u{aff}', Extend),
+    ('\u{b01}', '\u{b03}', Extend), ('\u{b05}', '\u{b0c}', OLetter),
+    ('\u{b0f}', '\u{b10}', OLetter), ('\u{b13}', '\u{b28}', OLetter),
+    ('\u{b2a}', '\u{b30}', OLetter), ('\u{b32}', '\u{b33}', OLetter),
+    ('\u{b35}', '\u{b39}', OLetter), ('\u{b3c}', '\u{b3c}', Extend),
+    ('\u{b3d}', '\u{b3d}', OLetter), ('\u{b3e}', '\u{b44}', Extend),
+    ('\u{b47}', '\u{b48}', Extend), ('\u{b4b}', '\u{b4d}', Extend), ('\u{b55}', '\u{b57}', Extend),
+    ('\u{b5c}', '\u{b5d}', OLetter), ('\u{b5f}', '\u{b61}', OLetter),
+    ('\u{b62}', '\u{b63}', Extend), ('\u{b66}', '\u{b6f}', Numeric),
+    ('\u{b71}', '\u{b71}', OLetter), ('\u{b82}', '\u{b82}', Extend),
+    ('\u{b83}', '\u{b83}', OLetter), ('\u{b85}', '\u{b8a}', OLetter),
+    ('\u{b8e}', '\u{b90}', OLetter), ('\u{b92}', '\u{b95}', OLetter),
+    ('\u{b99}', '\u{b9a}', OLetter), ('\u{b9c}', '\u{b9c}', OLetter),
+    ('\u{b9e}', '\u{b9f}', OLetter), ('\u{ba3}', '\u{ba4}', OLetter),
+    ('\u{ba8}', '\u{baa}', OLetter), ('\u{bae}', '\u{bb9}', OLetter),
+    ('\u{bbe}', '\u{bc2}', Extend), ('\u{bc6}', '\u{bc8}', Extend), ('\u{bca}', '\u{bcd}', Extend),
+    ('\u{bd0}', '\u{bd0}', OLetter), ('\u{bd7}', '\u{bd7}', Extend),
+    ('\u{be6}', '\u{bef}', Numeric), ('\u{c00}', '\u{c04}', Extend),
+    ('\u{c05}', '\u{c0c}', OLetter), ('\u{c0e}', '\u{c10}', OLetter),
+    ('\u{c12}', '\u{c28}', OLetter), ('\u{c2a}', '\u{c39}', OLetter),
+    ('\u{c3c}', '\u{c3c}', Extend), ('\u{c3d}', '\u{c3d}', OLetter),
+    ('\u{c3e}', '\u{c44}', Extend), ('\u{c46}', '\u{c48}', Extend), ('\u{c4a}', '\u{c4d}', Extend),
+    ('\u{c55}', '\u{c56}', Extend), ('\u{c58}', '\u{c5a}', OLetter),
+    ('\u{c5d}', '\u{c5d}', OLetter), ('\u{c60}', '\u{c61}', OLetter),
+    ('\u{c62}', '\u{c63}', Extend), ('\u{c66}', '\u{c6f}', Numeric),
+    ('\u{c80}', '\u{c80}', OLetter), ('\u{c81}', '\u{c83}', Extend),
+    ('\u{c85}', '\u{c8c}', OLetter), ('\u{c8e}', '\u{c90}', OLetter),
+    ('\u{c92}', '\u{ca8}', OLetter), ('\u{caa}', '\u{cb3}', OLetter),
+    ('\u{cb5}', '\u{cb9}', OLetter), ('\u{cbc}', '\u{cbc}', Extend),
+    ('\u{cbd}', '\u{cbd}', OLetter), ('\u{cbe}', '\u{cc4}', Extend),
+    ('\u{cc6}', '\u{cc8}', Extend), ('\u{cca}', '\u{ccd}', Extend), ('\u{cd5}', '\u{cd6}', Extend),
+    ('\u{cdd}', '\u{cde}', OLetter), ('\u{ce0}', '\u{ce1}', OLetter),
+    ('\u{ce2}', '\u{ce3}', Extend), ('\u{ce6}', '\u{cef}', Numeric),
+    ('\u{cf1}', '\u{cf2}', OLetter), ('\u{cf3}', '\u{cf3}', Extend),
+    ('\u{d00}', '\u{d03}', Extend), ('\u{d04}', '\u{d0c}', OLetter),
+    ('\u{d0e}', '\u{d10}', OLetter), ('\u{d12}', '\u{d3a}', OLetter),
+    ('\u{d3b}', '\u{d3c}', Extend), ('\u{d3d}', '\u{d3d}', OLetter),
+    ('\u{d3e}', '\u{d44}', Extend), ('\u{d46}', '\u{d48}', Extend), ('\u{d4a}', '\u{d4d}', Extend),
+    ('\u{d4e}', '\u{d4e}', OLetter), ('\u{d54}', '\u{d56}', OLetter),
+    ('\u{d57}', '\u{d57}', Extend), ('\u{d5f}', '\u{d61}', OLetter),
+    ('\u{d62}', '\u{d63}', Extend), ('\u{d66}', '\u{d6f}', Numeric),
+    ('\u{d7a}', '\u{d7f}', OLetter), ('\u{d81}', '\u{d83}', Extend),
+    ('\u{d85}', '\u{d96}', OLetter), ('\u{d9a}', '\u{db1}', OLetter),
+    ('\u{db3}', '\u{dbb}', OLetter), ('\u{dbd}', '\u{dbd}', OLetter),
+    ('\u{dc0}', '\u{dc6}', OLetter), ('\u{dca}', '\u{dca}', Extend),
+    ('\u{dcf}', '\u{dd4}', Extend), ('\u{dd6}', '\u{dd6}', Extend), ('\u{dd8}', '\u{ddf}', Extend),
+    ('\u{de6}', '\u{def}', Numeric), ('\u{df2}', '\u{df3}', Extend),
+    ('\u{e01}', '\u{e30}', OLetter), ('\u{e31}', '\u{e31}', Extend),
+    ('\u{e32}', '\u{e33}', OLetter), ('\u{e34}', '\u{e3a}', Extend),
+    ('\u{e40}', '\u{e46}', OLetter), ('\u{e47}', '\u{e4e}', Extend),
+    ('\u{e50}', '\u{e59}', Numeric), ('\u{e81}', '\u{e82}', OLetter),
+    ('\u{e84}', '\u{e84}', OLetter), ('\u{e86}', '\u{e8a}', OLetter),
+    ('\u{e8c}', '\u{ea3}', OLetter), ('\u{ea5}', '\u{ea5}', OLetter),
+    ('\u{ea7}', '\u{eb0}', OLetter), ('\u{eb1}', '\u{eb1}', Extend),
+    ('\u{eb2}', '\u{eb3}', OLetter), ('\u{eb4}', '\u{ebc}', Extend),
+    ('\u{ebd}', '\u{ebd}', OLetter), ('\u{ec0}', '\u{ec4}', OLetter),
+    ('\u{ec6}', '\u{ec6}', OLetter), ('\u{ec8}', '\u{ece}', Extend),
+    ('\u{ed0}', '\u{ed9}', Numeric), ('\u{edc}', '\u{edf}', OLetter),
+    ('\u{f00}', '\u{f00}', OLetter), ('\u{f18}', '\u{f19}', Extend),
+    ('\u{f20}', '\u{f29}', Numeric), ('\u{f35}', '\u{f35}', Extend),
+    ('\u{f37}', '\u{f37}', Extend), ('\u{f39}', '\u{f39}', Extend), ('\u{f3a}', '\u{f3d}', Close),
+    ('\u{f3e}', '\u{f3f}', Extend), ('\u{f40}', '\u{f47}', OLetter),
+    ('\u{f49}', '\u{f6c}', OLetter), ('\u{f71}', '\u{f84}', Extend),
+    ('\u{f86}', '\u{f87}', Extend), ('\u{f88}', '\u{f8c}', OLetter),
+    ('\u{f8d}', '\u{f97}', Extend), ('\u{f99}', '\u{fbc}', Extend), ('\u{fc6}', '\u{fc6}', Extend),
+    ('\u{1000}', '\u{102a}', OLetter), ('\u{102b}', '\u{103e}', Extend),
+    ('\u{103f}', '\u{103f}', OLetter), ('\u{1040}', '\u{1049}', Numeric),
+    ('\u{104a}', '\u{104b}', STerm), ('\u{1050}', '\u{1055}', OLetter),
+    ('\u{1056}', '\u{1059}', Extend), ('\u{105a}', '\u{105d}', OLetter),
+    ('\u{105e}', '\u{1060}', Extend), ('\u{1061}', '\u{1061}', OLetter),
+    ('\u{1062}', '\u{1064}', Extend), ('\u{1065}', '\u{1066}', OLetter),
+    ('\u{1067}', '\u{106d}', Extend), ('\u{106e}', '\u{1070}', OLetter),
+    ('\u{1071}', '\u{1074}', Extend), ('\u{1075}', '\u{1081}', OLetter),
+    ('\u{1082}', '\u{108d}', Extend), ('\u{108e}', '\u{108e}', OLetter),
+    ('\u{108f}', '\u{108f}', Extend), ('\u{1090}', '\u{1099}', Numeric),
+    ('\u{109a}', '\u{109d}', Extend), ('\u{10a0}', '\u{10c5}', Upper),
+    ('\u{10c7}', '\u{10c7}', Upper), ('\u{10cd}', '\u{10cd}', Upper),
+    ('\u{10d0}', '\u{10fa}', OLetter), ('\u{10fc}', '\u{10fc}', Lower),
+    ('\u{10fd}', '\u{1248}', OLetter), ('\u{124a}', '\u{124d}', OLetter),
+    ('\u{1250}', '\u{1256}', OLetter), ('\u{1258}', '\u{1258}', OLetter),
+    ('\u{125a}', '\u{125d}', OLetter), ('\u{1260}', '\u{1288}', OLetter),
+    ('\u{128a}', '\u{128d}', OLetter), ('\u{1290}', '\u{12b0}', OLetter),
+    ('\u{12b2}', '\u{12b5}', OLetter), ('\u{12b8}', '\u{12be}', OLetter),
+    ('\u{12c0}', '\u{12c0}', OLetter), ('\u{12c2}', '\u{12c5}', OLetter),
+    ('\u{12c8}', '\u{12d6}', OLetter), ('\u{12d8}', '\u{1310}', OLetter),
+    ('\u{1312}', '\u{1315}', OLetter), ('\u{1318}', '\u{135a}', OLetter),
+    ('\u{135d}', '\u{135f}', Extend), ('\u{1362}', '\u{1362}', STerm),
+    ('\u{1367}', '\u{1368}', STerm), ('\u{1380}', '\u{138f}', OLetter),
+    ('\u{13a0}', '\u{13f5}', Upper), ('\u{13f8}', '\u{13fd}', Lower),
+    ('\u{1401}', '\u{166c}', OLetter), ('\u{166e}', '\u{166e}', STerm),
+    ('\u{166f}', '\u{167f}', OLetter), ('\u{1680}', '\u{1680}', Sp),
+    ('\u{1681}', '\u{169a}', OLetter), ('\u{169b}', '\u{169c}', Close),
+    ('\u{16a0}', '\u{16ea}', OLetter), ('\u{16ee}', '\u{16f8}', OLetter),
+    ('\u{1700}', '\u{1711}', OLetter), ('\u{1712}', '\u{1715}', Extend),
+    ('\u{171f}', '\u{1731}', OLetter), ('\u{1732}', '\u{1734}', Extend),
+    ('\u{1735}', '\u{1736}', STerm), ('\u{1740}', '\u{1751}', OLetter),
+    ('\u{1752}', '\u{1753}', Extend), ('\u{1760}', '\u{176c}', OLetter),
+    ('\u{176e}', '\u{1770}', OLetter), ('\u{1772}', '\u{1773}', Extend),
+    ('\u{1780}', '\u{17b3}', OLetter), ('\u{17b4}', '\u{17d3}', Extend),
+    ('\u{17d4}', '\u{17d5}', STerm), ('\u{17d7}', '\u{17d7}', OLetter),
+    ('\u{17dc}', '\u{17dc}', OLetter), ('\u{17dd}', '\u{17dd}', Extend),
+    ('\u{17e0}', '\u{17e9}', Numeric), ('\u{1802}', '\u{1802}', SContinue),
+    ('\u{1803}', '\u{1803}', STerm), ('\u{1808}', '\u{1808}', SContinue),
+    ('\u{1809}', '\u{1809}', STerm), ('\u{180b}', '\u{180d}', Extend),
+    ('\u{180e}', '\u{180e}', Format), ('\u{180f}', '\u{180f}', Extend),
+    ('\u{1810}', '\u{1819}', Numeric), ('\u{1820}', '\u{1878}', OLetter),
+    ('\u{1880}', '\u{1884}', OLetter), ('\u{1885}', '\u{1886}', Extend),
+    ('\u{1887}', '\u{18a8}', OLetter), ('\u{18a9}', '\u{18a9}', Extend),
+    ('\u{18aa}', '\u{18aa}', OLetter), ('\u{18b0}', '\u{18f5}', OLetter),
+    ('\u{1900}', '\u{191e}', OLetter), ('\u{1920}', '\u{192b}', Extend),
+    ('\u{1930}', '\u{193b}', Extend), ('\u{1944}', '\u{1945}', STerm),
+    ('\u{1946}', '\u{194f}', Numeric), ('\u{1950}', '\u{196d}', OLetter),
+    ('\u{1970}', '\u{1974}', OLetter), ('\u{1980}', '\u{19ab}', OLetter),
+    ('\u{19b0}', '\u{19c9}', OLetter), ('\u{19d0}', '\u{19da}', Numeric),
+    ('\u{1a00}', '\u{1a16}', OLetter), ('\u{1a17}', '\u{1a1b}', Extend),
+    ('\u{1a20}', '\u{1a54}', OLetter), ('\u{1a55}', '\u{1a5e}', Extend),
+    ('\u{1a60}', '\u{1a7c}', Extend), ('\u{1a7f}', '\u{1a7f}', Extend),
+    ('\u{1a80}', '\u{1a89}', Numeric), ('\u{1a90}', '\u{1a99}', Numeric),
+    ('\u{1aa7}', '\u{1aa7}', OLetter), ('\u{1aa8}', '\u{1aab}', STerm),
+    ('\u{1ab0}', '\u{1ace}', Extend), ('\u{1b00}', '\u{1b04}', Extend),
+    ('\u{1b05}', '\u{1b33}', OLetter), ('\u{1b34}', '\u{1b44}', Extend),
+    ('\u{1b45}', '\u{1b4c}', OLetter), ('\u{1b4e}', '\u{1b4f}', STerm),
+    ('\u{1b50}', '\u{1b59}', Numeric), ('\u{1b5a}', '\u{1b5b}', STerm),
+    ('\u{1b5e}', '\u{1b5f}', STerm), ('\u{1b6b}', '\u{1b73}', Extend),
+    ('\u{1b7d}', '\u{1b7f}', STerm), ('\u{1b80}', '\u{1b82}', Extend),
+    ('\u{1b83}', '\u{1ba0}', OLetter), ('\u{1ba1}', '\u{1bad}', Extend),
+    ('\u{1bae}', '\u{1baf}', OLetter), ('\u{1bb0}', '\u{1bb9}', Numeric),
+    ('\u{1bba}', '\u{1be5}', OLetter), ('\u{1be6}', '\u{1bf3}', Extend),
+    ('\u{1c00}', '\u{1c23}', OLetter), ('\u{1c24}', '\u{1c37}', Extend),
+    ('\u{1c3b}', '\u{1c3c}', STerm), ('\u{1c40}', '\u{1c49}', Numeric),
+    ('\u{1c4d}', '\u{1c4f}', OLetter), ('\u{1c50}', '\u{1c59}', Numeric),
+    ('\u{1c5a}', '\u{1c7d}', OLetter), ('\u{1c7e}', '\u{1c7f}', STerm),
+    ('\u{1c80}', '\u{1c88}', Lower), ('\u{1c89}', '\u{1c89}', Upper),
+    ('\u{1c8a}', '\u{1c8a}', Lower), ('\u{1c90}', '\u{1cba}', OLetter),
+    ('\u{1cbd}', '\u{1cbf}', OLetter), ('\u{1cd0}', '\u{1cd2}', Extend),
+    ('\u{1cd4}', '\u{1ce8}', Extend), ('\u{1ce9}', '\u{1cec}', OLetter),
+    ('\u{1ced}', '\u{1ced}', Extend), ('\u{1cee}', '\u{1cf3}', OLetter),
+    ('\u{1cf4}', '\u{1cf4}', Extend), ('\u{1cf5}', '\u{1cf6}', OLetter),
+    ('\u{1cf7}', '\u{1cf9}', Extend), ('\u{1cfa}', '\u{1cfa}', OLetter),
+    ('\u{1d00}', '\u{1dbf}', Lower), ('\u{1dc0}', '\u{1dff}', Extend),
+    ('\u{1e00}', '\u{1e00}', Upper), ('\u{1e01}', '\u{1e01}', Lower),
+    ('\u{1e02}', '\u{1e02}', Upper), ('\u{1e03}', '\u{1e03}', Lower),
+    ('\u{1e04}', '\u{1e04}', Upper), ('\u{1e05}', '\u{1e05}', Lower),
+    ('\u{1e06}', '\u{1e06}', Upper), ('\u{1e07}', '\u{1e07}', Lower),
+    ('\u{1e08}', '\u{1e08}', Upper), ('\u{1e09}', '\u{1e09}', Lower),
+    ('\u{1e0a}', '\u{1e0a}', Upper), ('\u{1e0b}', '\u{1e0b}', Lower),
+    ('\u{1e0c}', '\u{1e0c}', Upper), ('\u{1e0d}', '\u{1e0d}', Lower),
+    ('\u{1e0e}', '\u{1e0e}', Upper), ('\u{1e0f}', '\u{1e0f}', Lower),
+    ('\u{1e10}', '\u{1e10}', Upper), ('\u{1e11}', '\u{1e11}', Lower),
+    ('\u{1e12}', '\u{1e12}', Upper), ('\u{1e13}', '\u{1e13}', Lower),
+    ('\u{1e14}', '\u{1e14}', Upper), ('\u{1e15}', '\u{1e15}', Lower),
+    ('\u{1e16}', '\u{1e16}', Upper), ('\u{1e17}', '\u{1e17}', Lower),
+    ('\u{1e18}', '\u{1e18}', Upper), ('\u{1e19}', '\u{1e19}', Lower),
+    ('\u{1e1a}', '\u{1e1a}', Upper), ('\u{1e1b}', '\u{1e1b}', Lower),
+    ('\u{1e1c}', '\u{1e1c}', Upper), ('\u{1e1d}', '\u{1e1d}', Lower),
+    ('\u{1e1e}', '\u{1e1e}', Upper), ('\u{1e1f}', '\u{1e1f}', Lower),
+    ('\u{1e20}', '\u{1e20}', Upper), ('\u{1e21}', '\u{1e21}', Lower),
+    ('\u{1e22}', '\u{1e22}', Upper), ('\u{1e23}', '\u{1e23}', Lower),
+    ('\u{1e24}', '\u{1e24}', Upper), ('\u{1e25}', '\u{1e25}', Lower),
+    ('\u{1e26}', '\u{1e26}', Upper), ('\u{1e27}', '\u{1e27}', Lower),
+    ('\u{1e28}', '\u{1e28}', Upper), ('\u{1e29}', '\u{1e29}', Lower),
+    ('\u{1e2a}', '\u{1e2a}', Upper), ('\u{1e2b}', '\u{1e2b}', Lower),
+    ('\u{1e2c}', '\u{1e2c}', Upper), ('\u{1e2d}', '\u{1e2d}', Lower),
+    ('\u{1e2e}', '\u{1e2e}', Upper), ('\u{1e2f}', '\u{1e2f}', Lower),
+    ('\u{1e30}', '\u{1e30}', Upper), ('\u{1e31}', '\u{1e31}', Lower),
+    ('\u{1e32}', '\u{1e32}', Upper), ('\u{1e33}', '\u{1e33}', Lower),
+    ('\u{1e34}', '\u{1e34}', Upper), ('\u{1e35}', '\u{1e35}', Lower),
+    ('\u{1e36}', '\u{1e36}', Upper), ('\u{1e37}', '\u{1e37}', Lower),
+    ('\u{1e38}', '\u{1e38}', Upper), ('\u{1e39}', '\u{1e39}', Lower),
+    ('\u{1e3a}', '\u{1e3a}', Upper), ('\u{1e3b}', '\u{1e3b}', Lower),
+    ('\u{1e3c}', '\u{1e3c}', Upper), ('\u{1e3d}', '\u{1e3d}', Lower),
+    ('\u{1e3e}', '\u{1e3e}', Upper), ('\u{1e3f}', '\u{1e3f}', Lower),
+    ('\u{1e40}', '\u{1e40}', Upper), ('\u{1e41}', '\u{1e41}', Lower),
+    ('\u{1e42}', '\u{1e42}', Upper), ('\u{1e43}', '\u{1e43}', Lower),
+    ('\u{1e44}', '\u{1e44}', Upper), ('\u{1e45}', '\u{1e45}', Lower),
+    ('\u{1e46}', '\u{1e46}', Upper), ('\u{1e47}', '\u{1e47}', Lower),
+    ('\u{1e48}', '\u{1e48}', Upper), ('\u{1e49}', '\u{1e49}', Lower),
+    ('\u{1e4a}', '\u{1e4a}', Upper), ('\u{1e4b}', '\u{1e4b}', Lower),
+    ('\u{1e4c}', '\u{1e4c}', Upper), ('\u{1e4d}', '\u{1e4d}', Lower),
+    ('\u{1e4e}', '\u{1e4e}', Upper), ('\u{1e4f}', '\u{1e4f}', Lower),
+    ('\u{1e50}', '\u{1e50}', Upper), ('\u{1e51}', '\u{1e51}', Lower),
+    ('\u{1e52}', '\u{1e52}', Upper), ('\u{1e53}', '\u{1e53}', Lower),
+    ('\u{1e54}', '\u{1e54}', Upper), ('\u{1e55}', '\u{1e55}', Lower),
+    ('\u{1e56}', '\u{1e56}', Upper), ('\u{1e57}', '\u{1e57}', Lower),
+    ('\u{1e58}', '\u{1e58}', Upper), ('\u{1e59}', '\u{1e59}', Lower),
+    ('\u{1e5a}', '\u{1e5a}', Upper), ('\u{1e5b}', '\u{1e5b}', Lower),
+    ('\u{1e5c}', '\u{1e5c}', Upper), ('\u{1e5d}', '\u{1e5d}', Lower),
+    ('\u{1e5e}', '\u{1e5e}', Upper), ('\u{1e5f}', '\u{1e5f}', Lower),
+    ('\u{1e60}', '\u{1e60}', Upper), ('\u{1e61}', '\u{1e61}', Lower),
+    ('\u{1e62}', '\u{1e62}', Upper), ('\u{1e63}', '\u{1e63}', Lower),
+    ('\u{1e64}', '\u{1e64}', Upper), ('\u{1e65}', '\u{1e65}', Lower),
+    ('\u{1e66}', '\u{1e66}', Upper), ('\u{1e67}', '\u{1e67}', Lower),
+    ('\u{1e68}', '\u{1e68}', Upper), ('\u{1e69}', '\u{1e69}', Lower),
+    ('\u{1e6a}', '\u{1e6a}', Upper), ('\u{1e6b}', '\u{1e6b}', Lower),
+    ('\u{1e6c}', '\u{1e6c}', Upper), ('\u{1e6d}', '\u{1e6d}', Lower),
+    ('\u{1e6e}', '\u{1e6e}', Upper), ('\u{1e6f}', '\u{1e6f}', Lower),
+    ('\u{1e70}', '\u{1e70}', Upper), ('\u{1e71}', '\u{1e71}', Lower),
+    ('\u{1e72}', '\u{1e72}', Upper), ('\u{1e73}', '\u{1e73}', Lower),
+    ('\u{1e74}', '\u{1e74}', Upper), ('\u{1e75}', '\u{1e75}', Lower),
+    ('\u{1e76}', '\u{1e76}', Upper), ('\u{1e77}', '\u{1e77}', Lower),
+    ('\u{1e78}', '\u{1e78}', Upper), ('\u{1e79}', '\u{1e79}', Lower),
+    ('\u{1e7a}', '\u{1e7a}', Upper), ('\u{1e7b}', '\u{1e7b}', Lower),
+    ('\u{1e7c}', '\u{1e7c}', Upper), ('\u{1e7d}', '\u{1e7d}', Lower),
+    ('\u{1e7e}', '\u{1e7e}', Upper), ('\u{1e7f}', '\u{1e7f}', Lower),
+    ('\u{1e80}', '\u{1e80}', Upper), ('\u{1e81}', '\u{1e81}', Lower),
+    ('\u{1e82}', '\u{1e82}', Upper), ('\u{1e83}', '\u{1e83}', Lower),
+    ('\u{1e84}', '\u{1e84}', Upper), ('\u{1e85}', '\u{1e85}', Lower),
+    ('\u{1e86}', '\u{1e86}', Upper), ('\u{1e87}', '\u{1e87}', Lower),
+    ('\u{1e88}', '\u{1e88}', Upper), ('\u{1e89}', '\u{1e89}', Lower),
+    ('\u{1e8a}', '\u{1e8a}', Upper), ('\u{1e8b}', '\u{1e8b}', Lower),
+    ('\u{1e8c}', '\u{1e8c}', Upper), ('\u{1e8d}', '\u{1e8d}', Lower),
+    ('\u{1e8e}', '\u{1e8e}', Upper), ('\u{1e8f}', '\u{1e8f}', Lower),
+    ('\u{1e90}', '\u{1e90}', Upper), ('\u{1e91}', '\u{1e91}', Lower),
+    ('\u{1e92}', '\u{1e92}', Upper), ('\u{1e93}', '\u{1e93}', Lower),
+    ('\u{1e94}', '\u{1e94}', Upper), ('\u{1e95}', '\u{1e9d}', Lower),
+    ('\u{1e9e}', '\u{1e9e}', Upper), ('\u{1e9f}', '\u{1e9f}', Lower),
+    ('\u{1ea0}', '\u{1ea0}', Upper), ('\u{1ea1}', '\u{1ea1}', Lower),
+    ('\u{1ea2}', '\u{1ea2}', Upper), ('\u{1ea3}', '\u{1ea3}', Lower),
+    ('\u{1ea4}', '\u{1ea4}', Upper), ('\u{1ea5}', '\u{1ea5}', Lower),
+    ('\u{1ea6}', '\u{1ea6}', Upper), ('\u{1ea7}', '\u{1ea7}', Lower),
+    ('\u{1ea8}', '\u{1ea8}', Upper), ('\u{1ea9}', '\u{1ea9}', Lower),
+    ('\u{1eaa}', '\u{1eaa}', Upper), ('\u{1eab}', '\u{1eab}', Lower),
+    ('\u{1eac}', '\u{1eac}', Upper), ('\u{1ead}', '\u{1ead}', Lower),
+    ('\u{1eae}', '\u{1eae}', Upper), ('\u{1eaf}', '\u{1eaf}', Lower),
+    ('\u{1eb0}', '\u{1eb0}', Upper), ('\u{1eb1}', '\u{1eb1}', Lower),
+    ('\u{1eb2}', '\u{1eb2}', Upper), ('\u{1eb3}', '\u{1eb3}', Lower),
+    ('\u{1eb4}', '\u{1eb4}', Upper), ('\u{1eb5}', '\u{1eb5}', Lower),
+    ('\u{1eb6}', '\u{1eb6}', Upper), ('\u{1eb7}', '\u{1eb7}', Lower),
+    ('\u{1eb8}', '\u{1eb8}', Upper), ('\u{1eb9}', '\u{1eb9}', Lower),
+    ('\u{1eba}', '\u{1eba}', Upper), ('\u{1ebb}', '\u{1ebb}', Lower),
+    ('\u{1ebc}', '\u{1ebc}', Upper), ('\u{1ebd}', '\u{1ebd}', Lower),
+    ('\u{1ebe}', '\u{1ebe}', Upper), ('\u{1ebf}', '\u{1ebf}', Lower),
+    ('\u{1ec0}', '\u{1ec0}', Upper), ('\u{1ec1}', '\u{1ec1}', Lower),
+    ('\u{1ec2}', '\u{1ec2}', Upper), ('\u{1ec3}', '\u{1ec3}', Lower),
+    ('\u{1ec4}', '\u{1ec4}', Upper), ('\u{1ec5}', '\u{1ec5}', Lower),
+    ('\u{1ec6}', '\u{1ec6}', Upper), ('\u{1ec7}', '\u{1ec7}', Lower),
+    ('\u{1ec8}', '\u{1ec8}', Upper), ('\u{1ec9}', '\u{1ec9}', Lower),
+    ('\u{1eca}', '\u{1eca}', Upper), ('\u{1ecb}', '\u{1ecb}', Lower),
+    ('\u{1ecc}', '\u{1ecc}', Upper), ('\u{1ecd}', '\u{1ecd}', Lower),
+    ('\u{1ece}', '\u{1ece}', Upper), ('\u{1ecf}', '\u{1ecf}', Lower),
+    ('\u{1ed0}', '\u{1ed0}', Upper), ('\u{1ed1}', '\u{1ed1}', Lower),
+    ('\u{1ed2}', '\u{1ed2}', Upper), ('\u{1ed3}', '\u{1ed3}', Lower),
+    ('\u{1ed4}', '\u{1ed4}', Upper), ('\u{1ed5}', '\u{1ed5}', Lower),
+    ('\u{1ed6}', '\u{1ed6}', Upper), ('\u{1ed7}', '\u{1ed7}', Lower),
+    ('\u{1ed8}', '\u{1ed8}', Upper), ('\u{1ed9}', '\u{1ed9}', Lower),
+    ('\u{1eda}', '\u{1eda}', Upper), ('\u{1edb}', '\u{1edb}', Lower),
+    ('\u{1edc}', '\u{1edc}', Upper), ('\u{1edd}', '\u{1edd}', Lower),
+    ('\u{1ede}', '\u{1ede}', Upper), ('\u{1edf}', '\u{1edf}', Lower),
+    ('\u{1ee0}', '\u{1ee0}', Upper), ('\u{1ee1}', '\u{1ee1}', Lower),
+    ('\u{1ee2}', '\u{1ee2}', Upper), ('\u{1ee3}', '\u{1ee3}', Lower),
+    ('\u{1ee4}', '\u{1ee4}', Upper), ('\u{1ee5}', '\u{1ee5}', Lower),
+    ('\u{1ee6}', '\u{1ee6}', Upper), ('\u{1ee7}', '\u{1ee7}', Lower),
+    ('\u{1ee8}', '\u{1ee8}', Upper), ('\u{1ee9}', '\u{1ee9}', Lower),
+    ('\u{1eea}', '\u{1eea}', Upper), ('\u{1eeb}', '\u{1eeb}', Lower),
+    ('\u{1eec}', '\u{1eec}', Upper), ('\u{1eed}', '\u{1eed}', Lower),
+    ('\u{1eee}', '\u{1eee}', Upper), ('\u{1eef}', '\u{1eef}', Lower),
+    ('\u{1ef0}', '\u{1ef0}', Upper), ('\u{1ef1}', '\u{1ef1}', Lower),
+    ('\u{1ef2}', '\u{1ef2}', Upper), ('\u{1ef3}', '\u{1ef3}', Lower),
+    ('\u{1ef4}', '\u{1ef4}', Upper), ('\u{1ef5}', '\u{1ef5}', Lower),
+    ('\u{1ef6}', '\u{1ef6}', Upper), ('\u{1ef7}', '\u{1ef7}', Lower),
+    ('\u{1ef8}', '\u{1ef8}', Upper), ('\u{1ef9}', '\u{1ef9}', Lower),
+    ('\u{1efa}', '\u{1efa}', Upper), ('\u{1efb}', '\u{1efb}', Lower),
+    ('\u{1efc}', '\u{1efc}', Upper), ('\u{1efd}', '\u{1efd}', Lower),
+    ('\u{1efe}', '\u{1efe}', Upper), ('\u{1eff}', '\u{1f07}', Lower),
+    ('\u{1f08}', '\u{1f0f}', Upper), ('\u{1f10}', '\u{1f15}', Lower),
+    ('\u{1f18}', '\u{1f1d}', Upper), ('\u{1f20}', '\u{1f27}', Lower),
+    ('\u{1f28}', '\u{1f2f}', Upper), ('\u{1f30}', '\u{1f37}', Lower),
+    ('\u{1f38}', '\u{1f3f}', Upper), ('\u{1f40}', '\u{1f45}', Lower),
+    ('\u{1f48}', '\u{1f4d}', Upper), ('\u{1f50}', '\u{1f57}', Lower),
+    ('\u{1f59}', '\u{1f59}', Upper), ('\u{1f5b}', '\u{1f5b}', Upper),
+    ('\u{1f5d}', '\u{1f5d}', Upper), ('\u{1f5f}', '\u{1f5f}', Upper),
+    ('\u{1f60}', '\u{1f67}', Lower), ('\u{1f68}', '\u{1f6f}', Upper),
+    ('\u{1f70}', '\u{1f7d}', Lower), ('\u{1f80}', '\u{1f87}', Lower),
+    ('\u{1f88}', '\u{1f8f}', Upper), ('\u{1f90}', '\u{1f97}', Lower),
+    ('\u{1f98}', '\u{1f9f}', Upper), ('\u{1fa0}', '\u{1fa7}', Lower),
+    ('\u{1fa8}', '\u{1faf}', Upper), ('\u{1fb0}', '\u{1fb4}', Lower),
+    ('\u{1fb6}', '\u{1fb7}', Lower), ('\u{1fb8}', '\u{1fbc}', Upper),
+    ('\u{1fbe}', '\u{1fbe}', Lower), ('\u{1fc2}', '\u{1fc4}', Lower),
+    ('\u{1fc6}', '\u{1fc7}', Lower), ('\u{1fc8}', '\u{1fcc}', Upper),
+    ('\u{1fd0}', '\u{1fd3}', Lower), ('\u{1fd6}', '\u{1fd7}', Lower),
+    ('\u{1fd8}', '\u{1fdb}', Upper), ('\u{1fe0}', '\u{1fe7}', Lower),
+    ('\u{1fe8}', '\u{1fec}', Upper), ('\u{1ff2}', '\u{1ff4}', Lower),
+    ('\u{1ff6}', '\u{1ff7}', Lower), ('\u{1ff8}', '\u{1ffc}', Upper), ('\u{2000}', '\u{200a}', Sp),
+    ('\u{200b}', '\u{200b}', Format), ('\u{200c}', '\u{200d}', Extend),
+    ('\u{200e}', '\u{200f}', Format), ('\u{2013}', '\u{2014}', SContinue),
+    ('\u{2018}', '\u{201f}', Close), ('\u{2024}', '\u{2024}', ATerm),
+    ('\u{2028}', '\u{2029}', Sep), ('\u{202a}', '\u{202e}', Format), ('\u{202f}', '\u{202f}', Sp),
+    ('\u{2039}', '\u{203a}', Close), ('\u{203c}', '\u{203d}', STerm),
+    ('\u{2045}', '\u{2046}', Close), ('\u{2047}', '\u{2049}', STerm), ('\u{205f}', '\u{205f}', Sp),
+    ('\u{2060}', '\u{2064}', Format), ('\u{2066}', '\u{206f}', Format),
+    ('\u{2071}', '\u{2071}', Lower), ('\u{207d}', '\u{207e}', Close),
+    ('\u{207f}', '\u{207f}', Lower), ('\u{208d}', '\u{208e}', Close),
+    ('\u{2090}', '\u{209c}', Lower), ('\u{20d0}', '\u{20f0}', Extend),
+    ('\u{2102}', '\u{2102}', Upper), ('\u{2107}', '\u{2107}', Upper),
+    ('\u{210a}', '\u{210a}', Lower), ('\u{210b}', '\u{210d}', Upper),
+    ('\u{210e}', '\u{210f}', Lower), ('\u{2110}', '\u{2112}', Upper),
+    ('\u{2113}', '\u{2113}', Lower), ('\u{2115}', '\u{2115}', Upper),
+    ('\u{2119}', '\u{211d}', Upper), ('\u{2124}', '\u{2124}', Upper),
+    ('\u{2126}', '\u{2126}', Upper), ('\u{2128}', '\u{2128}', Upper),
+    ('\u{212a}', '\u{212d}', Upper), ('\u{212f}', '\u{212f}', Lower),
+    ('\u{2130}', '\u{2133}', Upper), ('\u{2134}', '\u{2134}', Lower),
+    ('\u{2135}', '\u{2138}', OLetter), ('\u{2139}', '\u{2139}', Lower),
+    ('\u{213c}', '\u{213d}', Lower), ('\u{213e}', '\u{213f}', Upper),
+    ('\u{2145}', '\u{2145}', Upper), ('\u{2146}', '\u{2149}', Lower),
+    ('\u{214e}', '\u{214e}', Lower), ('\u{2160}', '\u{216f}', Upper),
+    ('\u{2170}', '\u{217f}', Lower), ('\u{2180}', '\u{2182}', OLetter),
+    ('\u{2183}', '\u{2183}', Upper), ('\u{2184}', '\u{2184}', Lower),
+    ('\u{2185}', '\u{2188}', OLetter), ('\u{2308}', '\u{230b}', Close),
+    ('\u{2329}', '\u{232a}', Close), ('\u{24b6}', '\u{24cf}', Upper),
+    ('\u{24d0}', '\u{24e9}', Lower), ('\u{275b}', '\u{2760}', Close),
+    ('\u{2768}', '\u{2775}', Close), ('\u{27c5}', '\u{27c6}', Close),
+    ('\u{27e6}', '\u{27ef}', Close), ('\u{2983}', '\u{2998}', Close),
+    ('\u{29d8}', '\u{29db}', Close), ('\u{29fc}', '\u{29fd}', Close),
+    ('\u{2c00}', '\u{2c2f}', Upper), ('\u{2c30}', '\u{2c5f}', Lower),
+    ('\u{2c60}', '\u{2c60}', Upper), ('\u{2c61}', '\u{2c61}', Lower),
+    ('\u{2c62}', '\u{2c64}', Upper), ('\u{2c65}', '\u{2c66}', Lower),
+    ('\u{2c67}', '\u{2c67}', Upper), ('\u{2c68}', '\u{2c68}', Lower),
+    ('\u{2c69}', '\u{2c69}', Upper), ('\u{2c6a}', '\u{2c6a}', Lower),
+    ('\u{2c6b}', '\u{2c6b}', Upper), ('\u{2c6c}', '\u{2c6c}', Lower),
+    ('\u{2c6d}', '\u{2c70}', Upper), ('\u{2c71}', '\u{2c71}', Lower),
+    ('\u{2c72}', '\u{2c72}', Upper), ('\u{2c73}', '\u{2c74}', Lower),
+    ('\u{2c75}', '\u{2c75}', Upper), ('\u{2c76}', '\u{2c7d}', Lower),
+    ('\u{2c7e}', '\u{2c80}', Upper), ('\u{2c81}', '\u{2c81}', Lower),
+    ('\u{2c82}', '\u{2c82}', Upper), ('\u{2c83}', '\u{2c83}', Lower),
+    ('\u{2c84}', '\u{2c84}', Upper), ('\u{2c85}', '\u{2c85}', Lower),
+    ('\u{2c86}', '\u{2c86}', Upper), ('\u{2c87}', '\u{2c87}', Lower),
+    ('\u{2c88}', '\u{2c88}', Upper), ('\u{2c89}', '\u{2c89}', Lower),
+    ('\u{2c8a}', '\u{2c8a}', Upper), ('\u{2c8b}', '\u{2c8b}', Lower),
+    ('\u{2c8c}', '\u{2c8c}', Upper), ('\u{2c8d}', '\u{2c8d}', Lower),
+    ('\u{2c8e}', '\u{2c8e}', Upper), ('\u{2c8f}', '\u{2c8f}', Lower),
+    ('\u{2c90}', '\u{2c90}', Upper), ('\u{2c91}', '\u{2c91}', Lower),
+    ('\u{2c92}', '\u{2c92}', Upper), ('\u{2c93}', '\u{2c93}', Lower),
+    ('\u{2c94}', '\u{2c94}', Upper), ('\u{2c95}', '\u{2c95}', Lower),
+    ('\u{2c96}', '\u{2c96}', Upper), ('\u{2c97}', '\u{2c97}', Lower),
+    ('\u{2c98}', '\u{2c98}', Upper), ('\u{2c99}', '\u{2c99}', Lower),
+    ('\u{2c9a}', '\u{2c9a}', Upper), ('\u{2c9b}', '\u{2c9b}', Lower),
+    ('\u{2c9c}', '\u{2c9c}', Upper), ('\u{2c9d}', '\u{2c9d}', Lower),
+    ('\u{2c9e}', '\u{2c9e}', Upper), ('\u{2c9f}', '\u{2c9f}', Lower),
+    ('\u{2ca0}', '\u{2ca0}', Upper), ('\u{2ca1}', '\u{2ca1}', Lower),
+    ('\u{2ca2}', '\u{2ca2}', Upper), ('\u{2ca3}', '\u{2ca3}', Lower),
+    ('\u{2ca4}', '\u{2ca4}', Upper), ('\u{2ca5}', '\u{2ca5}', Lower),
+    ('\u{2ca6}', '\u{2ca6}', Upper), ('\u{2ca7}', '\u{2ca7}', Lower),
+    ('\u{2ca8}', '\u{2ca8}', Upper), ('\u{2ca9}', '\u{2ca9}', Lower),
+    ('\u{2caa}', '\u{2caa}', Upper), ('\u{2cab}', '\u{2cab}', Lower),
+    ('\u{2cac}', '\u{2cac}', Upper), ('\u{2cad}', '\u{2cad}', Lower),
+    ('\u{2cae}', '\u{2cae}', Upper), ('\u{2caf}', '\u{2caf}', Lower),
+    ('\u{2cb0}', '\u{2cb0}', Upper), ('\u{2cb1}', '\u{2cb1}', Lower),
+    ('\u{2cb2}', '\u{2cb2}', Upper), ('\u{2cb3}', '\u{2cb3}', Lower),
+    ('\u{2cb4}', '\u{2cb4}', Upper), ('\u{2cb5}', '\u{2cb5}', Lower),
+    ('\u{2cb6}', '\u{2cb6}', Upper), ('\u{2cb7}', '\u{2cb7}', Lower),
+    ('\u{2cb8}', '\u{2cb8}', Upper), ('\u{2cb9}', '\u{2cb9}', Lower),
+    ('\u{2cba}', '\u{2cba}', Upper), ('\u{2cbb}', '\u{2cbb}', Lower),
+    ('\u{2cbc}', '\u{2cbc}', Upper), ('\u{2cbd}', '\u{2cbd}', Lower),
+    ('\u{2cbe}', '\u{2cbe}', Upper), ('\u{2cbf}', '\u{2cbf}', Lower),
+    ('\u{2cc0}', '\u{2cc0}', Upper), ('\u{2cc1}', '\u{2cc1}', Lower),
+    ('\u{2cc2}', '\u{2cc2}', Upper), ('\u{2cc3}', '\u{2cc3}', Lower),
+    ('\u{2cc4}', '\u{2cc4}', Upper), ('\u{2cc5}', '\u{2cc5}', Lower),
+    ('\u{2cc6}', '\u{2cc6}', Upper), ('\u{2cc7}', '\u{2cc7}', Lower),
+    ('\u{2cc8}', '\u{2cc8}', Upper), ('\u{2cc9}', '\u{2cc9}', Lower),
+    ('\u{2cca}', '\u{2cca}', Upper), ('\u{2ccb}', '\u{2ccb}', Lower),
+    ('\u{2ccc}', '\u{2ccc}', Upper), ('\u{2ccd}', '\u{2ccd}', Lower),
+    ('\u{2cce}', '\u{2cce}', Upper), ('\u{2ccf}', '\u{2ccf}', Lower),
+    ('\u{2cd0}', '\u{2cd0}', Upper), ('\u{2cd1}', '\u{2cd1}', Lower),
+    ('\u{2cd2}', '\u{2cd2}', Upper), ('\u{2cd3}', '\u{2cd3}', Lower),
+    ('\u{2cd4}', '\u{2cd4}', Upper), ('\u{2cd5}', '\u{2cd5}', Lower),
+    ('\u{2cd6}', '\u{2cd6}', Upper), ('\u{2cd7}', '\u{2cd7}', Lower),
+    ('\u{2cd8}', '\u{2cd8}', Upper), ('\u{2cd9}', '\u{2cd9}', Lower),
+    ('\u{2cda}', '\u{2cda}', Upper), ('\u{2cdb}', '\u{2cdb}', Lower),
+    ('\u{2cdc}', '\u{2cdc}', Upper), ('\u{2cdd}', '\u{2cdd}', Lower),
+    ('\u{2cde}', '\u{2cde}', Upper), ('\u{2cdf}', '\u{2cdf}', Lower),
+    ('\u{2ce0}', '\u{2ce0}', Upper), ('\u{2ce1}', '\u{2ce1}', Lower),
+    ('\u{2ce2}', '\u{2ce2}', Upper), ('\u{2ce3}', '\u{2ce4}', Lower),
+    ('\u{2ceb}', '\u{2ceb}', Upper), ('\u{2cec}', '\u{2cec}', Lower),
+    ('\u{2ced}', '\u{2ced}', Upper), ('\u{2cee}', '\u{2cee}', Lower),
+    ('\u{2cef}', '\u{2cf1}', Extend), ('\u{2cf2}', '\u{2cf2}', Upper),
+    ('\u{2cf3}', '\u{2cf3}', Lower), ('\u{2cf9}', '\u{2cfb}', STerm),
+    ('\u{2d00}', '\u{2d25}', Lower), ('\u{2d27}', '\u{2d27}', Lower),
+    ('\u{2d2d}', '\u{2d2d}', Lower), ('\u{2d30}', '\u{2d67}', OLetter),
+    ('\u{2d6f}', '\u{2d6f}', OLetter), ('\u{2d7f}', '\u{2d7f}', Extend),
+    ('\u{2d80}', '\u{2d96}', OLetter), ('\u{2da0}', '\u{2da6}', OLetter),
+    ('\u{2da8}', '\u{2dae}', OLetter), ('\u{2db0}', '\u{2db6}', OLetter),
+    ('\u{2db8}', '\u{2dbe}', OLetter), ('\u{2dc0}', '\u{2dc6}', OLetter),
+    ('\u{2dc8}', '\u{2dce}', OLetter), ('\u{2dd0}', '\u{2dd6}', OLetter),
+    ('\u{2dd8}', '\u{2dde}', OLetter), ('\u{2de0}', '\u{2dff}', Extend),
+    ('\u{2e00}', '\u{2e0d}', Close), ('\u{2e1c}', '\u{2e1d}', Close),
+    ('\u{2e20}', '\u{2e29}', Close), ('\u{2e2e}', '\u{2e2e}', STerm),
+    ('\u{2e2f}', '\u{2e2f}', OLetter), ('\u{2e3c}', '\u{2e3c}', STerm),
+    ('\u{2e42}', '\u{2e42}', Close), ('\u{2e53}', '\u{2e54}', STerm),
+    ('\u{2e55}', '\u{2e5c}', Close), ('\u{3000}', '\u{3000}', Sp),
+    ('\u{3001}', '\u{3001}', SContinue), ('\u{3002}', '\u{3002}', STerm),
+    ('\u{3005}', '\u{3007}', OLetter), ('\u{3008}', '\u{3011}', Close),
+    ('\u{3014}', '\u{301b}', Close), ('\u{301d}', '\u{301f}', Close),
+    ('\u{3021}', '\u{3029}', OLetter), ('\u{302a}', '\u{302f}', Extend),
+    ('\u{3031}', '\u{3035}', OLetter), ('\u{3038}', '\u{303c}', OLetter),
+    ('\u{3041}', '\u{3096}', OLetter), ('\u{3099}', '\u{309a}', Extend),
+    ('\u{309d}', '\u{309f}', OLetter), ('\u{30a1}', '\u{30fa}', OLetter),
+    ('\u{30fc}', '\u{30ff}', OLetter), ('\u{3105}', '\u{312f}', OLetter),
+    ('\u{3131}', '\u{318e}', OLetter), ('\u{31a0}', '\u{31bf}', OLetter),
+    ('\u{31f0}', '\u{31ff}', OLetter), ('\u{3400}', '\u{4dbf}', OLetter),
+    ('\u{4e00}', '\u{a48c}', OLetter), ('\u{a4d0}', '\u{a4fd}', OLetter),
+    ('\u{a4ff}', '\u{a4ff}', STerm), ('\u{a500}', '\u{a60c}', OLetter),
+    ('\u{a60e}', '\u{a60f}', STerm), ('\u{a610}', '\u{a61f}', OLetter),
+    ('\u{a620}', '\u{a629}', Numeric), ('\u{a62a}', '\u{a62b}', OLetter),
+    ('\u{a640}', '\u{a640}', Upper), ('\u{a641}', '\u{a641}', Lower),
+    ('\u{a642}', '\u{a642}', Upper), ('\u{a643}', '\u{a643}', Lower),
+    ('\u{a644}', '\u{a644}', Upper), ('\u{a645}', '\u{a645}', Lower),
+    ('\u{a646}', '\u{a646}', Upper), ('\u{a647}', '\u{a647}', Lower),
+    ('\u{a648}', '\u{a648}', Upper), ('\u{a649}', '\u{a649}', Lower),
+    ('\u{a64a}', '\u{a64a}', Upper), ('\u{a64b}', '\u{a64b}', Lower),
+    ('\u{a64c}', '\u{a64c}', Upper), ('\u{a64d}', '\u{a64d}', Lower),
+    ('\u{a64e}', '\u{a64e}', Upper), ('\u{a64f}', '\u{a64f}', Lower),
+    ('\u{a650}', '\u{a650}', Upper), ('\u{a651}', '\u{a651}', Lower),
+    ('\u{a652}', '\u{a652}', Upper), ('\u{a653}', '\u{a653}', Lower),
+    ('\u{a654}', '\u{a654}', Upper), ('\u{a655}', '\u{a655}', Lower),
+    ('\u{a656}', '\u{a656}', Upper), ('\u{a657}', '\u{a657}', Lower),
+    ('\u{a658}', '\u{a658}', Upper), ('\u{a659}', '\u{a659}', Lower),
+    ('\u{a65a}', '\u{a65a}', Upper), ('\u{a65b}', '\u{a65b}', Lower),
+    ('\u{a65c}', '\u{a65c}', Upper), ('\u{a65d}', '\u{a65d}', Lower),
+    ('\u{a65e}', '\u{a65e}', Upper), ('\u{a65f}', '\u{a65f}', Lower),
+    ('\u{a660}', '\u{a660}', Upper), ('\u{a661}', '\u{a661}', Lower),
+    ('\u{a662}', '\u{a662}', Upper), ('\u{a663}', '\u{a663}', Lower),
+    ('\u{a664}', '\u{a664}', Upper), ('\u{a665}', '\u{a665}', Lower),
+    ('\u{a666}', '\u{a666}', Upper), ('\u{a667}', '\u{a667}', Lower),
+    ('\u{a668}', '\u{a668}', Upper), ('\u{a669}', '\u{a669}', Lower),
+    ('\u{a66a}', '\u{a66a}', Upper), ('\u{a66b}', '\u{a66b}', Lower),
+    ('\u{a66c}', '\u{a66c}', Upper), ('\u{a66d}', '\u{a66d}', Lower),
+    ('\u{a66e}', '\u{a66e}', OLetter), ('\u{a66f}', '\u{a672}', Extend),
+    ('\u{a674}', '\u{a67d}', Extend), ('\u{a67f}', '\u{a67f}', OLetter),
+    ('\u{a680}', '\u{a680}', Upper), ('\u{a681}', '\u{a681}', Lower),
+    ('\u{a682}', '\u{a682}', Upper), ('\u{a683}', '\u{a683}', Lower),
+    ('\u{a684}', '\u{a684}', Upper), ('\u{a685}', '\u{a685}', Lower),
+    ('\u{a686}', '\u{a686}', Upper), ('\u{a687}', '\u{a687}', Lower),
+    ('\u{a688}', '\u{a688}', Upper), ('\u{a689}', '\u{a689}', Lower),
+    ('\u{a68a}', '\u{a68a}', Upper), ('\u{a68b}', '\u{a68b}', Lower),
+    ('\u{a68c}', '\u{a68c}', Upper), ('\u{a68d}', '\u{a68d}', Lower),
+    ('\u{a68e}', '\u{a68e}', Upper), ('\u{a68f}', '\u{a68f}', Lower),
+    ('\u{a690}', '\u{a690}', Upper), ('\u{a691}', '\u{a691}', Lower),
+    ('\u{a692}', '\u{a692}', Upper), ('\u{a693}', '\u{a693}', Lower),
+    ('\u{a694}', '\u{a694}', Upper), ('\u{a695}', '\u{a695}', Lower),
+    ('\u{a696}', '\u{a696}', Upper), ('\u{a697}', '\u{a697}', Lower),
+    ('\u{a698}', '\u{a698}', Upper), ('\u{a699}', '\u{a699}', Lower),
+    ('\u{a69a}', '\u{a69a}', Upper), ('\u{a69b}', '\u{a69d}', Lower),
+    ('\u{a69e}', '\u{a69f}', Extend), ('\u{a6a0}', '\u{a6ef}', OLetter),
+    ('\u{a6f0}', '\u{a6f1}', Extend), ('\u{a6f3}', '\u{a6f3}', STerm),
+    ('\u{a6f7}', '\u{a6f7}', STerm), ('\u{a717}', '\u{a71f}', OLetter),
+    ('\u{a722}', '\u{a722}', Upper), ('\u{a723}', '\u{a723}', Lower),
+    ('\u{a724}', '\u{a724}', Upper), ('\u{a725}', '\u{a725}', Lower),
+    ('\u{a726}', '\u{a726}', Upper), ('\u{a727}', '\u{a727}', Lower),
+    ('\u{a728}', '\u{a728}', Upper), ('\u{a729}', '\u{a729}', Lower),
+    ('\u{a72a}', '\u{a72a}', Upper), ('\u{a72b}', '\u{a72b}', Lower),
+    ('\u{a72c}', '\u{a72c}', Upper), ('\u{a72d}', '\u{a72d}', Lower),
+    ('\u{a72e}', '\u{a72e}', Upper), ('\u{a72f}', '\u{a731}', Lower),
+    ('\u{a732}', '\u{a732}', Upper), ('\u{a733}', '\u{a733}', Lower),
+    ('\u{a734}', '\u{a734}', Upper), ('\u{a735}', '\u{a735}', Lower),
+    ('\u{a736}', '\u{a736}', Upper), ('\u{a737}', '\u{a737}', Lower),
+    ('\u{a738}', '\u{a738}', Upper), ('\u{a739}', '\u{a739}', Lower),
+    ('\u{a73a}', '\u{a73a}', Upper), ('\u{a73b}', '\u{a73b}', Lower),
+    ('\u{a73c}', '\u{a73c}', Upper), ('\u{a73d}', '\u{a73d}', Lower),
+    ('\u{a73e}', '\u{a73e}', Upper), ('\u{a73f}', '\u{a73f}', Lower),
+    ('\u{a740}', '\u{a740}', Upper), ('\u{a741}', '\u{a741}', Lower),
+    ('\u{a742}', '\u{a742}', Upper), ('\u{a743}', '\u{a743}', Lower),
+    ('\u{a744}', '\u{a744}', Upper), ('\u{a745}', '\u{a745}', Lower),
+    ('\u{a746}', '\u{a746}', Upper), ('\u{a747}', '\u{a747}', Lower),
+    ('\u{a748}', '\u{a748}', Upper), ('\u{a749}', '\u{a749}', Lower),
+    ('\u{a74a}', '\u{a74a}', Upper), ('\u{a74b}', '\u{a74b}', Lower),
+    ('\u{a74c}', '\u{a74c}', Upper), ('\u{a74d}', '\u{a74d}', Lower),
+    ('\u{a74e}', '\u{a74e}', Upper), ('\u{a74f}', '\u{a74f}', Lower),
+    ('\u{a750}', '\u{a750}', Upper), ('\u{a751}', '\u{a751}', Lower),
+    ('\u{a752}', '\u{a752}', Upper), ('\u{a753}', '\u{a753}', Lower),
+    ('\u{a754}', '\u{a754}', Upper), ('\u{a755}', '\u{a755}', Lower),
+    ('\u{a756}', '\u{a756}', Upper), ('\u{a757}', '\u{a757}', Lower),
+    ('\u{a758}', '\u{a758}', Upper), ('\u{a759}', '\u{a759}', Lower),
+    ('\u{a75a}', '\u{a75a}', Upper), ('\u{a75b}', '\u{a75b}', Lower),
+    ('\u{a75c}', '\u{a75c}', Upper), ('\u{a75d}', '\u{a75d}', Lower),
+    ('\u{a75e}', '\u{a75e}', Upper), ('\u{a75f}', '\u{a75f}', Lower),
+    ('\u{a760}', '\u{a760}', Upper), ('\u{a761}', '\u{a761}', Lower),
+    ('\u{a762}', '\u{a762}', Upper), ('\u{a763}', '\u{a763}', Lower),
+    ('\u{a764}', '\u{a764}', Upper), ('\u{a765}', '\u{a765}', Lower),
+    ('\u{a766}', '\u{a766}', Upper), ('\u{a767}', '\u{a767}', Lower),
+    ('\u{a768}', '\u{a768}', Upper), ('\u{a769}', '\u{a769}', Lower),
+    ('\u{a76a}', '\u{a76a}', Upper), ('\u{a76b}', '\u{a76b}', Lower),
+    ('\u{a76c}', '\u{a76c}', Upper), ('\u{a76d}', '\u{a76d}', Lower),
+    ('\u{a76e}', '\u{a76e}', Upper), ('\u{a76f}', '\u{a778}', Lower),
+    ('\u{a779}', '\u{a779}', Upper), ('\u{a77a}', '\u{a77a}', Lower),
+    ('\u{a77b}', '\u{a77b}', Upper), ('\u{a77c}', '\u{a77c}', Lower),
+    ('\u{a77d}', '\u{a77e}', Upper), ('\u{a77f}', '\u{a77f}', Lower),
+    ('\u{a780}', '\u{a780}', Upper), ('\u{a781}', '\u{a781}', Lower),
+    ('\u{a782}', '\u{a782}', Upper), ('\u{a783}', '\u{a783}', Lower),
+    ('\u{a784}', '\u{a784}', Upper), ('\u{a785}', '\u{a785}', Lower),
+    ('\u{a786}', '\u{a786}', Upper), ('\u{a787}', '\u{a787}', Lower),
+    ('\u{a788}', '\u{a788}', OLetter), ('\u{a78b}', '\u{a78b}', Upper),
+    ('\u{a78c}', '\u{a78c}', Lower), ('\u{a78d}', '\u{a78d}', Upper),
+    ('\u{a78e}', '\u{a78e}', Lower), ('\u{a78f}', '\u{a78f}', OLetter),
+    ('\u{a790}', '\u{a790}', Upper), ('\u{a791}', '\u{a791}', Lower),
+    ('\u{a792}', '\u{a792}', Upper), ('\u{a793}', '\u{a795}', Lower),
+    ('\u{a796}', '\u{a796}', Upper), ('\u{a797}', '\u{a797}', Lower),
+    ('\u{a798}', '\u{a798}', Upper), ('\u{a799}', '\u{a799}', Lower),
+    ('\u{a79a}', '\u{a79a}', Upper), ('\u{a79b}', '\u{a79b}', Lower),
+    ('\u{a79c}', '\u{a79c}', Upper), ('\u{a79d}', '\u{a79d}', Lower),
+    ('\u{a79e}', '\u{a79e}', Upper), ('\u{a79f}', '\u{a79f}', Lower),
+    ('\u{a7a0}', '\u{a7a0}', Upper), ('\u{a7a1}', '\u{a7a1}', Lower),
+    ('\u{a7a2}', '\u{a7a2}', Upper), ('\u{a7a3}', '\u{a7a3}', Lower),
+    ('\u{a7a4}', '\u{a7a4}', Upper), ('\u{a7a5}', '\u{a7a5}', Lower),
+    ('\u{a7a6}', '\u{a7a6}', Upper), ('\u{a7a7}', '\u{a7a7}', Lower),
+    ('\u{a7a8}', '\u{a7a8}', Upper), ('\u{a7a9}', '\u{a7a9}', Lower),
+    ('\u{a7aa}', '\u{a7ae}', Upper), ('\u{a7af}', '\u{a7af}', Lower),
+    ('\u{a7b0}', '\u{a7b4}', Upper), ('\u{a7b5}', '\u{a7b5}', Lower),
+    ('\u{a7b6}', '\u{a7b6}', Upper), ('\u{a7b7}', '\u{a7b7}', Lower),
+    ('\u{a7b8}', '\u{a7b8}', Upper), ('\u{a7b9}', '\u{a7b9}', Lower),
+    ('\u{a7ba}', '\u{a7ba}', Upper), ('\u{a7bb}', '\u{a7bb}', Lower),
+    ('\u{a7bc}', '\u{a7bc}', Upper), ('\u{a7bd}', '\u{a7bd}', Lower),
+    ('\u{a7be}', '\u{a7be}', Upper), ('\u{a7bf}', '\u{a7bf}', Lower),
+    ('\u{a7c0}', '\u{a7c0}', Upper), ('\u{a7c1}', '\u{a7c1}', Lower),
+    ('\u{a7c2}', '\u{a7c2}', Upper), ('\u{a7c3}', '\u{a7c3}', Lower),
+    ('\u{a7c4}', '\u{a7c7}', Upper), ('\u{a7c8}', '\u{a7c8}', Lower),
+    ('\u{a7c9}', '\u{a7c9}', Upper), ('\u{a7ca}', '\u{a7ca}', Lower),
+    ('\u{a7cb}', '\u{a7cc}', Upper), ('\u{a7cd}', '\u{a7cd}', Lower),
+    ('\u{a7d0}', '\u{a7d0}', Upper), ('\u{a7d1}', '\u{a7d1}', Lower),
+    ('\u{a7d3}', '\u{a7d3}', Lower), ('\u{a7d5}', '\u{a7d5}', Lower),
+    ('\u{a7d6}', '\u{a7d6}', Upper), ('\u{a7d7}', '\u{a7d7}', Lower),
+    ('\u{a7d8}', '\u{a7d8}', Upper), ('\u{a7d9}', '\u{a7d9}', Lower),
+    ('\u{a7da}', '\u{a7da}', Upper), ('\u{a7db}', '\u{a7db}', Lower),
+    ('\u{a7dc}', '\u{a7dc}', Upper), ('\u{a7f2}', '\u{a7f4}', Lower),
+    ('\u{a7f5}', '\u{a7f5}', Upper), ('\u{a7f6}', '\u{a7f6}', Lower),
+    ('\u{a7f7}', '\u{a7f7}', OLetter), ('\u{a7f8}', '\u{a7fa}', Lower),
+    ('\u{a7fb}', '\u{a801}', OLetter), ('\u{a802}', '\u{a802}', Extend),
+    ('\u{a803}', '\u{a805}', OLetter), ('\u{a806}', '\u{a806}', Extend),
+    ('\u{a807}', '\u{a80a}', OLetter), ('\u{a80b}', '\u{a80b}', Extend),
+    ('\u{a80c}', '\u{a822}', OLetter), ('\u{a823}', '\u{a827}', Extend),
+    ('\u{a82c}', '\u{a82c}', Extend), ('\u{a840}', '\u{a873}', OLetter),
+    ('\u{a876}', '\u{a877}', STerm), ('\u{a880}', '\u{a881}', Extend),
+    ('\u{a882}', '\u{a8b3}', OLetter), ('\u{a8b4}', '\u{a8c5}', Extend),
+    ('\u{a8ce}', '\u{a8cf}', STerm), ('\u{a8d0}', '\u{a8d9}', Numeric),
+    ('\u{a8e0}', '\u{a8f1}', Extend), ('\u{a8f2}', '\u{a8f7}', OLetter),
+    ('\u{a8fb}', '\u{a8fb}', OLetter), ('\u{a8fd}', '\u{a8fe}', OLetter),
+    ('\u{a8ff}', '\u{a8ff}', Extend), ('\u{a900}', '\u{a909}', Numeric),
+    ('\u{a90a}', '\u{a925}', OLetter), ('\u{a926}', '\u{a92d}', Extend),
+    ('\u{a92f}', '\u{a92f}', STerm), ('\u{a930}', '\u{a946}', OLetter),
+    ('\u{a947}', '\u{a953}', Extend), ('\u{a960}', '\u{a97c}', OLetter),
+    ('\u{a980}', '\u{a983}', Extend), ('\u{a984}', '\u{a9b2}', OLetter),
+    ('\u{a9b3}', '\u{a9c0}', Extend), ('\u{a9c8}', '\u{a9c9}', STerm),
+    ('\u{a9cf}', '\u{a9cf}', OLetter), ('\u{a9d0}', '\u{a9d9}', Numeric),
+    ('\u{a9e0}', '\u{a9e4}', OLetter), ('\u{a9e5}', '\u{a9e5}', Extend),
+    ('\u{a9e6}', '\u{a9ef}', OLetter), ('\u{a9f0}', '\u{a9f9}', Numeric),
+    ('\u{a9fa}', '\u{a9fe}', OLetter), ('\u{aa00}', '\u{aa28}', OLetter),
+    ('\u{aa29}', '\u{aa36}', Extend), ('\u{aa40}', '\u{aa42}', OLetter),
+    ('\u{aa43}', '\u{aa43}', Extend), ('\u{aa44}', '\u{aa4b}', OLetter),
+    ('\u{aa4c}', '\u{aa4d}', Extend), ('\u{aa50}', '\u{aa59}', Numeric),
+    ('\u{aa5d}', '\u{aa5f}', STerm), ('\u{aa60}', '\u{aa76}', OLetter),
+    ('\u{aa7a}', '\u{aa7a}', OLetter), ('\u{aa7b}', '\u{aa7d}', Extend),
+    ('\u{aa7e}', '\u{aaaf}', OLetter), ('\u{aab0}', '\u{aab0}', Extend),
+    ('\u{aab1}', '\u{aab1}', OLetter), ('\u{aab2}', '\u{aab4}', Extend),
+    ('\u{aab5}', '\u{aab6}', OLetter), ('\u{aab7}', '\u{aab8}', Extend),
+    ('\u{aab9}', '\u{aabd}', OLetter), ('\u{aabe}', '\u{aabf}', Extend),
+    ('\u{aac0}', '\u{aac0}', OLetter), ('\u{aac1}', '\u{aac1}', Extend),
+    ('\u{aac2}', '\u{aac2}', OLetter), ('\u{aadb}', '\u{aadd}', OLetter),
+    ('\u{aae0}', '\u{aaea}', OLetter), ('\u{aaeb}', '\u{aaef}', Extend),
+    ('\u{aaf0}', '\u{aaf1}', STerm), ('\u{aaf2}', '\u{aaf4}', OLetter),
+    ('\u{aaf5}', '\u{aaf6}', Extend), ('\u{ab01}', '\u{ab06}', OLetter),
+    ('\u{ab09}', '\u{ab0e}', OLetter), ('\u{ab11}', '\u{ab16}', OLetter),
+    ('\u{ab20}', '\u{ab26}', OLetter), ('\u{ab28}', '\u{ab2e}', OLetter),
+    ('\u{ab30}', '\u{ab5a}', Lower), ('\u{ab5c}', '\u{ab69}', Lower),
+    ('\u{ab70}', '\u{abbf}', Lower), ('\u{abc0}', '\u{abe2}', OLetter),
+    ('\u{abe3}', '\u{abea}', Extend), ('\u{abeb}', '\u{abeb}', STerm),
+    ('\u{abec}', '\u{abed}', Extend), ('\u{abf0}', '\u{abf9}', Numeric),
+    ('\u{ac00}', '\u{d7a3}', OLetter), ('\u{d7b0}', '\u{d7c6}', OLetter),
+    ('\u{d7cb}', '\u{d7fb}', OLetter), ('\u{f900}', '\u{fa6d}', OLetter),
+    ('\u{fa70}', '\u{fad9}', OLetter), ('\u{fb00}', '\u{fb06}', Lower),
+    ('\u{fb13}', '\u{fb17}', Lower), ('\u{fb1d}', '\u{fb1d}', OLetter),
+    ('\u{fb1e}', '\u{fb1e}', Extend), ('\u{fb1f}', '\u{fb28}', OLetter),
+    ('\u{fb2a}', '\u{fb36}', OLetter), ('\u{fb38}', '\u{fb3c}', OLetter),
+    ('\u{fb3e}', '\u{fb3e}', OLetter), ('\u{fb40}', '\u{fb41}', OLetter),
+    ('\u{fb43}', '\u{fb44}', OLetter), ('\u{fb46}', '\u{fbb1}', OLetter),
+    ('\u{fbd3}', '\u{fd3d}', OLetter), ('\u{fd3e}', '\u{fd3f}', Close),
+    ('\u{fd50}', '\u{fd8f}', OLetter), ('\u{fd92}', '\u{fdc7}', OLetter),
+    ('\u{fdf0}', '\u{fdfb}', OLetter), ('\u{fe00}', '\u{fe0f}', Extend),
+    ('\u{fe10}', '\u{fe11}', SContinue), ('\u{fe12}', '\u{fe12}', STerm),
+    ('\u{fe13}', '\u{fe14}', SContinue), ('\u{fe15}', '\u{fe16}', STerm),
+    ('\u{fe17}', '\u{fe18}', Close), ('\u{fe20}', '\u{fe2f}', Extend),
+    ('\u{fe31}', '\u{fe32}', SContinue), ('\u{fe35}', '\u{fe44}', Close),
+    ('\u{fe47}', '\u{fe48}', Close), ('\u{fe50}', '\u{fe51}', SContinue),
+    ('\u{fe52}', '\u{fe52}', ATerm), ('\u{fe54}', '\u{fe55}', SContinue),
+    ('\u{fe56}', '\u{fe57}', STerm), ('\u{fe58}', '\u{fe58}', SContinue),
+    ('\u{fe59}', '\u{fe5e}', Close), ('\u{fe63}', '\u{fe63}', SContinue),
+    ('\u{fe70}', '\u{fe74}', OLetter), ('\u{fe76}', '\u{fefc}', OLetter),
+    ('\u{feff}', '\u{feff}', Format), ('\u{ff01}', '\u{ff01}', STerm),
+    ('\u{ff08}', '\u{ff09}', Close), ('\u{ff0c}', '\u{ff0d}', SContinue),
+    ('\u{ff0e}', '\u{ff0e}', ATerm), ('\u{ff10}', '\u{ff19}', Numeric),
+    ('\u{ff1a}', '\u{ff1b}', SContinue), ('\u{ff1f}', '\u{ff1f}', STerm),
+    ('\u{ff21}', '\u{ff3a}', Upper), ('\u{ff3b}', '\u{ff3b}', Close),
+    ('\u{ff3d}', '\u{ff3d}', Close), ('\u{ff41}', '\u{ff5a}', Lower),
+    ('\u{ff5b}', '\u{ff5b}', Close), ('\u{ff5d}', '\u{ff5d}', Close),
+    ('\u{ff5f}', '\u{ff60}', Close), ('\u{ff61}', '\u{ff61}', STerm),
+    ('\u{ff62}', '\u{ff63}', Close), ('\u{ff64}', '\u{ff64}', SContinue),
+    ('\u{ff66}', '\u{ff9d}', OLetter), ('\u{ff9e}', '\u{ff9f}', Extend),
+    ('\u{ffa0}', '\u{ffbe}', OLetter), ('\u{ffc2}', '\u{ffc7}', OLetter),
+    ('\u{ffca}', '\u{ffcf}', OLetter), ('\u{ffd2}', '\u{ffd7}', OLetter),
+    ('\u{ffda}', '\u{ffdc}', OLetter), ('\u{fff9}', '\u{fffb}', Format),
+    ('\u{10000}', '\u{1000b}', OLetter), ('\u{1000d}', '\u{10026}', OLetter),
+    ('\u{10028}', '\u{1003a}', OLetter), ('\u{1003c}', '\u{1003d}', OLetter),
+    ('\u{1003f}', '\u{1004d}', OLetter), ('\u{10050}', '\u{1005d}', OLetter),
+    ('\u{10080}', '\u{100fa}', OLetter), ('\u{10140}', '\u{10174}', OLetter),
+    ('\u{101fd}', '\u{101fd}', Extend), ('\u{10280}', '\u{1029c}', OLetter),
+    ('\u{102a0}', '\u{102d0}', OLetter), ('\u{102e0}', '\u{102e0}', Extend),
+    ('\u{10300}', '\u{1031f}', OLetter), ('\u{1032d}', '\u{1034a}', OLetter),
+    ('\u{10350}', '\u{10375}', OLetter), ('\u{10376}', '\u{1037a}', Extend),
+    ('\u{10380}', '\u{1039d}', OLetter), ('\u{103a0}', '\u{103c3}', OLetter),
+    ('\u{103c8}', '\u{103cf}', OLetter), ('\u{103d1}', '\u{103d5}', OLetter),
+    ('\u{10400}', '\u{10427}', Upper), ('\u{10428}', '\u{1044f}', Lower),
+    ('\u{10450}', '\u{1049d}', OLetter), ('\u{104a0}', '\u{104a9}', Numeric),
+    ('\u{104b0}', '\u{104d3}', Upper), ('\u{104d8}', '\u{104fb}', Lower),
+    ('\u{10500}', '\u{10527}', OLetter), ('\u{10530}', '\u{10563}', OLetter),
+    ('\u{10570}', '\u{1057a}', Upper), ('\u{1057c}', '\u{1058a}', Upper),
+    ('\u{1058c}', '\u{10592}', Upper), ('\u{10594}', '\u{10595}', Upper),
+    ('\u{10597}', '\u{105a1}', Lower), ('\u{105a3}', '\u{105b1}', Lower),
+    ('\u{105b3}', '\u{105b9}', Lower), ('\u{105bb}', '\u{105bc}', Lower),
+    ('\u{105c0}', '\u{105f3}', OLetter), ('\u{10600}', '\u{10736}', OLetter),
+    ('\u{10740}', '\u{10755}', OLetter), ('\u{10760}', '\u{10767}', OLetter),
+    ('\u{10780}', '\u{10780}', Lower), ('\u{10781}', '\u{10782}', OLetter),
+    ('\u{10783}', '\u{10785}', Lower), ('\u{10787}', '\u{107b0}', Lower),
+    ('\u{107b2}', '\u{107ba}', Lower), ('\u{10800}', '\u{10805}', OLetter),
+    ('\u{10808}', '\u{10808}', OLetter), ('\u{1080a}', '\u{10835}', OLetter),
+    ('\u{10837}', '\u{10838}', OLetter), ('\u{1083c}', '\u{1083c}', OLetter),
+    ('\u{1083f}', '\u{10855}', OLetter), ('\u{10860}', '\u{10876}', OLetter),
+    ('\u{10880}', '\u{1089e}', OLetter), ('\u{108e0}', '\u{108f2}', OLetter),
+    ('\u{108f4}', '\u{108f5}', OLetter), ('\u{10900}', '\u{10915}', OLetter),
+    ('\u{10920}', '\u{10939}', OLetter), ('\u{10980}', '\u{109b7}', OLetter),
+    ('\u{109be}', '\u{109bf}', OLetter), ('\u{10a00}', '\u{10a00}', OLetter),
+    ('\u{10a01}', '\u{10a03}', Extend), ('\u{10a05}', '\u{10a06}', Extend),
+    ('\u{10a0c}', '\u{10a0f}', Extend), ('\u{10a10}', '\u{10a13}', OLetter),
+    ('\u{10a15}', '\u{10a17}', OLetter), ('\u{10a19}', '\u{10a35}', OLetter),
+    ('\u{10a38}', '\u{10a3a}', Extend), ('\u{10a3f}', '\u{10a3f}', Extend),
+    ('\u{10a56}', '\u{10a57}', STerm), ('\u{10a60}', '\u{10a7c}', OLetter),
+    ('\u{10a80}', '\u{10a9c}', OLetter), ('\u{10ac0}', '\u{10ac7}', OLetter),
+    ('\u{10ac9}', '\u{10ae4}', OLetter), ('\u{10ae5}', '\u{10ae6}', Extend),
+    ('\u{10b00}', '\u{10b35}', OLetter), ('\u{10b40}', '\u{10b55}', OLetter),
+    ('\u{10b60}', '\u{10b72}', OLetter), ('\u{10b80}', '\u{10b91}', OLetter),
+    ('\u{10c00}', '\u{10c48}', OLetter), ('\u{10c80}', '\u{10cb2}', Upper),
+    ('\u{10cc0}', '\u{10cf2}', Lower), ('\u{10d00}', '\u{10d23}', OLetter),
+    ('\u{10d24}', '\u{10d27}', Extend), ('\u{10d30}', '\u{10d39}', Numeric),
+    ('\u{10d40}', '\u{10d49}', Numeric), ('\u{10d4a}', '\u{10d4f}', OLetter),
+    ('\u{10d50}', '\u{10d65}', Upper), ('\u{10d69}', '\u{10d6d}', Extend),
+    ('\u{10d6f}', '\u{10d6f}', OLetter), ('\u{10d70}', '\u{10d85}', Lower),
+    ('\u{10e80}', '\u{10ea9}', OLetter), ('\u{10eab}', '\u{10eac}', Extend),
+    ('\u{10eb0}', '\u{10eb1}', OLetter), ('\u{10ec2}', '\u{10ec4}', OLetter),
+    ('\u{10efc}', '\u{10eff}', Extend), ('\u{10f00}', '\u{10f1c}', OLetter),
+    ('\u{10f27}', '\u{10f27}', OLetter), ('\u{10f30}', '\u{10f45}', OLetter),
+    ('\u{10f46}', '\u{10f50}', Extend), ('\u{10f55}', '\u{10f59}', STerm),
+    ('\u{10f70}', '\u{10f81}', OLetter), ('\u{10f82}', '\u{10f85}', Extend),
+    ('\u{10f86}', '\u{10f89}', STerm), ('\u{10fb0}', '\u{10fc4}', OLetter),
+    ('\u{10fe0}', '\u{10ff6}', OLetter), ('\u{11000}', '\u{11002}', Extend),
+    ('\u{11003}', '\u{11037}', OLetter), ('\u{11038}', '\u{11046}', Extend),
+    ('\u{11047}', '\u{11048}', STerm), ('\u{11066}', '\u{1106f}', Numeric),
+    ('\u{11070}', '\u{11070}', Extend), ('\u{11071}', '\u{11072}', OLetter),
+    ('\u{11073}', '\u{11074}', Extend), ('\u{11075}', '\u{11075}', OLetter),
+    ('\u{1107f}', '\u{11082}', Extend), ('\u{11083}', '\u{110af}', OLetter),
+    ('\u{110b0}', '\u{110ba}', Extend), ('\u{110bd}', '\u{110bd}', Numeric),
+    ('\u{110be}', '\u{110c1}', STerm), ('\u{110c2}', '\u{110c2}', Extend),
+    ('\u{110cd}', '\u{110cd}', Numeric), ('\u{110d0}', '\u{110e8}', OLetter),
+    ('\u{110f0}', '\u{110f9}', Numeric), ('\u{11100}', '\u{11102}', Extend),
+    ('\u{11103}', '\u{11126}', OLetter), ('\u{11127}', '\u{11134}', Extend),
+    ('\u{11136}', '\u{1113f}', Numeric), ('\u{11141}', '\u{11143}', STerm),
+    ('\u{11144}', '\u{11144}', OLetter), ('\u{11145}', '\u{11146}', Extend),
+    ('\u{11147}', '\u{11147}', OLetter), ('\u{11150}', '\u{11172}', OLetter),
+    ('\u{11173}', '\u{11173}', Extend), ('\u{11176}', '\u{11176}', OLetter),
+    ('\u{11180}', '\u{11182}', Extend), ('\u{11183}', '\u{111b2}', OLetter),
+    ('\u{111b3}', '\u{111c0}', Extend), ('\u{111c1}', '\u{111c4}', OLetter),
+    ('\u{111c5}', '\u{111c6}', STerm), ('\u{111c9}', '\u{111cc}', Extend),
+    ('\u{111cd}', '\u{111cd}', STerm), ('\u{111ce}', '\u{111cf}', Extend),
+    ('\u{111d0}', '\u{111d9}', Numeric), ('\u{111da}', '\u{111da}', OLetter),
+    ('\u{111dc}', '\u{111dc}', OLetter), ('\u{111de}', '\u{111df}', STerm),
+    ('\u{11200}', '\u{11211}', OLetter), ('\u{11213}', '\u{1122b}', OLetter),
+    ('\u{1122c}', '\u{11237}', Extend), ('\u{11238}', '\u{11239}', STerm),
+    ('\u{1123b}', '\u{1123c}', STerm), ('\u{1123e}', '\u{1123e}', Extend),
+    ('\u{1123f}', '\u{11240}', OLetter), ('\u{11241}', '\u{11241}', Extend),
+    ('\u{11280}', '\u{11286}', OLetter), ('\u{11288}', '\u{11288}', OLetter),
+    ('\u{1128a}', '\u{1128d}', OLetter), ('\u{1128f}', '\u{1129d}', OLetter),
+    ('\u{1129f}', '\u{112a8}', OLetter), ('\u{112a9}', '\u{112a9}', STerm),
+    ('\u{112b0}', '\u{112de}', OLetter), ('\u{112df}', '\u{112ea}', Extend),
+    ('\u{112f0}', '\u{112f9}', Numeric), ('\u{11300}', '\u{11303}', Extend),
+    ('\u{11305}', '\u{1130c}', OLetter), ('\u{1130f}', '\u{11310}', OLetter),
+    ('\u{11313}', '\u{11328}', OLetter), ('\u{1132a}', '\u{11330}', OLetter),
+    ('\u{11332}', '\u{11333}', OLetter), ('\u{11335}', '\u{11339}', OLetter),
+    ('\u{1133b}', '\u{1133c}', Extend), ('\u{1133d}', '\u{1133d}', OLetter),
+    ('\u{1133e}', '\u{11344}', Extend), ('\u{11347}', '\u{11348}', Extend),
+    ('\u{1134b}', '\u{1134d}', Extend), ('\u{11350}', '\u{11350}', OLetter),
+    ('\u{11357}', '\u{11357}', Extend), ('\u{1135d}', '\u{11361}', OLetter),
+    ('\u{11362}', '\u{11363}', Extend), ('\u{11366}', '\u{1136c}', Extend),
+    ('\u{11370}', '\u{11374}', Extend), ('\u{11380}', '\u{11389}', OLetter),
+    ('\u{1138b}', '\u{1138b}', OLetter), ('\u{1138e}', '\u{1138e}', OLetter),
+    ('\u{11390}', '\u{113b5}', OLetter), ('\u{113b7}', '\u{113b7}', OLetter),
+    ('\u{113b8}', '\u{113c0}', Extend), ('\u{113c2}', '\u{113c2}', Extend),
+    ('\u{113c5}', '\u{113c5}', Extend), ('\u{113c7}', '\u{113ca}', Extend),
+    ('\u{113cc}', '\u{113d0}', Extend), ('\u{113d1}', '\u{113d1}', OLetter),
+    ('\u{113d2}', '\u{113d2}', Extend), ('\u{113d3}', '\u{113d3}', OLetter),
+    ('\u{113d4}', '\u{113d5}', STerm), ('\u{113e1}', '\u{113e2}', Extend),
+    ('\u{11400}', '\u{11434}', OLetter), ('\u{11435}', '\u{11446}', Extend),
+    ('\u{11447}', '\u{1144a}', OLetter), ('\u{1144b}', '\u{1144c}', STerm),
+    ('\u{11450}', '\u{11459}', Numeric), ('\u{1145e}', '\u{1145e}', Extend),
+    ('\u{1145f}', '\u{11461}', OLetter), ('\u{11480}', '\u{114af}', OLetter),
+    ('\u{114b0}', '\u{114c3}', Extend), ('\u{114c4}', '\u{114c5}', OLetter),
+    ('\u{114c7}', '\u{114c7}', OLetter), ('\u{114d0}', '\u{114d9}', Numeric),
+    ('\u{11580}', '\u{115ae}', OLetter), ('\u{115af}', '\u{115b5}', Extend),
+    ('\u{115b8}', '\u{115c0}', Extend), ('\u{115c2}', '\u{115c3}', STerm),
+    ('\u{115c9}', '\u{115d7}', STerm), ('\u{115d8}', '\u{115db}', OLetter),
+    ('\u{115dc}', '\u{115dd}', Extend), ('\u{11600}', '\u{1162f}', OLetter),
+    ('\u{11630}', '\u{11640}', Extend), ('\u{11641}', '\u{11642}', STerm),
+    ('\u{11644}', '\u{11644}', OLetter), ('\u{11650}', '\u{11659}', Numeric),
+    ('\u{11680}', '\u{116aa}', OLetter), ('\u{116ab}', '\u{116b7}', Extend),
+    ('\u{116b8}', '\u{116b8}', OLetter), ('\u{116c0}', '\u{116c9}', Numeric),
+    ('\u{116d0}', '\u{116e3}', Numeric), ('\u{11700}', '\u{1171a}', OLetter),
+    ('\u{1171d}', '\u{1172b}', Extend), ('\u{11730}', '\u{11739}', Numeric),
+    ('\u{1173c}', '\u{1173e}', STerm), ('\u{11740}', '\u{11746}', OLetter),
+    ('\u{11800}', '\u{1182b}', OLetter), ('\u{1182c}', '\u{1183a}', Extend),
+    ('\u{118a0}', '\u{118bf}', Upper), ('\u{118c0}', '\u{118df}', Lower),
+    ('\u{118e0}', '\u{118e9}', Numeric), ('\u{118ff}', '\u{11906}', OLetter),
+    ('\u{11909}', '\u{11909}', OLetter), ('\u{1190c}', '\u{11913}', OLetter),
+    ('\u{11915}', '\u{11916}', OLetter), ('\u{11918}', '\u{1192f}', OLetter),
+    ('\u{11930}', '\u{11935}', Extend), ('\u{11937}', '\u{11938}', Extend),
+    ('\u{1193b}', '\u{1193e}', Extend), ('\u{1193f}', '\u{1193f}', OLetter),
+    ('\u{11940}', '\u{11940}', Extend), ('\u{11941}', '\u{11941}', OLetter),
+    ('\u{11942}', '\u{11943}', Extend), ('\u{11944}', '\u{11944}', STerm),
+    ('\u{11946}', '\u{11946}', STerm), ('\u{11950}', '\u{11959}', Numeric),
+    ('\u{119a0}', '\u{119a7}', OLetter), ('\u{119aa}', '\u{119d0}', OLetter),
+    ('\u{119d1}', '\u{119d7}', Extend), ('\u{119da}', '\u{119e0}', Extend),
+    ('\u{119e1}', '\u{119e1}', OLetter), ('\u{119e3}', '\u{119e3}', OLetter),
+    ('\u{119e4}', '\u{119e4}', Extend), ('\u{11a00}', '\u{11a00}', OLetter),
+    ('\u{11a01}', '\u{11a0a}', Extend), ('\u{11a0b}', '\u{11a32}', OLetter),
+    ('\u{11a33}', '\u{11a39}', Extend), ('\u{11a3a}', '\u{11a3a}', OLetter),
+    ('\u{11a3b}', '\u{11a3e}', Extend), ('\u{11a42}', '\u{11a43}', STerm),
+    ('\u{11a47}', '\u{11a47}', Extend), ('\u{11a50}', '\u{11a50}', OLetter),
+    ('\u{11a51}', '\u{11a5b}', Extend), ('\u{11a5c}', '\u{11a89}', OLetter),
+    ('\u{11a8a}', '\u{11a99}', Extend), ('\u{11a9b}', '\u{11a9c}', STerm),
+    ('\u{11a9d}', '\u{11a9d}', OLetter), ('\u{11ab0}', '\u{11af8}', OLetter),
+    ('\u{11bc0}', '\u{11be0}', OLetter), ('\u{11bf0}', '\u{11bf9}', Numeric),
+    ('\u{11c00}', '\u{11c08}', OLetter), ('\u{11c0a}', '\u{11c2e}', OLetter),
+    ('\u{11c2f}', '\u{11c36}', Extend), ('\u{11c38}', '\u{11c3f}', Extend),
+    ('\u{11c40}', '\u{11c40}', OLetter), ('\u{11c41}', '\u{11c42}', STerm),
+    ('\u{11c50}', '\u{11c59}', Numeric), ('\u{11c72}', '\u{11c8f}', OLetter),
+    ('\u{11c92}', '\u{11ca7}', Extend), ('\u{11ca9}', '\u{11cb6}', Extend),
+    ('\u{11d00}', '\u{11d06}', OLetter), ('\u{11d08}', '\u{11d09}', OLetter),
+    ('\u{11d0b}', '\u{11d30}', OLetter), ('\u{11d31}', '\u{11d36}', Extend),
+    ('\u{11d3a}', '\u{11d3a}', Extend), ('\u{11d3c}', '\u{11d3d}', Extend),
+    ('\u{11d3f}', '\u{11d45}', Extend), ('\u{11d46}', '\u{11d46}', OLetter),
+    ('\u{11d47}', '\u{11d47}', Extend), ('\u{11d50}', '\u{11d59}', Numeric),
+    ('\u{11d60}', '\u{11d65}', OLetter), ('\u{11d67}', '\u{11d68}', OLetter),
+    ('\u{11d6a}', '\u{11d89}', OLetter), ('\u{11d8a}', '\u{11d8e}', Extend),
+    ('\u{11d90}', '\u{11d91}', Extend), ('\u{11d93}', '\u{11d97}', Extend),
+    ('\u{11d98}', '\u{11d98}', OLetter), ('\u{11da0}', '\u{11da9}', Numeric),
+    ('\u{11ee0}', '\u{11ef2}', OLetter), ('\u{11ef3}', '\u{11ef6}', Extend),
+    ('\u{11ef7}', '\u{11ef8}', STerm), ('\u{11f00}', '\u{11f01}', Extend),
+    ('\u{11f02}', '\u{11f02}', OLetter), ('\u{11f03}', '\u{11f03}', Extend),
+    ('\u{11f04}', '\u{11f10}', OLetter), ('\u{11f12}', '\u{11f33}', OLetter),
+    ('\u{11f34}', '\u{11f3a}', Extend), ('\u{11f3e}', '\u{11f42}', Extend),
+    ('\u{11f43}', '\u{11f44}', STerm), ('\u{11f50}', '\u{11f59}', Numeric),
+    ('\u{11f5a}', '\u{11f5a}', Extend), ('\u{11fb0}', '\u{11fb0}', OLetter),
+    ('\u{12000}', '\u{12399}', OLetter), ('\u{12400}', '\u{1246e}', OLetter),
+    ('\u{12480}', '\u{12543}', OLetter), ('\u{12f90}', '\u{12ff0}', OLetter),
+    ('\u{13000}', '\u{1342f}', OLetter), ('\u{13430}', '\u{1343f}', Format),
+    ('\u{13440}', '\u{13440}', Extend), ('\u{13441}', '\u{13446}', OLetter),
+    ('\u{13447}', '\u{13455}', Extend), ('\u{13460}', '\u{143fa}', OLetter),
+    ('\u{14400}', '\u{14646}', OLetter), ('\u{16100}', '\u{1611d}', OLetter),
+    ('\u{1611e}', '\u{1612f}', Extend), ('\u{16130}', '\u{16139}', Numeric),
+    ('\u{16800}', '\u{16a38}', OLetter), ('\u{16a40}', '\u{16a5e}', OLetter),
+    ('\u{16a60}', '\u{16a69}', Numeric), ('\u{16a6e}', '\u{16a6f}', STerm),
+    ('\u{16a70}', '\u{16abe}', OLetter), ('\u{16ac0}', '\u{16ac9}', Numeric),
+    ('\u{16ad0}', '\u{16aed}', OLetter), ('\u{16af0}', '\u{16af4}', Extend),
+    ('\u{16af5}', '\u{16af5}', STerm), ('\u{16b00}', '\u{16b2f}', OLetter),
+    ('\u{16b30}', '\u{16b36}', Extend), ('\u{16b37}', '\u{16b38}', STerm),
+    ('\u{16b40}', '\u{16b43}', OLetter), ('\u{16b44}', '\u{16b44}', STerm),
+    ('\u{16b50}', '\u{16b59}', Numeric), ('\u{16b63}', '\u{16b77}', OLetter),
+    ('\u{16b7d}', '\u{16b8f}', OLetter), ('\u{16d40}', '\u{16d6c}', OLetter),
+    ('\u{16d6e}', '\u{16d6f}', STerm), ('\u{16d70}', '\u{16d79}', Numeric),
+    ('\u{16e40}', '\u{16e5f}', Upper), ('\u{16e60}', '\u{16e7f}', Lower),
+    ('\u{16e98}', '\u{16e98}', STerm), ('\u{16f00}', '\u{16f4a}', OLetter),
+    ('\u{16f4f}', '\u{16f4f}', Extend), ('\u{16f50}', '\u{16f50}', OLetter),
+    ('\u{16f51}', '\u{16f87}', Extend), ('\u{16f8f}', '\u{16f92}', Extend),
+    ('\u{16f93}', '\u{16f9f}', OLetter), ('\u{16fe0}', '\u{16fe1}', OLetter),
+    ('\u{16fe3}', '\u{16fe3}', OLetter), ('\u{16fe4}', '\u{16fe4}', Extend),
+    ('\u{16ff0}', '\u{16ff1}', Extend), ('\u{17000}', '\u{187f7}', OLetter),
+    ('\u{18800}', '\u{18cd5}', OLetter), ('\u{18cff}', '\u{18d08}', OLetter),
+    ('\u{1aff0}', '\u{1aff3}', OLetter), ('\u{1aff5}', '\u{1affb}', OLetter),
+    ('\u{1affd}', '\u{1affe}', OLetter), ('\u{1b000}', '\u{1b122}', OLetter),
+    ('\u{1b132}', '\u{1b132}', OLetter), ('\u{1b150}', '\u{1b152}', OLetter),
+    ('\u{1b155}', '\u{1b155}', OLetter), ('\u{1b164}', '\u{1b167}', OLetter),
+    ('\u{1b170}', '\u{1b2fb}', OLetter), ('\u{1bc00}', '\u{1bc6a}', OLetter),
+    ('\u{1bc70}', '\u{1bc7c}', OLetter), ('\u{1bc80}', '\u{1bc88}', OLetter),
+    ('\u{1bc90}', '\u{1bc99}', OLetter), ('\u{1bc9d}', '\u{1bc9e}', Extend),
+    ('\u{1bc9f}', '\u{1bc9f}', STerm), ('\u{1bca0}', '\u{1bca3}', Format),
+    ('\u{1ccf0}', '\u{1ccf9}', Numeric), ('\u{1cf00}', '\u{1cf2d}', Extend),
+    ('\u{1cf30}', '\u{1cf46}', Extend), ('\u{1d165}', '\u{1d169}', Extend),
+    ('\u{1d16d}', '\u{1d172}', Extend), ('\u{1d173}', '\u{1d17a}', Format),
+    ('\u{1d17b}', '\u{1d182}', Extend), ('\u{1d185}', '\u{1d18b}', Extend),
+    ('\u{1d1aa}', '\u{1d1ad}', Extend), ('\u{1d242}', '\u{1d244}', Extend),
+    ('\u{1d400}', '\u{1d419}', Upper), ('\u{1d41a}', '\u{1d433}', Lower),
+    ('\u{1d434}', '\u{1d44d}', Upper), ('\u{1d44e}', '\u{1d454}', Lower),
+    ('\u{1d456}', '\u{1d467}', Lower), ('\u{1d468}', '\u{1d481}', Upper),
+    ('\u{1d482}', '\u{1d49b}', Lower), ('\u{1d49c}', '\u{1d49c}', Upper),
+    ('\u{1d49e}', '\u{1d49f}', Upper), ('\u{1d4a2}', '\u{1d4a2}', Upper),
+    ('\u{1d4a5}', '\u{1d4a6}', Upper), ('\u{1d4a9}', '\u{1d4ac}', Upper),
+    ('\u{1d4ae}', '\u{1d4b5}', Upper), ('\u{1d4b6}', '\u{1d4b9}', Lower),
+    ('\u{1d4bb}', '\u{1d4bb}', Lower), ('\u{1d4bd}', '\u{1d4c3}', Lower),
+    ('\u{1d4c5}', '\u{1d4cf}', Lower), ('\u{1d4d0}', '\u{1d4e9}', Upper),
+    ('\u{1d4ea}', '\u{1d503}', Lower), ('\u{1d504}', '\u{1d505}', Upper),
+    ('\u{1d507}', '\u{1d50a}', Upper), ('\u{1d50d}', '\u{1d514}', Upper),
+    ('\u{1d516}', '\u{1d51c}', Upper), ('\u{1d51e}', '\u{1d537}', Lower),
+    ('\u{1d538}', '\u{1d539}', Upper), ('\u{1d53b}', '\u{1d53e}', Upper),
+    ('\u{1d540}', '\u{1d544}', Upper), ('\u{1d546}', '\u{1d546}', Upper),
+    ('\u{1d54a}', '\u{1d550}', Upper), ('\u{1d552}', '\u{1d56b}', Lower),
+    ('\u{1d56c}', '\u{1d585}', Upper), ('\u{1d586}', '\u{1d59f}', Lower),
+    ('\u{1d5a0}', '\u{1d5b9}', Upper), ('\u{1d5ba}', '\u{1d5d3}', Lower),
+    ('\u{1d5d4}', '\u{1d5ed}', Upper), ('\u{1d5ee}', '\u{1d607}', Lower),
+    ('\u{1d608}', '\u{1d621}', Upper), ('\u{1d622}', '\u{1d63b}', Lower),
+    ('\u{1d63c}', '\u{1d655}', Upper), ('\u{1d656}', '\u{1d66f}', Lower),
+    ('\u{1d670}', '\u{1d689}', Upper), ('\u{1d68a}', '\u{1d6a5}', Lower),
+    ('\u{1d6a8}', '\u{1d6c0}', Upper), ('\u{1d6c2}', '\u{1d6da}', Lower),
+    ('\u{1d6dc}', '\u{1d6e1}', Lower), ('\u{1d6e2}', '\u{1d6fa}', Upper),
+    ('\u{1d6fc}', '\u{1d714}', Lower), ('\u{1d716}', '\u{1d71b}', Lower),
+    ('\u{1d71c}', '\u{1d734}', Upper), ('\u{1d736}', '\u{1d74e}', Lower),
+    ('\u{1d750}', '\u{1d755}', Lower), ('\u{1d756}', '\u{1d76e}', Upper),
+    ('\u{1d770}', '\u{1d788}', Lower), ('\u{1d78a}', '\u{1d78f}', Lower),
+    ('\u{1d790}', '\u{1d7a8}', Upper), ('\u{1d7aa}', '\u{1d7c2}', Lower),
+    ('\u{1d7c4}', '\u{1d7c9}', Lower), ('\u{1d7ca}', '\u{1d7ca}', Upper),
+    ('\u{1d7cb}', '\u{1d7cb}', Lower), ('\u{1d7ce}', '\u{1d7ff}', Numeric),
+    ('\u{1da00}', '\u{1da36}', Extend), ('\u{1da3b}', '\u{1da6c}', Extend),
+    ('\u{1da75}', '\u{1da75}', Extend), ('\u{1da84}', '\u{1da84}', Extend),
+    ('\u{1da88}', '\u{1da88}', STerm), ('\u{1da9b}', '\u{1da9f}', Extend),
+    ('\u{1daa1}', '\u{1daaf}', Extend), ('\u{1df00}', '\u{1df09}', Lower),
+    ('\u{1df0a}', '\u{1df0a}', OLetter), ('\u{1df0b}', '\u{1df1e}', Lower),
+    ('\u{1df25}', '\u{1df2a}', Lower), ('\u{1e000}', '\u{1e006}', Extend),
+    ('\u{1e008}', '\u{1e018}', Extend), ('\u{1e01b}', '\u{1e021}', Extend),
+    ('\u{1e023}', '\u{1e024}', Extend), ('\u{1e026}', '\u{1e02a}', Extend),
+    ('\u{1e030}', '\u{1e06d}', Lower), ('\u{1e08f}', '\u{1e08f}', Extend),
+    ('\u{1e100}', '\u{1e12c}', OLetter), ('\u{1e130}', '\u{1e136}', Extend),
+    ('\u{1e137}', '\u{1e13d}', OLetter), ('\u{1e140}', '\u{1e149}', Numeric),
+    ('\u{1e14e}', '\u{1e14e}', OLetter), ('\u{1e290}', '\u{1e2ad}', OLetter),
+    ('\u{1e2ae}', '\u{1e2ae}', Extend), ('\u{1e2c0}', '\u{1e2eb}', OLetter),
+    ('\u{1e2ec}', '\u{1e2ef}', Extend), ('\u{1e2f0}', '\u{1e2f9}', Numeric),
+    ('\u{1e4d0}', '\u{1e4eb}', OLetter), ('\u{1e4ec}', '\u{1e4ef}', Extend),
+    ('\u{1e4f0}', '\u{1e4f9}', Numeric), ('\u{1e5d0}', '\u{1e5ed}', OLetter),
+    ('\u{1e5ee}', '\u{1e5ef}', Extend), ('\u{1e5f0}', '\u{1e5f0}', OLetter),
+    ('\u{1e5f1}', '\u{1e5fa}', Numeric), ('\u{1e7e0}', '\u{1e7e6}', OLetter),
+    ('\u{1e7e8}', '\u{1e7eb}', OLetter), ('\u{1e7ed}', '\u{1e7ee}', OLetter),
+    ('\u{1e7f0}', '\u{1e7fe}', OLetter), ('\u{1e800}', '\u{1e8c4}', OLetter),
+    ('\u{1e8d0}', '\u{1e8d6}', Extend), ('\u{1e900}', '\u{1e921}', Upper),
+    ('\u{1e922}', '\u{1e943}', Lower), ('\u{1e944}', '\u{1e94a}', Extend),
+    ('\u{1e94b}', '\u{1e94b}', OLetter), ('\u{1e950}', '\u{1e959}', Numeric),
+    ('\u{1ee00}', '\u{1ee03}', OLetter), ('\u{1ee05}', '\u{1ee1f}', OLetter),
+    ('\u{1ee21}', '\u{1ee22}', OLetter), ('\u{1ee24}', '\u{1ee24}', OLetter),
+    ('\u{1ee27}', '\u{1ee27}', OLetter), ('\u{1ee29}', '\u{1ee32}', OLetter),
+    ('\u{1ee34}', '\u{1ee37}', OLetter), ('\u{1ee39}', '\u{1ee39}', OLetter),
+    ('\u{1ee3b}', '\u{1ee3b}', OLetter), ('\u{1ee42}', '\u{1ee42}', OLetter),
+    ('\u{1ee47}', '\u{1ee47}', OLetter), ('\u{1ee49}', '\u{1ee49}', OLetter),
+    ('\u{1ee4b}', '\u{1ee4b}', OLetter), ('\u{1ee4d}', '\u{1ee4f}', OLetter),
+    ('\u{1ee51}', '\u{1ee52}', OLetter), ('\u{1ee54}', '\u{1ee54}', OLetter),
+    ('\u{1ee57}', '\u{1ee57}', OLetter), ('\u{1ee59}', '\u{1ee59}', OLetter),
+    ('\u{1ee5b}', '\u{1ee5b}', OLetter), ('\u{1ee5d}', '\u{1ee5d}', OLetter),
+    ('\u{1ee5f}', '\u{1ee5f}', OLetter), ('\u{1ee61}', '\u{1ee62}', OLetter),
+    ('\u{1ee64}', '\u{1ee64}', OLetter), ('\u{1ee67}', '\u{1ee6a}', OLetter),
+    ('\u{1ee6c}', '\u{1ee72}', OLetter), ('\u{1ee74}', '\u{1ee77}', OLetter),
+    ('\u{1ee79}', '\u{1ee7c}', OLetter), ('\u{1ee7e}', '\u{1ee7e}', OLetter),
+    ('\u{1ee80}', '\u{1ee89}', OLetter), ('\u{1ee8b}', '\u{1ee9b}', OLetter),
+    ('\u{1eea1}', '\u{1eea3}', OLetter), ('\u{1eea5}', '\u{1eea9}', OLetter),
+    ('\u{1eeab}', '\u{1eebb}', OLetter), ('\u{1f130}', '\u{1f149}', Upper),
+    ('\u{1f150}', '\u{1f169}', Upper), ('\u{1f170}', '\u{1f189}', Upper),
+    ('\u{1f676}', '\u{1f678}', Close), ('\u{1fbf0}', '\u{1fbf9}', Numeric),
+    ('\u{20000}', '\u{2a6df}', OLetter), ('\u{2a700}', '\u{2b739}', OLetter),
+    ('\u{2b740}', '\u{2b81d}', OLetter), ('\u{2b820}', '\u{2cea1}', OLetter),
+    ('\u{2ceb0}', '\u{2ebe0}', OLetter), ('\u{2ebf0}', '\u{2ee5d}', OLetter),
+    ('\u{2f800}', '\u{2fa1d}', OLetter), ('\u{30000}', '\u{3134a}', OLetter),
+    ('\u{31350}', '\u{323af}', OLetter), ('\u{e0001}', '\u{e0001}', Format),
+    ('\u{e0020}', '\u{e007f}', Extend), ('\u{e0100}', '\u{e01ef}', Extend),
+];
@@ -0,0 +1,385 @@
+//! The display width of code points, see [UAX #11](https://www.unicode.org/reports/tr11/).
+//!
+//! Generated from the `East_Asian_Width`, `General_Category` and related
+//! properties of the Unicode Character Database 16.0.0 by
+//! `scripts/gen_tables.py`, do not edit by hand.
+
+use super::{contains, lookup};
+use CharWidth::*;
+
+/// How many terminal cells a code point takes on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CharWidth {
+    /// Control and format characters, default ignorable code points, and code
+    /// points which are rendered on top of others, like combining marks or
+    /// Hangul medial vowels.
+    Zero,
+    Narrow,
+    /// East asian wide and fullwidth code points, as well as emoji with a
+    /// default emoji presentation.
+    Wide,
+    /// East asian ambiguous code points, which are narrow or wide depending on
+    /// the context.
+    Ambiguous,
+}
+
+pub(crate) fn char_width(c: char) -> CharWidth {
+    lookup(c, CHAR_WIDTH).unwrap_or(CharWidth::Narrow)
+}
+
+/// Check if the code point is an emoji, which can be given an emoji
+/// presentation by a variation selector (`Emoji`).
+pub(crate) fn is_emoji(c: char) -> bool {
+    contains(c, EMOJI)
+}
+
+#[rustfmt::skip]
+const CHAR_WIDTH: &[(char, char, CharWidth)] = &[
+    ('\u{0}', '\u{1f}', Zero), ('\u{7f}', '\u{9f}', Zero), ('\u{a1}', '\u{a1}', Ambiguous),
+    ('\u{a4}', '\u{a4}', Ambiguous), ('\u{a7}', '\u{a8}', Ambiguous),
+    ('\u{aa}', '\u{aa}', Ambiguous), ('\u{ad}', '\u{ad}', Zero), ('\u{ae}', '\u{ae}', Ambiguous),
+    ('\u{b0}', '\u{b4}', Ambiguous), ('\u{b6}', '\u{ba}', Ambiguous),
+    ('\u{bc}', '\u{bf}', Ambiguous), ('\u{c6}', '\u{c6}', Ambiguous),
+    ('\u{d0}', '\u{d0}', Ambiguous), ('\u{d7}', '\u{d8}', Ambiguous),
+    ('\u{de}', '\u{e1}', Ambiguous), ('\u{e6}', '\u{e6}', Ambiguous),
+    ('\u{e8}', '\u{ea}', Ambiguous), ('\u{ec}', '\u{ed}', Ambiguous),
+    ('\u{f0}', '\u{f0}', Ambiguous), ('\u{f2}', '\u{f3}', Ambiguous),
+    ('\u{f7}', '\u{fa}', Ambiguous), ('\u{fc}', '\u{fc}', Ambiguous),
+    ('\u{fe}', '\u{fe}', Ambiguous), ('\u{101}', '\u{101}', Ambiguous),
+    ('\u{111}', '\u{111}', Ambiguous), ('\u{113}', '\u{113}', Ambiguous),
+    ('\u{11b}', '\u{11b}', Ambiguous), ('\u{126}', '\u{127}', Ambiguous),
+    ('\u{12b}', '\u{12b}', Ambiguous), ('\u{131}', '\u{133}', Ambiguous),
+    ('\u{138}', '\u{138}', Ambiguous), ('\u{13f}', '\u{142}', Ambiguous),
+    ('\u{144}', '\u{144}', Ambiguous), ('\u{148}', '\u{14b}', Ambiguous),
+    ('\u{14d}', '\u{14d}', Ambiguous), ('\u{152}', '\u{153}', Ambiguous),
+    ('\u{166}', '\u{167}', Ambiguous), ('\u{16b}', '\u{16b}', Ambiguous),
+    ('\u{1ce}', '\u{1ce}', Ambiguous), ('\u{1d0}', '\u{1d0}', Ambiguous),
+    ('\u{1d2}', '\u{1d2}', Ambiguous), ('\u{1d4}', '\u{1d4}', Ambiguous),
+    ('\u{1d6}', '\u{1d6}', Ambiguous), ('\u{1d8}', '\u{1d8}', Ambiguous),
+    ('\u{1da}', '\u{1da}', Ambiguous), ('\u{1dc}', '\u{1dc}', Ambiguous),
+    ('\u{251}', '\u{251}', Ambiguous), ('\u{261}', '\u{261}', Ambiguous),
+    ('\u{2c4}', '\u{2c4}', Ambiguous), ('\u{2c7}', '\u{2c7}', Ambiguous),
+    ('\u{2c9}', '\u{2cb}', Ambiguous), ('\u{2cd}', '\u{2cd}', Ambiguous),
+    ('\u{2d0}', '\u{2d0}', Ambiguous), ('\u{2d8}', '\u{2db}', Ambiguous),
+    ('\u{2dd}', '\u{2dd}', Ambiguous), ('\u{2df}', '\u{2df}', Ambiguous),
+    ('\u{300}', '\u{36f}', Zero), ('\u{391}', '\u{3a1}', Ambiguous),
+    ('\u{3a3}', '\u{3a9}', Ambiguous), ('\u{3b1}', '\u{3c1}', Ambiguous),
+    ('\u{3c3}', '\u{3c9}', Ambiguous), ('\u{401}', '\u{401}', Ambiguous),
+    ('\u{410}', '\u{44f}', Ambiguous), ('\u{451}', '\u{451}', Ambiguous),
+    ('\u{483}', '\u{489}', Zero), ('\u{591}', '\u{5bd}', Zero), ('\u{5bf}', '\u{5bf}', Zero),
+    ('\u{5c1}', '\u{5c2}', Zero), ('\u{5c4}', '\u{5c5}', Zero), ('\u{5c7}', '\u{5c7}', Zero),
+    ('\u{610}', '\u{61a}', Zero), ('\u{61c}', '\u{61c}', Zero), ('\u{64b}', '\u{65f}', Zero),
+    ('\u{670}', '\u{670}', Zero), ('\u{6d6}', '\u{6dc}', Zero), ('\u{6df}', '\u{6e4}', Zero),
+    ('\u{6e7}', '\u{6e8}', Zero), ('\u{6ea}', '\u{6ed}', Zero), ('\u{711}', '\u{711}', Zero),
+    ('\u{730}', '\u{74a}', Zero), ('\u{7a6}', '\u{7b0}', Zero), ('\u{7eb}', '\u{7f3}', Zero),
+    ('\u{7fd}', '\u{7fd}', Zero), ('\u{816}', '\u{819}', Zero), ('\u{81b}', '\u{823}', Zero),
+    ('\u{825}', '\u{827}', Zero), ('\u{829}', '\u{82d}', Zero), ('\u{859}', '\u{85b}', Zero),
+    ('\u{897}', '\u{89f}', Zero), ('\u{8ca}', '\u{8e1}', Zero), ('\u{8e3}', '\u{902}', Zero),
+    ('\u{93a}', '\u{93a}', Zero), ('\u{93c}', '\u{93c}', Zero), ('\u{941}', '\u{948}', Zero),
+    ('\u{94d}', '\u{94d}', Zero), ('\u{951}', '\u{957}', Zero), ('\u{962}', '\u{963}', Zero),
+    ('\u{981}', '\u{981}', Zero), ('\u{9bc}', '\u{9bc}', Zero), ('\u{9c1}', '\u{9c4}', Zero),
+    ('\u{9cd}', '\u{9cd}', Zero), ('\u{9e2}', '\u{9e3}', Zero), ('\u{9fe}', '\u{9fe}', Zero),
+    ('\u{a01}', '\u{a02}', Zero), ('\u{a3c}', '\u{a3c}', Zero), ('\u{a41}', '\u{a42}', Zero),
+    ('\u{a47}', '\u{a48}', Zero), ('\u{a4b}', '\u{a4d}', Zero), ('\u{a51}', '\u{a51}', Zero),
+    ('\u{a70}', '\u{a71}', Zero), ('\u{a75}', '\u{a75}', Zero), ('\u{a81}', '\u{a82}', Zero),
+    ('\u{abc}', '\u{abc}', Zero), ('\u{ac1}', '\u{ac5}', Zero), ('\u{ac7}', '\u{ac8}', Zero),
+    ('\u{acd}', '\u{acd}', Zero), ('\u{ae2}', '\u{ae3}', Zero), ('\u{afa}', '\u{aff}', Zero),
+    ('\u{b01}', '\u{b01}', Zero), ('\u{b3c}', '\u{b3c}', Zero), ('\u{b3f}', '\u{b3f}', Zero),
+    ('\u{b41}', '\u{b44}', Zero), ('\u{b4d}', '\u{b4d}', Zero), ('\u{b55}', '\u{b56}', Zero),
+    ('\u{b62}', '\u{b63}', Zero), ('\u{b82}', '\u{b82}', Zero), ('\u{bc0}', '\u{bc0}', Zero),
+    ('\u{bcd}', '\u{bcd}', Zero), ('\u{c00}', '\u{c00}', Zero), ('\u{c04}', '\u{c04}', Zero),
+    ('\u{c3c}', '\u{c3c}', Zero), ('\u{c3e}', '\u{c40}', Zero), ('\u{c46}', '\u{c48}', Zero),
+    ('\u{c4a}', '\u{c4d}', Zero), ('\u{c55}', '\u{c56}', Zero), ('\u{c62}', '\u{c63}', Zero),
+    ('\u{c81}', '\u{c81}', Zero), ('\u{cbc}', '\u{cbc}', Zero), ('\u{cbf}', '\u{cbf}', Zero),
+    ('\u{cc6}', '\u{cc6}', Zero), ('\u{ccc}', '\u{ccd}', Zero), ('\u{ce2}', '\u{ce3}', Zero),
+    ('\u{d00}', '\u{d01}', Zero), ('\u{d3b}', '\u{d3c}', Zero), ('\u{d41}', '\u{d44}', Zero),
+    ('\u{d4d}', '\u{d4d}', Zero), ('\u{d62}', '\u{d63}', Zero), ('\u{d81}', '\u{d81}', Zero),
+    ('\u{dca}', '\u{dca}', Zero), ('\u{dd2}', '\u{dd4}', Zero), ('\u{dd6}', '\u{dd6}', Zero),
+    ('\u{e31}', '\u{e31}', Zero), ('\u{e34}', '\u{e3a}', Zero), ('\u{e47}', '\u{e4e}', Zero),
+    ('\u{eb1}', '\u{eb1}', Zero), ('\u{eb4}', '\u{ebc}', Zero), ('\u{ec8}', '\u{ece}', Zero),
+    ('\u{f18}', '\u{f19}', Zero), ('\u{f35}', '\u{f35}', Zero), ('\u{f37}', '\u{f37}', Zero),
+    ('\u{f39}', '\u{f39}', Zero), ('\u{f71}', '\u{f7e}', Zero), ('\u{f80}', '\u{f84}', Zero),
+    ('\u{f86}', '\u{f87}', Zero), ('\u{f8d}', '\u{f97}', Zero), ('\u{f99}', '\u{fbc}', Zero),
+    ('\u{fc6}', '\u{fc6}', Zero), ('\u{102d}', '\u{1030}', Zero), ('\u{1032}', '\u{1037}', Zero),
+    ('\u{1039}', '\u{103a}', Zero), ('\u{103d}', '\u{103e}', Zero), ('\u{1058}', '\u{1059}', Zero),
+    ('\u{105e}', '\u{1060}', Zero), ('\u{1071}', '\u{1074}', Zero), ('\u{1082}', '\u{1082}', Zero),
+    ('\u{1085}', '\u{1086}', Zero), ('\u{108d}', '\u{108d}', Zero), ('\u{109d}', '\u{109d}', Zero),
+    ('\u{1100}', '\u{115f}', Wide), ('\u{1160}', '\u{11ff}', Zero), ('\u{135d}', '\u{135f}', Zero),
+    ('\u{1712}', '\u{1714}', Zero), ('\u{1732}', '\u{1733}', Zero), ('\u{1752}', '\u{1753}', Zero),
+    ('\u{1772}', '\u{1773}', Zero), ('\u{17b4}', '\u{17b5}', Zero), ('\u{17b7}', '\u{17bd}', Zero),
+    ('\u{17c6}', '\u{17c6}', Zero), ('\u{17c9}', '\u{17d3}', Zero), ('\u{17dd}', '\u{17dd}', Zero),
+    ('\u{180b}', '\u{180f}', Zero), ('\u{1885}', '\u{1886}', Zero), ('\u{18a9}', '\u{18a9}', Zero),
+    ('\u{1920}', '\u{1922}', Zero), ('\u{1927}', '\u{1928}', Zero), ('\u{1932}', '\u{1932}', Zero),
+    ('\u{1939}', '\u{193b}', Zero), ('\u{1a17}', '\u{1a18}', Zero), ('\u{1a1b}', '\u{1a1b}', Zero),
+    ('\u{1a56}', '\u{1a56}', Zero), ('\u{1a58}', '\u{1a5e}', Zero), ('\u{1a60}', '\u{1a60}', Zero),
+    ('\u{1a62}', '\u{1a62}', Zero), ('\u{1a65}', '\u{1a6c}', Zero), ('\u{1a73}', '\u{1a7c}', Zero),
+    ('\u{1a7f}', '\u{1a7f}', Zero), ('\u{1ab0}', '\u{1ace}', Zero), ('\u{1b00}', '\u{1b03}', Zero),
+    ('\u{1b34}', '\u{1b34}', Zero), ('\u{1b36}', '\u{1b3a}', Zero), ('\u{1b3c}', '\u{1b3c}', Zero),
+    ('\u{1b42}', '\u{1b42}', Zero), ('\u{1b6b}', '\u{1b73}', Zero), ('\u{1b80}', '\u{1b81}', Zero),
+    ('\u{1ba2}', '\u{1ba5}', Zero), ('\u{1ba8}', '\u{1ba9}', Zero), ('\u{1bab}', '\u{1bad}', Zero),
+    ('\u{1be6}', '\u{1be6}', Zero), ('\u{1be8}', '\u{1be9}', Zero), ('\u{1bed}', '\u{1bed}', Zero),
+    ('\u{1bef}', '\u{1bf1}', Zero), ('\u{1c2c}', '\u{1c33}', Zero), ('\u{1c36}', '\u{1c37}', Zero),
+    ('\u{1cd0}', '\u{1cd2}', Zero), ('\u{1cd4}', '\u{1ce0}', Zero), ('\u{1ce2}', '\u{1ce8}', Zero),
+    ('\u{1ced}', '\u{1ced}', Zero), ('\u{1cf4}', '\u{1cf4}', Zero), ('\u{1cf8}', '\u{1cf9}', Zero),
+    ('\u{1dc0}', '\u{1dff}', Zero), ('\u{200b}', '\u{200f}', Zero),
+    ('\u{2010}', '\u{2010}', Ambiguous), ('\u{2013}', '\u{2016}', Ambiguous),
+    ('\u{2018}', '\u{2019}', Ambiguous), ('\u{201c}', '\u{201d}', Ambiguous),
+    ('\u{2020}', '\u{2022}', Ambiguous), ('\u{2024}', '\u{2027}', Ambiguous),
+    ('\u{202a}', '\u{202e}', Zero), ('\u{2030}', '\u{2030}', Ambiguous),
+    ('\u{2032}', '\u{2033}', Ambiguous), ('\u{2035}', '\u{2035}', Ambiguous),
+    ('\u{203b}', '\u{203b}', Ambiguous), ('\u{203e}', '\u{203e}', Ambiguous),
+    ('\u{2060}', '\u{206f}', Zero), ('\u{2074}', '\u{2074}', Ambiguous),
+    ('\u{207f}', '\u{207f}', Ambiguous), ('\u{2081}', '\u{2084}', Ambiguous),
+    ('\u{20ac}', '\u{20ac}', Ambiguous), ('\u{20d0}', '\u{20f0}', Zero),
+    ('\u{2103}', '\u{2103}', Ambiguous), ('\u{2105}', '\u{2105}', Ambiguous),
+    ('\u{2109}', '\u{2109}', Ambiguous), ('\u{2113}', '\u{2113}', Ambiguous),
+    ('\u{2116}', '\u{2116}', Ambiguous), ('\u{2121}', '\u{2122}', Ambiguous),
+    ('\u{2126}', '\u{2126}', Ambiguous), ('\u{212b}', '\u{212b}', Ambiguous),
+    ('\u{2153}', '\u{2154}', Ambiguous), ('\u{215b}', '\u{215e}', Ambiguous),
+    ('\u{2160}', '\u{216b}', Ambiguous), ('\u{2170}', '\u{2179}', Ambiguous),
+    ('\u{2189}', '\u{2189}', Ambiguous), ('\u{2190}', '\u{2199}', Ambiguous),
+    ('\u{21b8}', '\u{21b9}', Ambiguous), ('\u{21d2}', '\u{21d2}', Ambiguous),
+    ('\u{21d4}', '\u{21d4}', Ambiguous), ('\u{21e7}', '\u{21e7}', Ambiguous),
+    ('\u{2200}', '\u{2200}', Ambiguous), ('\u{2202}', '\u{2203}', Ambiguous),
+    ('\u{2207}', '\u{2208}', Ambiguous), ('\u{220b}', '\u{220b}', Ambiguous),
+    ('\u{220f}', '\u{220f}', Ambiguous), ('\u{2211}', '\u{2211}', Ambiguous),
+    ('\u{2215}', '\u{2215}', Ambiguous), ('\u{221a}', '\u{221a}', Ambiguous),
+    ('\u{221d}', '\u{2220}', Ambiguous), ('\u{2223}', '\u{2223}', Ambiguous),
+    ('\u{2225}', '\u{2225}', Ambiguous), ('\u{2227}', '\u{222c}', Ambiguous),
+    ('\u{222e}', '\u{222e}', Ambiguous), ('\u{2234}', '\u{2237}', Ambiguous),
+    ('\u{223c}', '\u{223d}', Ambiguous), ('\u{2248}', '\u{2248}', Ambiguous),
+    ('\u{224c}', '\u{224c}', Ambiguous), ('\u{2252}', '\u{2252}', Ambiguous),
+    ('\u{2260}', '\u{2261}', Ambiguous), ('\u{2264}', '\u{2267}', Ambiguous),
+    ('\u{226a}', '\u{226b}', Ambiguous), ('\u{226e}', '\u{226f}', Ambiguous),
+    ('\u{2282}', '\u{2283}', Ambiguous), ('\u{2286}', '\u{2287}', Ambiguous),
+    ('\u{2295}', '\u{2295}', Ambiguous), ('\u{2299}', '\u{2299}', Ambiguous),
+    ('\u{22a5}', '\u{22a5}', Ambiguous), ('\u{22bf}', '\u{22bf}', Ambiguous),
+    ('\u{2312}', '\u{2312}', Ambiguous), ('\u{231a}', '\u{231b}', Wide),
+    ('\u{2329}', '\u{232a}', Wide), ('\u{23e9}', '\u{23ec}', Wide), ('\u{23f0}', '\u{23f0}', Wide),
+    ('\u{23f3}', '\u{23f3}', Wide), ('\u{2460}', '\u{24e9}', Ambiguous),
+    ('\u{24eb}', '\u{254b}', Ambiguous), ('\u{2550}', '\u{2573}', Ambiguous),
+    ('\u{2580}', '\u{258f}', Ambiguous), ('\u{2592}', '\u{2595}', Ambiguous),
+    ('\u{25a0}', '\u{25a1}', Ambiguous), ('\u{25a3}', '\u{25a9}', Ambiguous),
+    ('\u{25b2}', '\u{25b3}', Ambiguous), ('\u{25b6}', '\u{25b7}', Ambiguous),
+    ('\u{25bc}', '\u{25bd}', Ambiguous), ('\u{25c0}', '\u{25c1}', Ambiguous),
+    ('\u{25c6}', '\u{25c8}', Ambiguous), ('\u{25cb}', '\u{25cb}', Ambiguous),
+    ('\u{25ce}', '\u{25d1}', Ambiguous), ('\u{25e2}', '\u{25e5}', Ambiguous),
+    ('\u{25ef}', '\u{25ef}', Ambiguous), ('\u{25fd}', '\u{25fe}', Wide),
+    ('\u{2605}', '\u{2606}', Ambiguous), ('\u{2609}', '\u{2609}', Ambiguous),
+    ('\u{260e}', '\u{260f}', Ambiguous), ('\u{2614}', '\u{2615}', Wide),
+    ('\u{261c}', '\u{261c}', Ambiguous), ('\u{261e}', '\u{261e}', Ambiguous),
+    ('\u{2630}', '\u{2637}', Wide), ('\u{2640}', '\u{2640}', Ambiguous),
+    ('\u{2642}', '\u{2642}', Ambiguous), ('\u{2648}', '\u{2653}', Wide),
+    ('\u{2660}', '\u{2661}', Ambiguous), ('\u{2663}', '\u{2665}', Ambiguous),
+    ('\u{2667}', '\u{266a}', Ambiguous), ('\u{266c}', '\u{266d}', Ambiguous),
+    ('\u{266f}', '\u{266f}', Ambiguous), ('\u{267f}', '\u{267f}', Wide),
+    ('\u{268a}', '\u{268f}', Wide), ('\u{2693}', '\u{2693}', Wide),
+    ('\u{269e}', '\u{269f}', Ambiguous), ('\u{26a1}', '\u{26a1}', Wide),
+    ('\u{26aa}', '\u{26ab}', Wide), ('\u{26bd}', '\u{26be}', Wide),
+    ('\u{26bf}', '\u{26bf}', Ambiguous), ('\u{26c4}', '\u{26c5}', Wide),
+    ('\u{26c6}', '\u{26cd}', Ambiguous), ('\u{26ce}', '\u{26ce}', Wide),
+    ('\u{26cf}', '\u{26d3}', Ambiguous), ('\u{26d4}', '\u{26d4}', Wide),
+    ('\u{26d5}', '\u{26e1}', Ambiguous), ('\u{26e3}', '\u{26e3}', Ambiguous),
+    ('\u{26e8}', '\u{26e9}', Ambiguous), ('\u{26ea}', '\u{26ea}', Wide),
+    ('\u{26eb}', '\u{26f1}', Ambiguous), ('\u{26f2}', '\u{26f3}', Wide),
+    ('\u{26f4}', '\u{26f4}', Ambiguous), ('\u{26f5}', '\u{26f5}', Wide),
+    ('\u{26f6}', '\u{26f9}', Ambiguous), ('\u{26fa}', '\u{26fa}', Wide),
+    ('\u{26fb}', '\u{26fc}', Ambiguous), ('\u{26fd}', '\u{26fd}', Wide),
+    ('\u{26fe}', '\u{26ff}', Ambiguous), ('\u{2705}', '\u{2705}', Wide),
+    ('\u{270a}', '\u{270b}', Wide), ('\u{2728}', '\u{2728}', Wide),
+    ('\u{273d}', '\u{273d}', Ambiguous), ('\u{274c}', '\u{274c}', Wide),
+    ('\u{274e}', '\u{274e}', Wide), ('\u{2753}', '\u{2755}', Wide), ('\u{2757}', '\u{2757}', Wide),
+    ('\u{2776}', '\u{277f}', Ambiguous), ('\u{2795}', '\u{2797}', Wide),
+    ('\u{27b0}', '\u{27b0}', Wide), ('\u{27bf}', '\u{27bf}', Wide), ('\u{2b1b}', '\u{2b1c}', Wide),
+    ('\u{2b50}', '\u{2b50}', Wide), ('\u{2b55}', '\u{2b55}', Wide),
+    ('\u{2b56}', '\u{2b59}', Ambiguous), ('\u{2cef}', '\u{2cf1}', Zero),
+    ('\u{2d7f}', '\u{2d7f}', Zero), ('\u{2de0}', '\u{2dff}', Zero), ('\u{2e80}', '\u{2e99}', Wide),
+    ('\u{2e9b}', '\u{2ef3}', Wide), ('\u{2f00}', '\u{2fd5}', Wide), ('\u{2ff0}', '\u{3029}', Wide),
+    ('\u{302a}', '\u{302d}', Zero), ('\u{302e}', '\u{303e}', Wide), ('\u{3041}', '\u{3096}', Wide),
+    ('\u{3099}', '\u{309a}', Zero), ('\u{309b}', '\u{30ff}', Wide), ('\u{3105}', '\u{312f}', Wide),
+    ('\u{3131}', '\u{3163}', Wide), ('\u{3164}', '\u{3164}', Zero), ('\u{3165}', '\u{318e}', Wide),
+    ('\u{3190}', '\u{31e5}', Wide), ('\u{31ef}', '\u{321e}', Wide), ('\u{3220}', '\u{3247}', Wide),
+    ('\u{3248}', '\u{324f}', Ambiguous), ('\u{3250}', '\u{a48c}', Wide),
+    ('\u{a490}', '\u{a4c6}', Wide), ('\u{a66f}', '\u{a672}', Zero), ('\u{a674}', '\u{a67d}', Zero),
+    ('\u{a69e}', '\u{a69f}', Zero), ('\u{a6f0}', '\u{a6f1}', Zero), ('\u{a802}', '\u{a802}', Zero),
+    ('\u{a806}', '\u{a806}', Zero), ('\u{a80b}', '\u{a80b}', Zero), ('\u{a825}', '\u{a826}', Zero),
+    ('\u{a82c}', '\u{a82c}', Zero), ('\u{a8c4}', '\u{a8c5}', Zero), ('\u{a8e0}', '\u{a8f1}', Zero),
+    ('\u{a8ff}', '\u{a8ff}', Zero), ('\u{a926}', '\u{a92d}', Zero), ('\u{a947}', '\u{a951}', Zero),
+    ('\u{a960}', '\u{a97c}', Wide), ('\u{a980}', '\u{a982}', Zero), ('\u{a9b3}', '\u{a9b3}', Zero),
+    ('\u{a9b6}', '\u{a9b9}', Zero), ('\u{a9bc}', '\u{a9bd}', Zero), ('\u{a9e5}', '\u{a9e5}', Zero),
+    ('\u{aa29}', '\u{aa2e}', Zero), ('\u{aa31}', '\u{aa32}', Zero), ('\u{aa35}', '\u{aa36}', Zero),
+    ('\u{aa43}', '\u{aa43}', Zero), ('\u{aa4c}', '\u{aa4c}', Zero), ('\u{aa7c}', '\u{aa7c}', Zero),
+    ('\u{aab0}', '\u{aab0}', Zero), ('\u{aab2}', '\u{aab4}', Zero), ('\u{aab7}', '\u{aab8}', Zero),
+    ('\u{aabe}', '\u{aabf}', Zero), ('\u{aac1}', '\u{aac1}', Zero), ('\u{aaec}', '\u{aaed}', Zero),
+    ('\u{aaf6}', '\u{aaf6}', Zero), ('\u{abe5}', '\u{abe5}', Zero), ('\u{abe8}', '\u{abe8}', Zero),
+    ('\u{abed}', '\u{abed}', Zero), ('\u{ac00}', '\u{d7a3}', Wide), ('\u{d7b0}', '\u{d7c6}', Zero),
+    ('\u{d7cb}', '\u{d7fb}', Zero), ('\u{e000}', '\u{f8ff}', Ambiguous),
+    ('\u{f900}', '\u{faff}', Wide), ('\u{fb1e}', '\u{fb1e}', Zero), ('\u{fe00}', '\u{fe0f}', Zero),
+    ('\u{fe10}', '\u{fe19}', Wide), ('\u{fe20}', '\u{fe2f}', Zero), ('\u{fe30}', '\u{fe52}', Wide),
+    ('\u{fe54}', '\u{fe66}', Wide), ('\u{fe68}', '\u{fe6b}', Wide), ('\u{feff}', '\u{feff}', Zero),
+    ('\u{ff01}', '\u{ff60}', Wide), ('\u{ffa0}', '\u{ffa0}', Zero), ('\u{ffe0}', '\u{ffe6}', Wide),
+    ('\u{fff0}', '\u{fffb}', Zero), ('\u{fffd}', '\u{fffd}', Ambiguous),
+    ('\u{101fd}', '\u{101fd}', Zero), ('\u{102e0}', '\u{102e0}', Zero),
+    ('\u{10376}', '\u{1037a}', Zero), ('\u{10a01}', '\u{10a03}', Zero),
+    ('\u{10a05}', '\u{10a06}', Zero), ('\u{10a0c}', '\u{10a0f}', Zero),
+    ('\u{10a38}', '\u{10a3a}', Zero), ('\u{10a3f}', '\u{10a3f}', Zero),
+    ('\u{10ae5}', '\u{10ae6}', Zero), ('\u{10d24}', '\u{10d27}', Zero),
+    ('\u{10d69}', '\u{10d6d}', Zero), ('\u{10eab}', '\u{10eac}', Zero),
+    ('\u{10efc}', '\u{10eff}', Zero), ('\u{10f46}', '\u{10f50}', Zero),
+    ('\u{10f82}', '\u{10f85}', Zero), ('\u{11001}', '\u{11001}', Zero),
+    ('\u{11038}', '\u{11046}', Zero), ('\u{11070}', '\u{11070}', Zero),
+    ('\u{11073}', '\u{11074}', Zero), ('\u{1107f}', '\u{11081}', Zero),
+    ('\u{110b3}', '\u{110b6}', Zero), ('\u{110b9}', '\u{110ba}', Zero),
+    ('\u{110c2}', '\u{110c2}', Zero), ('\u{11100}', '\u{11102}', Zero),
+    ('\u{11127}', '\u{1112b}', Zero), ('\u{1112d}', '\u{11134}', Zero),
+    ('\u{11173}', '\u{11173}', Zero), ('\u{11180}', '\u{11181}', Zero),
+    ('\u{111b6}', '\u{111be}', Zero), ('\u{111c9}', '\u{111cc}', Zero),
+    ('\u{111cf}', '\u{111cf}', Zero), ('\u{1122f}', '\u{11231}', Zero),
+    ('\u{11234}', '\u{11234}', Zero), ('\u{11236}', '\u{11237}', Zero),
+    ('\u{1123e}', '\u{1123e}', Zero), ('\u{11241}', '\u{11241}', Zero),
+    ('\u{112df}', '\u{112df}', Zero), ('\u{112e3}', '\u{112ea}', Zero),
+    ('\u{11300}', '\u{11301}', Zero), ('\u{1133b}', '\u{1133c}', Zero),
+    ('\u{11340}', '\u{11340}', Zero), ('\u{11366}', '\u{1136c}', Zero),
+    ('\u{11370}', '\u{11374}', Zero), ('\u{113bb}', '\u{113c0}', Zero),
+    ('\u{113ce}', '\u{113ce}', Zero), ('\u{113d0}', '\u{113d0}', Zero),
+    ('\u{113d2}', '\u{113d2}', Zero), ('\u{113e1}', '\u{113e2}', Zero),
+    ('\u{11438}', '\u{1143f}', Zero), ('\u{11442}', '\u{11444}', Zero),
+    ('\u{11446}', '\u{11446}', Zero), ('\u{1145e}', '\u{1145e}', Zero),
+    ('\u{114b3}', '\u{114b8}', Zero), ('\u{114ba}', '\u{114ba}', Zero),
+    ('\u{114bf}', '\u{114c0}', Zero), ('\u{114c2}', '\u{114c3}', Zero),
+    ('\u{115b2}', '\u{115b5}', Zero), ('\u{115bc}', '\u{115bd}', Zero),
+    ('\u{115bf}', '\u{115c0}', Zero), ('\u{115dc}', '\u{115dd}', Zero),
+    ('\u{11633}', '\u{1163a}', Zero), ('\u{1163d}', '\u{1163d}', Zero),
+    ('\u{1163f}', '\u{11640}', Zero), ('\u{116ab}', '\u{116ab}', Zero),
+    ('\u{116ad}', '\u{116ad}', Zero), ('\u{116b0}', '\u{116b5}', Zero),
+    ('\u{116b7}', '\u{116b7}', Zero), ('\u{1171d}', '\u{1171d}', Zero),
+    ('\u{1171f}', '\u{1171f}', Zero), ('\u{11722}', '\u{11725}', Zero),
+    ('\u{11727}', '\u{1172b}', Zero), ('\u{1182f}', '\u{11837}', Zero),
+    ('\u{11839}', '\u{1183a}', Zero), ('\u{1193b}', '\u{1193c}', Zero),
+    ('\u{1193e}', '\u{1193e}', Zero), ('\u{11943}', '\u{11943}', Zero),
+    ('\u{119d4}', '\u{119d7}', Zero), ('\u{119da}', '\u{119db}', Zero),
+    ('\u{119e0}', '\u{119e0}', Zero), ('\u{11a01}', '\u{11a0a}', Zero),
+    ('\u{11a33}', '\u{11a38}', Zero), ('\u{11a3b}', '\u{11a3e}', Zero),
+    ('\u{11a47}', '\u{11a47}', Zero), ('\u{11a51}', '\u{11a56}', Zero),
+    ('\u{11a59}', '\u{11a5b}', Zero), ('\u{11a8a}', '\u{11a96}', Zero),
+    ('\u{11a98}', '\u{11a99}', Zero), ('\u{11c30}', '\u{11c36}', Zero),
+    ('\u{11c38}', '\u{11c3d}', Zero), ('\u{11c3f}', '\u{11c3f}', Zero),
+    ('\u{11c92}', '\u{11ca7}', Zero), ('\u{11caa}', '\u{11cb0}', Zero),
+    ('\u{11cb2}', '\u{11cb3}', Zero), ('\u{11cb5}', '\u{11cb6}', Zero),
+    ('\u{11d31}', '\u{11d36}', Zero), ('\u{11d3a}', '\u{11d3a}', Zero),
+    ('\u{11d3c}', '\u{11d3d}', Zero), ('\u{11d3f}', '\u{11d45}', Zero),
+    ('\u{11d47}', '\u{11d47}', Zero), ('\u{11d90}', '\u{11d91}', Zero),
+    ('\u{11d95}', '\u{11d95}', Zero), ('\u{11d97}', '\u{11d97}', Zero),
+    ('\u{11ef3}', '\u{11ef4}', Zero), ('\u{11f00}', '\u{11f01}', Zero),
+    ('\u{11f36}', '\u{11f3a}', Zero), ('\u{11f40}', '\u{11f40}', Zero),
+    ('\u{11f42}', '\u{11f42}', Zero), ('\u{11f5a}', '\u{11f5a}', Zero),
+    ('\u{13430}', '\u{13440}', Zero), ('\u{13447}', '\u{13455}', Zero),
+    ('\u{1611e}', '\u{16129}', Zero), ('\u{1612d}', '\u{1612f}', Zero),
+    ('\u{16af0}', '\u{16af4}', Zero), ('\u{16b30}', '\u{16b36}', Zero),
+    ('\u{16f4f}', '\u{16f4f}', Zero), ('\u{16f8f}', '\u{16f92}', Zero),
+    ('\u{16fe0}', '\u{16fe3}', Wide), ('\u{16fe4}', '\u{16fe4}', Zero),
+    ('\u{16ff0}', '\u{16ff1}', Wide), ('\u{17000}', '\u{187f7}', Wide),
+    ('\u{18800}', '\u{18cd5}', Wide), ('\u{18cff}', '\u{18d08}', Wide),
+    ('\u{1aff0}', '\u{1aff3}', Wide), ('\u{1aff5}', '\u{1affb}', Wide),
+    ('\u{1affd}', '\u{1affe}', Wide), ('\u{1b000}', '\u{1b122}', Wide),
+    ('\u{1b132}', '\u{1b132}', Wide), ('\u{1b150}', '\u{1b152}', Wide),
+    ('\u{1b155}', '\u{1b155}', Wide), ('\u{1b164}', '\u{1b167}', Wide),
+    ('\u{1b170}', '\u{1b2fb}', Wide), ('\u{1bc9d}', '\u{1bc9e}', Zero),
+    ('\u{1bca0}', '\u{1bca3}', Zero), ('\u{1cf00}', '\u{1cf2d}', Zero),
+    ('\u{1cf30}', '\u{1cf46}', Zero), ('\u{1d167}', '\u{1d169}', Zero),
+    ('\u{1d173}', '\u{1d182}', Zero), ('\u{1d185}', '\u{1d18b}', Zero),
+    ('\u{1d1aa}', '\u{1d1ad}', Zero), ('\u{1d242}', '\u{1d244}', Zero),
+    ('\u{1d300}', '\u{1d356}', Wide), ('\u{1d360}', '\u{1d376}', Wide),
+    ('\u{1da00}', '\u{1da36}', Zero), ('\u{1da3b}', '\u{1da6c}', Zero),
+    ('\u{1da75}', '\u{1da75}', Zero), ('\u{1da84}', '\u{1da84}', Zero),
+    ('\u{1da9b}', '\u{1da9f}', Zero), ('\u{1daa1}', '\u{1daaf}', Zero),
+    ('\u{1e000}', '\u{1e006}', Zero), ('\u{1e008}', '\u{1e018}', Zero),
+    ('\u{1e01b}', '\u{1e021}', Zero), ('\u{1e023}', '\u{1e024}', Zero),
+    ('\u{1e026}', '\u{1e02a}', Zero), ('\u{1e08f}', '\u{1e08f}', Zero),
+    ('\u{1e130}', '\u{1e136}', Zero), ('\u{1e2ae}', '\u{1e2ae}', Zero),
+    ('\u{1e2ec}', '\u{1e2ef}', Zero), ('\u{1e4ec}', '\u{1e4ef}', Zero),
+    ('\u{1e5ee}', '\u{1e5ef}', Zero), ('\u{1e8d0}', '\u{1e8d6}', Zero),
+    ('\u{1e944}', '\u{1e94a}', Zero), ('\u{1f004}', '\u{1f004}', Wide),
+    ('\u{1f0cf}', '\u{1f0cf}', Wide), ('\u{1f100}', '\u{1f10a}', Ambiguous),
+    ('\u{1f110}', '\u{1f12d}', Ambiguous), ('\u{1f130}', '\u{1f169}', Ambiguous),
+    ('\u{1f170}', '\u{1f18d}', Ambiguous), ('\u{1f18e}', '\u{1f18e}', Wide),
+    ('\u{1f18f}', '\u{1f190}', Ambiguous), ('\u{1f191}', '\u{1f19a}', Wide),
+    ('\u{1f19b}', '\u{1f1ac}', Ambiguous), ('\u{1f1e6}', '\u{1f202}', Wide),
+    ('\u{1f210}', '\u{1f23b}', Wide), ('\u{1f240}', '\u{1f248}', Wide),
+    ('\u{1f250}', '\u{1f251}', Wide), ('\u{1f260}', '\u{1f265}', Wide),
+    ('\u{1f300}', '\u{1f320}', Wide), ('\u{1f32d}', '\u{1f335}', Wide),
+    ('\u{1f337}', '\u{1f37c}', Wide), ('\u{1f37e}', '\u{1f393}', Wide),
+    ('\u{1f3a0}', '\u{1f3ca}', Wide), ('\u{1f3cf}', '\u{1f3d3}', Wide),
+    ('\u{1f3e0}', '\u{1f3f0}', Wide), ('\u{1f3f4}', '\u{1f3f4}', Wide),
+    ('\u{1f3f8}', '\u{1f43e}', Wide), ('\u{1f440}', '\u{1f440}', Wide),
+    ('\u{1f442}', '\u{1f4fc}', Wide), ('\u{1f4ff}', '\u{1f53d}', Wide),
+    ('\u{1f54b}', '\u{1f54e}', Wide), ('\u{1f550}', '\u{1f567}', Wide),
+    ('\u{1f57a}', '\u{1f57a}', Wide), ('\u{1f595}', '\u{1f596}', Wide),
+    ('\u{1f5a4}', '\u{1f5a4}', Wide), ('\u{1f5fb}', '\u{1f64f}', Wide),
+    ('\u{1f680}', '\u{1f6c5}', Wide), ('\u{1f6cc}', '\u{1f6cc}', Wide),
+    ('\u{1f6d0}', '\u{1f6d2}', Wide), ('\u{1f6d5}', '\u{1f6d7}', Wide),
+    ('\u{1f6dc}', '\u{1f6df}', Wide), ('\u{1f6eb}', '\u{1f6ec}', Wide),
+    ('\u{1f6f4}', '\u{1f6fc}', Wide), ('\u{1f7e0}', '\u{1f7eb}', Wide),
+    ('\u{1f7f0}', '\u{1f7f0}', Wide), ('\u{1f90c}', '\u{1f93a}', Wide),
+    ('\u{1f93c}', '\u{1f945}', Wide), ('\u{1f947}', '\u{1f9ff}', Wide),
+    ('\u{1fa70}', '\u{1fa7c}', Wide), ('\u{1fa80}', '\u{1fa89}', Wide),
+    ('\u{1fa8f}', '\u{1fac6}', Wide), ('\u{1face}', '\u{1fadc}', Wide),
+    ('\u{1fadf}', '\u{1fae9}', Wide), ('\u{1faf0}', '\u{1faf8}', Wide),
+    ('\u{20000}', '\u{2fffd}', Wide), ('\u{30000}', '\u{3fffd}', Wide),
+    ('\u{e0000}', '\u{e0fff}', Zero), ('\u{f0000}', '\u{ffffd}', Ambiguous),
+    ('\u{100000}', '\u{10fffd}', Ambiguous),
+];
+
+#[rustfmt::skip]
+const EMOJI: &[(char, char)] = &[
+    ('\u{23}', '\u{23}'), ('\u{2a}', '\u{2a}'), ('\u{30}', '\u{39}'), ('\u{a9}', '\u{a9}'),
+    ('\u{ae}', '\u{ae}'), ('\u{203c}', '\u{203c}'), ('\u{2049}', '\u{2049}'),
+    ('\u{2122}', '\u{2122}'), ('\u{2139}', '\u{2139}'), ('\u{2194}', '\u{2199}'),
+    ('\u{21a9}', '\u{21aa}'), ('\u{231a}', '\u{231b}'), ('\u{2328}', '\u{2328}'),
+    ('\u{23cf}', '\u{23cf}'), ('\u{23e9}', '\u{23f3}'), ('\u{23f8}', '\u{23fa}'),
+    ('\u{24c2}', '\u{24c2}'), ('\u{25aa}', '\u{25ab}'), ('\u{25b6}', '\u{25b6}'),
+    ('\u{25c0}', '\u{25c0}'), ('\u{25fb}', '\u{25fe}'), ('\u{2600}', '\u{2604}'),
+    ('\u{260e}', '\u{260e}'), ('\u{2611}', '\u{2611}'), ('\u{2614}', '\u{2615}'),
+    ('\u{2618}', '\u{2618}'), ('\u{261d}', '\u{261d}'), ('\u{2620}', '\u{2620}'),
+    ('\u{2622}', '\u{2623}'), ('\u{2626}', '\u{2626}'), ('\u{262a}', '\u{262a}'),
+    ('\u{262e}', '\u{262f}'), ('\u{2638}', '\u{263a}'), ('\u{2640}', '\u{2640}'),
+    ('\u{2642}', '\u{2642}'), ('\u{2648}', '\u{2653}'), ('\u{265f}', '\u{2660}'),
+    ('\u{2663}', '\u{2663}'), ('\u{2665}', '\u{2666}'), ('\u{2668}', '\u{2668}'),
+    ('\u{267b}', '\u{267b}'), ('\u{267e}', '\u{267f}'), ('\u{2692}', '\u{2697}'),
+    ('\u{2699}', '\u{2699}'), ('\u{269b}', '\u{269c}'), ('\u{26a0}', '\u{26a1}'),
+    ('\u{26a7}', '\u{26a7}'), ('\u{26aa}', '\u{26ab}'), ('\u{26b0}', '\u{26b1}'),
+    ('\u{26bd}', '\u{26be}'), ('\u{26c4}', '\u{26c5}'), ('\u{26c8}', '\u{26c8}'),
+    ('\u{26ce}', '\u{26cf}'), ('\u{26d1}', '\u{26d1}'), ('\u{26d3}', '\u{26d4}'),
+    ('\u{26e9}', '\u{26ea}'), ('\u{26f0}', '\u{26f5}'), ('\u{26f7}', '\u{26fa}'),
+    ('\u{26fd}', '\u{26fd}'), ('\u{2702}', '\u{2702}'), ('\u{2705}', '\u{2705}'),
+    ('\u{2708}', '\u{270d}'), ('\u{270f}', '\u{270f}'), ('\u{2712}', '\u{2712}'),
+    ('\u{2714}', '\u{2714}'), ('\u{2716}', '\u{2716}'), ('\u{271d}', '\u{271d}'),
+    ('\u{2721}', '\u{2721}'), ('\u{2728}', '\u{2728}'), ('\u{2733}', '\u{2734}'),
+    ('\u{2744}', '\u{2744}'), ('\u{2747}', '\u{2747}'), ('\u{274c}', '\u{274c}'),
+    ('\u{274e}', '\u{274e}'), ('\u{2753}', '\u{2755}'), ('\u{2757}', '\u{2757}'),
+    ('\u{2763}', '\u{2764}'), ('\u{2795}', '\u{2797}'), ('\u{27a1}', '\u{27a1}'),
+    ('\u{27b0}', '\u{27b0}'), ('\u{27bf}', '\u{27bf}'), ('\u{2934}', '\u{2935}'),
+    ('\u{2b05}', '\u{2b07}'), ('\u{2b1b}', '\u{2b1c}'), ('\u{2b50}', '\u{2b50}'),
+    ('\u{2b55}', '\u{2b55}'), ('\u{3030}', '\u{3030}'), ('\u{303d}', '\u{303d}'),
+    ('\u{3297}', '\u{3297}'), ('\u{3299}', '\u{3299}'), ('\u{1f004}', '\u{1f004}'),
+    ('\u{1f0cf}', '\u{1f0cf}'), ('\u{1f170}', '\u{1f171}'), ('\u{1f17e}', '\u{1f17f}'),
+    ('\u{1f18e}', '\u{1f18e}'), ('\u{1f191}', '\u{1f19a}'), ('\u{1f1e6}', '\u{1f1ff}'),
+    ('\u{1f201}', '\u{1f202}'), ('\u{1f21a}', '\u{1f21a}'), ('\u{1f22f}', '\u{1f22f}'),
+    ('\u{1f232}', '\u{1f23a}'), ('\u{1f250}', '\u{1f251}'), ('\u{1f300}', '\u{1f321}'),
+    ('\u{1f324}', '\u{1f393}'), ('\u{1f396}', '\u{1f397}'), ('\u{1f399}', '\u{1f39b}'),
+    ('\u{1f39e}', '\u{1f3f0}'), ('\u{1f3f3}', '\u{1f3f5}'), ('\u{1f3f7}', '\u{1f4fd}'),
+    ('\u{1f4ff}', '\u{1f53d}'), ('\u{1f549}', '\u{1f54e}'), ('\u{1f550}', '\u{1f567}'),
+    ('\u{1f56f}', '\u{1f570}'), ('\u{1f573}', '\u{1f57a}'), ('\u{1f587}', '\u{1f587}'),
+    ('\u{1f58a}', '\u{1f58d}'), ('\u{1f590}', '\u{1f590}'), ('\u{1f595}', '\u{1f596}'),
+    ('\u{1f5a4}', '\u{1f5a5}'), ('\u{1f5a8}', '\u{1f5a8}'), ('\u{1f5b1}', '\u{1f5b2}'),
+    ('\u{1f5bc}', '\u{1f5bc}'), ('\u{1f5c2}', '\u{1f5c4}'), ('\u{1f5d1}', '\u{1f5d3}'),
+    ('\u{1f5dc}', '\u{1f5de}'), ('\u{1f5e1}', '\u{1f5e1}'), ('\u{1f5e3}', '\u{1f5e3}'),
+    ('\u{1f5e8}', '\u{1f5e8}'), ('\u{1f5ef}', '\u{1f5ef}'), ('\u{1f5f3}', '\u{1f5f3}'),
+    ('\u{1f5fa}', '\u{1f64f}'), ('\u{1f680}', '\u{1f6c5}'), ('\u{1f6cb}', '\u{1f6d2}'),
+    ('\u{1f6d5}', '\u{1f6d7}'), ('\u{1f6dc}', '\u{1f6e5}'), ('\u{1f6e9}', '\u{1f6e9}'),
+    ('\u{1f6eb}', '\u{1f6ec}'), ('\u{1f6f0}', '\u{1f6f0}'), ('\u{1f6f3}', '\u{1f6fc}'),
+    ('\u{1f7e0}', '\u{1f7eb}'), ('\u{1f7f0}', '\u{1f7f0}'), ('\u{1f90c}', '\u{1f93a}'),
+    ('\u{1f93c}', '\u{1f945}'), ('\u{1f947}', '\u{1f9ff}'), ('\u{1fa70}', '\u{1fa7c}'),
+    ('\u{1fa80}', '\u{1fa89}'), ('\u{1fa8f}', '\u{1fac6}'), ('\u{1face}', '\u{1fadc}'),
+    ('\u{1fadf}', '\u{1fae9}'), ('\u{1faf0}', '\u{1faf8}'),
+];
@@ -0,0 +1,188 @@
+//! Operations on strings, which treat every [`UTF8Char`](crate::UTF8Char) as
+//! a unit, so that they never split or reorder the code points of one.
+
+use std::collections::VecDeque;
+
+use crate::{DisplayWidth, ToUTF8Chars, UTF8Chars};
+
+/// Reverse the order of the chars.
+pub fn reverse(text: &str) -> String {
+    text.utf8_chars().rev().map(|c| c.as_str()).collect()
+}
+
+/// Remove every char which is the same as the one in front of it.
+pub fn dedup_consecutive(text: &str) -> String {
+    let mut prev = None;
+
+    text.utf8_chars()
+        .filter(|&utf8_char| prev.replace(utf8_char) != Some(utf8_char))
+        .map(|c| c.as_str())
+        .collect()
+}
+
+/// Alternate between the chars of both strings, starting with the first one.
+///
+/// The rest of the longer string is appended.
+pub fn interleave(first: &str, second: &str) -> String {
+    let mut first = first.utf8_chars();
+    let mut second = second.utf8_chars();
+    let mut interleaved = String::new();
+
+    loop {
+        match (first.next(), second.next()) {
+            (None, None) => return interleaved,
+            (left, right) => {
+                interleaved.extend(left.iter().chain(&right).map(|c| c.as_str()));
+            }
+        }
+    }
+}
+
+/// Split the text into slices of `size` chars. The last one might be shorter.
+///
+/// # Panics
+///
+/// If `size` is 0.
+pub fn chunks(text: &str, size: usize) -> Chunks<'_> {
+    assert!(size > 0, "chunk size must be non-zero");
+
+    Chunks {
+        chars: text.utf8_chars(),
+        size,
+    }
+}
+
+/// An iterator over slices of [`UTF8Char`](crate::UTF8Char)s, see [`chunks`].
+#[derive(Debug)]
+pub struct Chunks<'a> {
+    chars: UTF8Chars<'a>,
+    size: usize,
+}
+
+impl<'a> Iterator for Chunks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.chars.as_str();
+        self.chars.nth(self.size - 1);
+
+        let len = rest.len() - self.chars.as_str().len();
+        (len > 0).then(|| &rest[..len])
+    }
+}
+
+/// Get all overlapping slices of `size` chars.
+///
+/// # Panics
+///
+/// If `size` is 0.
+pub fn windows(text: &str, size: usize) -> Windows<'_> {
+    assert!(size > 0, "window size must be non-zero");
+
+    Windows {
+        text,
+        chars: text.utf8_chars(),
+        lens: VecDeque::with_capacity(size),
+        size,
+    }
+}
+
+/// An iterator over overlapping slices of [`UTF8Char`](crate::UTF8Char)s, see
+/// [`windows`].
+#[derive(Debug)]
+pub struct Windows<'a> {
+    /// The text, starting at the current window.
+    text: &'a str,
+    chars: UTF8Chars<'a>,
+    /// The byte lengths of the chars in the current window.
+    lens: VecDeque<usize>,
+    size: usize,
+}
+
+impl<'a> Iterator for Windows<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.lens.len() == self.size {
+            let first_len = self.lens.pop_front()?;
+            self.text = &self.text[first_len..];
+        }
+
+        while self.lens.len() < self.size {
+            self.lens.push_back(self.chars.next()?.as_str().len());
+        }
+
+        Some(&self.text[..self.lens.iter().sum()])
+    }
+}
+
+/// Where the text goes when padding it, see [`pad_to_width`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Alignment {
+    Left,
+    Right,
+    /// Put the text in the middle. If the padding can't be split evenly, the
+    /// extra space goes to the right.
+    Center,
+}
+
+/// Pad the text with spaces, until it is `width` terminal cells wide, see
+/// [`UTF8Char::display_width`](crate::UTF8Char::display_width).
+///
+/// Text which is already as wide, or wider, is returned unchanged.
+pub fn pad_to_width(text: &str, width: usize, alignment: Alignment) -> String {
+    let padding = width.saturating_sub(text.display_width());
+    let left = match alignment {
+        Alignment::Left => 0,
+        Alignment::Right => padding,
+        Alignment::Center => padding / 2,
+    };
+
+    format!("{}{text}{}", " ".repeat(left), " ".repeat(padding - left))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::text::{
+        chunks, dedup_consecutive, interleave, pad_to_width, reverse, windows, Alignment,
+    };
+
+    #[test]
+    fn reverse_keeps_chars_intact() {
+        assert_eq!(reverse("👨‍👩‍👦🇩🇪e\u{301}a"), "ae\u{301}🇩🇪👨‍👩‍👦");
+    }
+
+    #[test]
+    fn dedup_consecutive_chars() {
+        assert_eq!(
+            dedup_consecutive("aaé\u{301}e\u{301}🇩🇪🇩🇪🇫🇷a"),
+            "aé\u{301}e\u{301}🇩🇪🇫🇷a"
+        );
+    }
+
+    #[test]
+    fn interleave_chars() {
+        assert_eq!(interleave("ab🇩🇪", "12"), "a1b2🇩🇪");
+        assert_eq!(interleave("a", "e\u{301}👍🏽"), "ae\u{301}👍🏽");
+    }
+
+    #[test]
+    fn chunks_and_windows() {
+        let text = "a👨‍👩‍👦🇩🇪b";
+
+        assert_eq!(chunks(text, 3).collect::<Vec<_>>(), ["a👨‍👩‍👦🇩🇪", "b"]);
+        assert_eq!(windows(text, 2).collect::<Vec<_>>(), ["a👨‍👩‍👦", "👨‍👩‍👦🇩🇪", "🇩🇪b"]);
+        assert_eq!(windows(text, 5).next(), None);
+    }
+
+    #[test]
+    fn pad_to_display_width() {
+        assert_eq!(pad_to_width("한a", 6, Alignment::Left), "한a   ");
+        assert_eq!(pad_to_width("🏳️‍🌈", 5, Alignment::Right), "   🏳️‍🌈");
+        assert_eq!(
+            pad_to_width("e\u{301}", 4, Alignment::Center),
+            " e\u{301}  "
+        );
+        assert_eq!(pad_to_width("abc", 2, Alignment::Center), "abc");
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{ToUTF8Chars, UTF8Char};
+
+/// Truncate the text to at most `max_chars` [`UTF8Char`]s.
+///
+/// If the text is longer, the returned prefix leaves room for the ellipsis,
+/// which the caller appends. Whether the text was truncated can be checked by
+/// comparing the lengths.
+pub fn truncate_chars<'a>(text: &'a str, max_chars: usize, ellipsis: Option<UTF8Char>) -> &'a str {
+    let ellipsis_chars = ellipsis.map_or(0, |_| 1);
+
+    truncate_by(text, max_chars, ellipsis_chars, |_| 1)
+}
+
+/// Truncate the text to at most `max_width` terminal cells, see
+/// [`UTF8Char::display_width`].
+///
+/// If the text is wider, the returned prefix leaves room for the ellipsis,
+/// which the caller appends.
+pub fn truncate_width<'a>(text: &'a str, max_width: usize, ellipsis: Option<UTF8Char>) -> &'a str {
+    let ellipsis_width = ellipsis.map_or(0, |ellipsis| ellipsis.display_width());
+
+    truncate_by(text, max_width, ellipsis_width, |utf8_char| {
+        utf8_char.display_width()
+    })
+}
+
+fn truncate_by(
+    text: &str,
+    limit: usize,
+    ellipsis_size: usize,
+    size: impl Fn(&UTF8Char) -> usize,
+) -> &str {
+    let mut total = 0;
+    // The end of the prefix, which still leaves room for the ellipsis.
+    let mut end_with_ellipsis = 0;
+
+    for (offset, utf8_char) in text.utf8_char_indices() {
+        total += size(&utf8_char);
+
+        if total > limit {
+            return &text[..end_with_ellipsis];
+        }
+
+        if total + ellipsis_size <= limit {
+            end_with_ellipsis = offset + utf8_char.as_str().len();
+        }
+    }
+
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{truncate_chars, truncate_width};
+
+    #[test]
+    fn truncate_chars_keeps_whole_chars() {
+        let name = "Ana👨‍👩‍👦🇩🇪";
+
+        assert_eq!(truncate_chars(name, 5, None), name);
+        assert_eq!(truncate_chars(name, 4, None), "Ana👨‍👩‍👦");
+        assert_eq!(truncate_chars(name, 4, Some("…".into())), "Ana");
+        assert_eq!(truncate_chars(name, 5, Some("…".into())), name);
+        assert_eq!(truncate_chars(name, 0, Some("…".into())), "");
+    }
+
+    #[test]
+    fn truncate_width_keeps_whole_chars() {
+        let text = "a한국🏳️‍🌈";
+
+        assert_eq!(truncate_width(text, 7, None), text);
+        assert_eq!(truncate_width(text, 4, None), "a한");
+        assert_eq!(truncate_width(text, 6, Some("…".into())), "a한국");
+        assert_eq!(truncate_width(text, 5, Some("…".into())), "a한");
+    }
+}
@@ -0,0 +1,135 @@
+use crate::{
+    grapheme::EMOJI_PRESENTATION_SELECTOR,
+    tables::{char_width, grapheme_cluster_break, is_emoji, CharWidth, GraphemeClusterBreak},
+    ToUTF8Chars, UTF8Char,
+};
+
+/// How wide code points with an ambiguous east asian width are displayed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AmbiguousWidth {
+    /// One cell, like most terminals do.
+    #[default]
+    Narrow,
+    /// Two cells, like terminals in a CJK locale, or with the ambiguous width
+    /// set to double (for example xterm's `-cjk_width`).
+    Wide,
+}
+
+impl<'a> UTF8Char<'a> {
+    /// The number of terminal cells the char takes, see
+    /// [`display_width_with`](UTF8Char::display_width_with).
+    pub fn display_width(&self) -> usize {
+        self.display_width_with(AmbiguousWidth::Narrow)
+    }
+
+    /// The number of terminal cells the char takes.
+    ///
+    /// The width is that of the first code point which is not zero width, so
+    /// combining marks, joiners and the like don't add to it. Emoji with an
+    /// emoji presentation selector, and flags, are always two cells wide.
+    /// Chars which only consist of zero width code points, like `"\r\n"`, have
+    /// a width of 0.
+    pub fn display_width_with(&self, ambiguous: AmbiguousWidth) -> usize {
+        let mut code_points = self.code_points();
+        let is_regional_indicator =
+            |cp| grapheme_cluster_break(cp) == GraphemeClusterBreak::RegionalIndicator;
+
+        match (code_points.next(), code_points.next()) {
+            (Some(first), Some(EMOJI_PRESENTATION_SELECTOR)) if is_emoji(first) => return 2,
+            (Some(first), Some(second))
+                if is_regional_indicator(first) && is_regional_indicator(second) =>
+            {
+                return 2
+            }
+            _ => {}
+        }
+
+        let width = self
+            .code_points()
+            .map(char_width)
+            .find(|&width| width != CharWidth::Zero);
+
+        match width.unwrap_or(CharWidth::Zero) {
+            CharWidth::Zero => 0,
+            CharWidth::Narrow => 1,
+            CharWidth::Wide => 2,
+            CharWidth::Ambiguous => match ambiguous {
+                AmbiguousWidth::Narrow => 1,
+                AmbiguousWidth::Wide => 2,
+            },
+        }
+    }
+}
+
+/// The display width of a whole string.
+pub trait DisplayWidth {
+    /// The number of terminal cells the string takes, see
+    /// [`UTF8Char::display_width`].
+    fn display_width(&self) -> usize;
+    /// The number of terminal cells the string takes, see
+    /// [`UTF8Char::display_width_with`].
+    fn display_width_with(&self, ambiguous: AmbiguousWidth) -> usize;
+}
+
+impl DisplayWidth for str {
+    fn display_width(&self) -> usize {
+        self.display_width_with(AmbiguousWidth::Narrow)
+    }
+
+    fn display_width_with(&self, ambiguous: AmbiguousWidth) -> usize {
+        self.utf8_chars()
+            .map(|utf8_char| utf8_char.display_width_with(ambiguous))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AmbiguousWidth, DisplayWidth, UTF8Char};
+
+    fn width(utf8_char: &str) -> usize {
+        UTF8Char::from(utf8_char).display_width()
+    }
+
+    #[test]
+    fn display_width_narrow_and_wide() {
+        assert_eq!(width("a"), 1);
+        assert_eq!(width("e\u{301}"), 1);
+        assert_eq!(width("한"), 2);
+        assert_eq!(width("\u{1100}\u{1161}\u{11a8}"), 2);
+        assert_eq!(width("Ａ"), 2);
+        assert_eq!(width("⚽"), 2);
+    }
+
+    #[test]
+    fn display_width_emoji_sequences() {
+        assert_eq!(width("🏳️‍🌈"), 2);
+        assert_eq!(width("👨‍👩‍👦"), 2);
+        assert_eq!(width("👍🏽"), 2);
+        assert_eq!(width("🇩🇪"), 2);
+        assert_eq!(width("1\u{fe0f}\u{20e3}"), 2);
+        assert_eq!(width("❤"), 1);
+        assert_eq!(width("❤\u{fe0f}"), 2);
+    }
+
+    #[test]
+    fn display_width_presentation_selector_after_non_emoji() {
+        assert_eq!(width("a\u{fe0f}"), 1);
+        assert_eq!(width("가\u{fe0f}"), 2);
+        assert_eq!(width("\u{301}\u{fe0f}"), 0);
+    }
+
+    #[test]
+    fn display_width_zero() {
+        assert_eq!(width("\r\n"), 0);
+        assert_eq!(width("\u{200b}"), 0);
+        assert_eq!(width("\u{301}"), 0);
+    }
+
+    #[test]
+    fn str_display_width() {
+        assert_eq!("a한🏳️‍🌈e\u{301}\n".display_width(), 6);
+        assert_eq!("±°".display_width(), 2);
+        assert_eq!("±°".display_width_with(AmbiguousWidth::Wide), 4);
+    }
+}
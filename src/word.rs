@@ -0,0 +1,288 @@
+use crate::{
+    tables::{is_extended_pictographic, word_break, WordBreak},
+    ToUTF8Chars, UTF8Char, UTF8Chars,
+};
+
+/// An iterator over the words of a string, and the segments between them, see
+/// [UAX #29](https://www.unicode.org/reports/tr29/#Word_Boundaries).
+///
+/// Word boundaries are only looked for between [`UTF8Char`]s, so a word never
+/// ends inside of one. For example `"don't"` is one word, and so is
+/// `"a\u{ad}b"`, since the soft hyphen is ignored.
+#[derive(Debug)]
+pub struct UTF8Words<'a> {
+    text: &'a str,
+    word_like_only: bool,
+}
+
+impl<'a> UTF8Words<'a> {
+    /// Skip the segments which don't contain a letter or a number, like white
+    /// space, punctuation and emoji.
+    pub fn word_like_only(mut self) -> Self {
+        self.word_like_only = true;
+        self
+    }
+}
+
+impl<'a> Iterator for UTF8Words<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.text.is_empty() {
+                return None;
+            }
+
+            let (word, rest) = self.text.split_at(word_len(self.text));
+            self.text = rest;
+
+            if !self.word_like_only || word.chars().any(char::is_alphanumeric) {
+                return Some(word);
+            }
+        }
+    }
+}
+
+pub trait ToUTF8Words<'a> {
+    fn utf8_words(&'a self) -> UTF8Words<'a>;
+}
+
+impl<'a> ToUTF8Words<'a> for str {
+    fn utf8_words(&'a self) -> UTF8Words<'a> {
+        UTF8Words {
+            text: self,
+            word_like_only: false,
+        }
+    }
+}
+
+/// The word break properties of an [`UTF8Char`].
+#[derive(Debug, Clone, Copy)]
+struct CharInfo {
+    /// The property of the first code point, which is not ignored by WB4.
+    /// Chars which only consist of ignored code points have the property of
+    /// the first one.
+    word_break: WordBreak,
+    first: char,
+    last: char,
+    regional_indicators: usize,
+}
+
+impl CharInfo {
+    fn new(utf8_char: UTF8Char) -> Self {
+        let mut code_points = utf8_char.code_points();
+        let first = code_points.next().expect("a char is never empty");
+        let last = code_points.next_back().unwrap_or(first);
+
+        CharInfo {
+            word_break: utf8_char
+                .code_points()
+                .map(word_break)
+                .find(|&wb| !is_ignored(wb))
+                .unwrap_or(word_break(first)),
+            first,
+            last,
+            regional_indicators: utf8_char
+                .code_points()
+                .filter(|&cp| word_break(cp) == WordBreak::RegionalIndicator)
+                .count(),
+        }
+    }
+
+    /// Check if WB4 attaches the char to the one in front of it.
+    fn is_ignored(&self) -> bool {
+        is_ignored(self.word_break)
+    }
+}
+
+fn is_ignored(wb: WordBreak) -> bool {
+    matches!(wb, WordBreak::Extend | WordBreak::Format | WordBreak::Zwj)
+}
+
+fn is_newline(wb: WordBreak) -> bool {
+    matches!(wb, WordBreak::Newline | WordBreak::Cr | WordBreak::Lf)
+}
+
+fn is_ah_letter(wb: WordBreak) -> bool {
+    matches!(wb, WordBreak::ALetter | WordBreak::HebrewLetter)
+}
+
+fn is_mid_num_let_q(wb: WordBreak) -> bool {
+    matches!(wb, WordBreak::MidNumLet | WordBreak::SingleQuote)
+}
+
+/// The state of the word boundary rules, after the chars seen so far.
+#[derive(Debug, Clone, Copy)]
+struct WordState {
+    /// The last char.
+    prev: CharInfo,
+    /// The property of the last char, which was not ignored.
+    before: WordBreak,
+    /// The property of the not ignored char in front of that.
+    before_before: Option<WordBreak>,
+    odd_regional_indicators: bool,
+}
+
+impl WordState {
+    fn new(first: CharInfo) -> Self {
+        WordState {
+            prev: first,
+            before: first.word_break,
+            before_before: None,
+            odd_regional_indicators: first.regional_indicators % 2 == 1,
+        }
+    }
+
+    /// Check if there is a boundary in front of the char, and add it to the
+    /// state.
+    ///
+    /// `rest` are the chars after it, which some rules look ahead into.
+    fn is_boundary_before(&mut self, info: CharInfo, rest: UTF8Chars) -> bool {
+        let prev_last = word_break(self.prev.last);
+        let first = word_break(info.first);
+
+        // WB3a, WB3b, WB3c, WB3d
+        let boundary = match (prev_last, first) {
+            (prev_last, first) if is_newline(prev_last) || is_newline(first) => Some(true),
+            (WordBreak::Zwj, _) if is_extended_pictographic(info.first) => Some(false),
+            (WordBreak::WSegSpace, WordBreak::WSegSpace) => Some(false),
+            _ => None,
+        };
+
+        self.prev = info;
+
+        // WB4
+        if boundary.is_none() && info.is_ignored() {
+            return false;
+        }
+
+        let boundary = boundary.unwrap_or_else(|| self.is_boundary(info.word_break, rest));
+
+        if info.word_break == WordBreak::RegionalIndicator && self.before == info.word_break {
+            self.odd_regional_indicators ^= info.regional_indicators % 2 == 1;
+        } else {
+            self.odd_regional_indicators = info.regional_indicators % 2 == 1;
+        }
+        self.before_before = Some(self.before);
+        self.before = info.word_break;
+
+        boundary
+    }
+
+    fn is_boundary(&self, wb: WordBreak, rest: UTF8Chars) -> bool {
+        use WordBreak::*;
+
+        // The property of the next char, which is not ignored.
+        let after = || {
+            rest.as_str()
+                .utf8_chars()
+                .map(CharInfo::new)
+                .find(|info| !info.is_ignored())
+                .map(|info| info.word_break)
+        };
+
+        match (self.before_before, self.before, wb) {
+            // WB5
+            (_, before, wb) if is_ah_letter(before) && is_ah_letter(wb) => false,
+            // WB6
+            (_, before, MidLetter | MidNumLet | SingleQuote)
+                if is_ah_letter(before) && after().is_some_and(is_ah_letter) =>
+            {
+                false
+            }
+            // WB7
+            (Some(before_before), MidLetter | MidNumLet | SingleQuote, wb)
+                if is_ah_letter(before_before) && is_ah_letter(wb) =>
+            {
+                false
+            }
+            // WB7a
+            (_, HebrewLetter, SingleQuote) => false,
+            // WB7b
+            (_, HebrewLetter, DoubleQuote) if after() == Some(HebrewLetter) => false,
+            // WB7c
+            (Some(HebrewLetter), DoubleQuote, HebrewLetter) => false,
+            // WB8, WB9, WB10
+            (_, Numeric, Numeric) => false,
+            (_, before, Numeric) if is_ah_letter(before) => false,
+            (_, Numeric, wb) if is_ah_letter(wb) => false,
+            // WB11
+            (Some(Numeric), MidNum, Numeric) => false,
+            (Some(Numeric), before, Numeric) if is_mid_num_let_q(before) => false,
+            // WB12
+            (_, Numeric, MidNum) if after() == Some(Numeric) => false,
+            (_, Numeric, wb) if is_mid_num_let_q(wb) && after() == Some(Numeric) => false,
+            // WB13, WB13a, WB13b
+            (_, Katakana, Katakana) => false,
+            (_, before, ExtendNumLet)
+                if is_ah_letter(before) || matches!(before, Numeric | Katakana | ExtendNumLet) =>
+            {
+                false
+            }
+            (_, ExtendNumLet, wb) if is_ah_letter(wb) || matches!(wb, Numeric | Katakana) => false,
+            // WB15, WB16
+            (_, RegionalIndicator, RegionalIndicator) => !self.odd_regional_indicators,
+            // WB999
+            _ => true,
+        }
+    }
+}
+
+/// Get the byte length of the word at the start of the text.
+fn word_len(text: &str) -> usize {
+    let mut chars = text.utf8_chars();
+    let Some(first) = chars.next() else {
+        return 0;
+    };
+    let mut state = WordState::new(CharInfo::new(first));
+
+    while let Some(utf8_char) = chars.next() {
+        if state.is_boundary_before(CharInfo::new(utf8_char), chars.as_str().utf8_chars()) {
+            return text.len() - chars.as_str().len() - utf8_char.as_str().len();
+        }
+    }
+
+    text.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ToUTF8Words;
+
+    #[test]
+    fn utf8_words() {
+        let words = "The quick (\"brown\") fox can't jump 32.3 feet, right?"
+            .utf8_words()
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            words,
+            [
+                "The", " ", "quick", " ", "(", "\"", "brown", "\"", ")", " ", "fox", " ", "can't",
+                " ", "jump", " ", "32.3", " ", "feet", ",", " ", "right", "?"
+            ]
+        );
+    }
+
+    #[test]
+    fn utf8_words_word_like_only() {
+        let words = "Hello, 👨‍👩‍👦 wörld\u{301} 42!"
+            .utf8_words()
+            .word_like_only()
+            .collect::<Vec<_>>();
+
+        assert_eq!(words, ["Hello", "wörld\u{301}", "42"]);
+    }
+
+    #[test]
+    fn utf8_words_never_split_chars() {
+        let words = "a👨‍👩‍👦b\u{200d}👩 🇩🇪🇫🇷\u{ad}x"
+            .utf8_words()
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            words,
+            ["a", "👨‍👩‍👦", "b\u{200d}👩", " ", "🇩🇪", "🇫🇷\u{ad}", "x"]
+        );
+    }
+}